@@ -2,13 +2,31 @@ use clap::Subcommand;
 use confy::ConfyError;
 use std::path::PathBuf;
 
+use crate::dependencies::project_config_io::secrets;
+
 pub const CONFY_APP_NAME: &str = "devperf";
 
 #[derive(Subcommand)]
 pub enum ConfigAction {
     Get {},
+    /// Move plaintext tokens of the config file into a secret store
+    MigrateSecrets {
+        #[clap(long, value_parser = ["keyring", "age-file"])]
+        store: String,
+
+        /// Encrypted file for the `age-file` store (default: secrets.age next to the config file)
+        #[clap(long, required = false)]
+        age_file: Option<String>,
+    },
 }
 
 pub fn get_config_path() -> Result<PathBuf, ConfyError> {
     confy::get_configuration_file_path(CONFY_APP_NAME, None)
 }
+
+pub fn migrate_secrets(store: String, age_file: Option<String>) -> anyhow::Result<()> {
+    let count = secrets::migrate_secrets(store, age_file)?;
+    println!("Moved {} secrets into the secret store", count);
+
+    Ok(())
+}
@@ -5,13 +5,22 @@ use crate::{
     common_types::date_time_range::DateTimeRange,
     dependencies::{
         deployments_fetcher::{
+            argo_cd_application::DeploymentsFetcherWithArgoCdApplication,
+            aws_code_deploy::DeploymentsFetcherWithAwsCodeDeploy,
             github_deployment::DeploymentsFetcherWithGithubDeployment,
             github_merged_pull::DeploymentsFetcherWithGithubMergedPullRequest,
-            heroku_release::DeploymentsFetcherWithHerokuRelease,
+            heroku_pipeline::DeploymentsFetcherWithHerokuPipeline,
+            heroku_release::DeploymentsFetcherWithHerokuRelease, interface::DeploymentsFetcher,
+            jenkins_build::DeploymentsFetcherWithJenkinsBuild,
+            kubernetes_rollout::DeploymentsFetcherWithKubernetesRollout,
+            netlify_deploy::DeploymentsFetcherWithNetlifyDeploy,
+            render_deploy::DeploymentsFetcherWithRenderDeploy,
+            vercel_deployment::DeploymentsFetcherWithVercelDeployment,
         },
         project_config_io::reader::{
             interface::ProjectConfigIOReader, settings_toml::ProjectConfigIOReaderWithSettingsToml,
         },
+        pull_requests_fetcher::github::PullRequestsFetcherWithGitHub,
         two_commits_comparer::github::TwoCommitsComparerWithGitHub,
     },
     metrics_retrieving::{
@@ -22,8 +31,18 @@ use crate::{
         },
     },
     project_creating::create_project::ProjectCreated,
+    shared::rate_limit::call_report,
 };
 
+fn count_warnings(events: &[RetrieveFourKeysEvent]) -> usize {
+    events
+        .iter()
+        .map(|event| match event {
+            RetrieveFourKeysEvent::RetrieveFourKeys(metrics) => metrics.warnings.len(),
+        })
+        .sum()
+}
+
 fn write_standard_out_from_events(events: Vec<RetrieveFourKeysEvent>) {
     for event in events {
         match event {
@@ -38,6 +57,7 @@ pub async fn get_four_keys(
     project_name: &str,
     since: DateTime<Utc>,
     until: DateTime<Utc>,
+    strict: bool,
 ) -> Result<()> {
     let config_reader = ProjectConfigIOReaderWithSettingsToml {};
     let project_config_dto = config_reader.read(project_name.to_string()).await?;
@@ -48,62 +68,168 @@ pub async fn get_four_keys(
     };
     let project_config: ProjectCreated = project_config_dto.try_into()?;
 
-    let events = match project_config {
+    let github = project_config.github_repository();
+    let deployments_fetcher: Box<dyn DeploymentsFetcher + Send + Sync> = match project_config {
         ProjectCreated::HerokuRelease(config) => {
             log::info!("Heroku project detected");
-            let deployments_fetcher = DeploymentsFetcherWithHerokuRelease {
-                heroku_app_name: config.heroku_app_name.clone(),
-                heroku_auth_token: config.heroku_auth_token.clone(),
-                github_owner_repo: config.github_owner_repo.clone(),
-                github_personal_token: config.github_personal_token.clone(),
-            };
-            let two_commits_comparer = TwoCommitsComparerWithGitHub {
-                github_personal_token: config.github_personal_token.clone(),
+            Box::new(DeploymentsFetcherWithHerokuRelease {
+                heroku_app_name: config.heroku_app_name,
+                heroku_auth_token: config.heroku_auth_token,
+                github_owner_repo: config.github_owner_repo,
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
+            })
+        }
+        ProjectCreated::HerokuPipeline(config) => {
+            log::info!("Heroku pipeline project detected");
+            Box::new(DeploymentsFetcherWithHerokuPipeline {
+                heroku_pipeline_name: config.heroku_pipeline_name,
+                heroku_auth_token: config.heroku_auth_token,
                 github_owner_repo: config.github_owner_repo,
-            };
-            let workflow = RetrieveFourKeysWorkflow {
-                deployments_fetcher,
-                two_commits_comparer,
-            };
-            workflow.retrieve_four_keys(context.clone())
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
+            })
         }
         ProjectCreated::GitHubDeployment(config) => {
             log::info!("GitHub deployment project detected");
-            let deployments_fetcher = DeploymentsFetcherWithGithubDeployment {
-                github_personal_token: config.github_personal_token.clone(),
-                github_owner_repo: config.github_owner_repo.clone(),
-                environment: config.github_deployment_environment.clone(),
-            };
-            let two_commits_comparer = TwoCommitsComparerWithGitHub {
-                github_personal_token: config.github_personal_token.clone(),
+            Box::new(DeploymentsFetcherWithGithubDeployment {
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
                 github_owner_repo: config.github_owner_repo,
-            };
-            let workflow = RetrieveFourKeysWorkflow {
-                deployments_fetcher,
-                two_commits_comparer,
-            };
-            workflow.retrieve_four_keys(context)
+                environment: config.github_deployment_environment,
+            })
         }
         ProjectCreated::GitHubPullRequest(config) => {
             log::info!("GitHub pull request project detected");
-            let deployments_fetcher = DeploymentsFetcherWithGithubMergedPullRequest {
-                github_personal_token: config.github_personal_token.clone(),
-                github_owner_repo: config.github_owner_repo.clone(),
+            Box::new(DeploymentsFetcherWithGithubMergedPullRequest {
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
+                github_owner_repo: config.github_owner_repo,
                 deploy_trigger_branch: config.github_deploy_branch_name,
-            };
-            let two_commits_comparer = TwoCommitsComparerWithGitHub {
-                github_personal_token: config.github_personal_token.clone(),
+            })
+        }
+        ProjectCreated::ArgoCdApplication(config) => {
+            log::info!("Argo CD application project detected");
+            Box::new(DeploymentsFetcherWithArgoCdApplication {
+                argo_cd_base_url: config.argo_cd_base_url,
+                argo_cd_auth_token: config.argo_cd_auth_token,
+                argo_cd_application_name: config.argo_cd_application_name,
                 github_owner_repo: config.github_owner_repo,
-            };
-            let workflow = RetrieveFourKeysWorkflow {
-                deployments_fetcher,
-                two_commits_comparer,
-            };
-            workflow.retrieve_four_keys(context)
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
+            })
         }
-    }
-    .await?;
+        ProjectCreated::KubernetesRollout(config) => {
+            log::info!("Kubernetes rollout project detected");
+            Box::new(DeploymentsFetcherWithKubernetesRollout {
+                kubeconfig_path: config.kubeconfig_path,
+                kubernetes_context: config.kubernetes_context,
+                kubernetes_namespace: config.kubernetes_namespace,
+                kubernetes_deployment_name: config.kubernetes_deployment_name,
+                kubernetes_commit_annotation: config.kubernetes_commit_annotation,
+                github_owner_repo: config.github_owner_repo,
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
+            })
+        }
+        ProjectCreated::VercelDeployment(config) => {
+            log::info!("Vercel deployment project detected");
+            Box::new(DeploymentsFetcherWithVercelDeployment {
+                vercel_api_base_url: config.vercel_api_base_url,
+                vercel_auth_token: config.vercel_auth_token,
+                vercel_project_id: config.vercel_project_id,
+                vercel_team_id: config.vercel_team_id,
+                github_owner_repo: config.github_owner_repo,
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
+            })
+        }
+        ProjectCreated::NetlifyDeploy(config) => {
+            log::info!("Netlify deploy project detected");
+            Box::new(DeploymentsFetcherWithNetlifyDeploy {
+                netlify_api_base_url: config.netlify_api_base_url,
+                netlify_auth_token: config.netlify_auth_token,
+                netlify_site_id: config.netlify_site_id,
+                github_owner_repo: config.github_owner_repo,
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
+            })
+        }
+        ProjectCreated::RenderDeploy(config) => {
+            log::info!("Render deploy project detected");
+            Box::new(DeploymentsFetcherWithRenderDeploy {
+                render_api_base_url: config.render_api_base_url,
+                render_api_key: config.render_api_key,
+                render_service_id: config.render_service_id,
+                github_owner_repo: config.github_owner_repo,
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
+            })
+        }
+        ProjectCreated::AwsCodeDeploy(config) => {
+            log::info!("AWS CodeDeploy project detected");
+            Box::new(DeploymentsFetcherWithAwsCodeDeploy {
+                aws_endpoint_url: config.aws_endpoint_url,
+                aws_region: config.aws_region,
+                aws_code_deploy_application_name: config.aws_code_deploy_application_name,
+                aws_code_deploy_deployment_group_name: config.aws_code_deploy_deployment_group_name,
+                aws_ecs_commit_tag: config.aws_ecs_commit_tag,
+                github_owner_repo: config.github_owner_repo,
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
+            })
+        }
+        ProjectCreated::JenkinsBuild(config) => {
+            log::info!("Jenkins build project detected");
+            Box::new(DeploymentsFetcherWithJenkinsBuild {
+                jenkins_base_url: config.jenkins_base_url,
+                jenkins_job_name: config.jenkins_job_name,
+                jenkins_user_name: config.jenkins_user_name,
+                jenkins_api_token: config.jenkins_api_token,
+                github_owner_repo: config.github_owner_repo,
+                github_credentials: config.github_credentials,
+                github_api_base_url: config.github_api_base_url,
+            })
+        }
+    };
+    let two_commits_comparer = TwoCommitsComparerWithGitHub {
+        github_credentials: github.github_credentials.clone(),
+        github_api_base_url: github.github_api_base_url.clone(),
+        github_owner_repo: github.github_owner_repo.clone(),
+    };
+    let pull_requests_fetcher = PullRequestsFetcherWithGitHub {
+        github_credentials: github.github_credentials,
+        github_api_base_url: github.github_api_base_url,
+        github_owner_repo: github.github_owner_repo,
+    };
+    let workflow = RetrieveFourKeysWorkflow {
+        deployments_fetcher,
+        two_commits_comparer,
+        pull_requests_fetcher,
+    };
+    let events = workflow.retrieve_four_keys(context).await?;
 
+    let warnings = count_warnings(&events);
     write_standard_out_from_events(events);
+    let report = call_report();
+    if report.retried > 0 || report.skipped > 0 {
+        log::warn!(
+            "{} API calls were retried and {} were skipped",
+            report.retried,
+            report.skipped
+        );
+    }
+    if warnings > 0 {
+        if strict {
+            anyhow::bail!(
+                "{} data quality warnings, see `warnings` in the output",
+                warnings
+            );
+        }
+        log::warn!(
+            "{} data quality warnings, see `warnings` in the output",
+            warnings
+        );
+    }
     Ok(())
 }
@@ -5,14 +5,37 @@ use crate::{
     apps::cli::initializer::github_pull_request, common_types::deployment_source::DeploymentSource,
 };
 
-use super::{github_deployment, heroku_release};
+use super::{
+    argo_cd_application, aws_code_deploy, github_deployment, heroku_pipeline, heroku_release,
+    jenkins_build, kubernetes_rollout, netlify_deploy, render_deploy, vercel_deployment,
+};
 
 pub async fn perform() -> Result<()> {
     println!("Initialize CLI");
     let github_deployment = DeploymentSource::GitHubDeployment.label();
     let github_pull_request = DeploymentSource::GitHubPullRequest.label();
     let heroku_release = DeploymentSource::HerokuRelease.label();
-    let options: Vec<&str> = vec![&github_deployment, &github_pull_request, &heroku_release];
+    let heroku_pipeline = DeploymentSource::HerokuPipeline.label();
+    let argo_cd_application = DeploymentSource::ArgoCdApplication.label();
+    let kubernetes_rollout = DeploymentSource::KubernetesRollout.label();
+    let vercel_deployment = DeploymentSource::VercelDeployment.label();
+    let netlify_deploy = DeploymentSource::NetlifyDeploy.label();
+    let render_deploy = DeploymentSource::RenderDeploy.label();
+    let aws_code_deploy = DeploymentSource::AwsCodeDeploy.label();
+    let jenkins_build = DeploymentSource::JenkinsBuild.label();
+    let options: Vec<&str> = vec![
+        &github_deployment,
+        &github_pull_request,
+        &heroku_release,
+        &heroku_pipeline,
+        &argo_cd_application,
+        &kubernetes_rollout,
+        &vercel_deployment,
+        &netlify_deploy,
+        &render_deploy,
+        &aws_code_deploy,
+        &jenkins_build,
+    ];
     let answer = Select::new("Select Deployment Frequency Source: ", options).prompt()?;
     let source = DeploymentSource::try_new(answer).expect("Invalid deployment source");
 
@@ -26,6 +49,30 @@ pub async fn perform() -> Result<()> {
         DeploymentSource::HerokuRelease => {
             heroku_release::add_project().await;
         }
+        DeploymentSource::HerokuPipeline => {
+            heroku_pipeline::add_project().await;
+        }
+        DeploymentSource::ArgoCdApplication => {
+            argo_cd_application::add_project().await;
+        }
+        DeploymentSource::KubernetesRollout => {
+            kubernetes_rollout::add_project().await;
+        }
+        DeploymentSource::VercelDeployment => {
+            vercel_deployment::add_project().await;
+        }
+        DeploymentSource::NetlifyDeploy => {
+            netlify_deploy::add_project().await;
+        }
+        DeploymentSource::RenderDeploy => {
+            render_deploy::add_project().await;
+        }
+        DeploymentSource::AwsCodeDeploy => {
+            aws_code_deploy::add_project().await;
+        }
+        DeploymentSource::JenkinsBuild => {
+            jenkins_build::add_project().await;
+        }
     }
 
     Ok(())
@@ -0,0 +1,101 @@
+use crate::{
+    common_types::github_credentials::GitHubCredentials,
+    dependencies::project_config_io::{
+        reader::{
+            interface::ProjectConfigIOReader, settings_toml::ProjectConfigIOReaderWithSettingsToml,
+        },
+        writer::settings_toml::ProjectConfigIOWriterWithSettingsToml,
+    },
+    project_creating::create_project::{
+        CreateProject, CreateProjectWorkflow, UncreatedAwsCodeDeployProject, UncreatedProject,
+    },
+};
+
+use super::input::{
+    aws_code_deploy_application_name, aws_code_deploy_deployment_group_name, aws_ecs_commit_tag,
+    aws_region, developer_count, github_owner_repo, github_personal_token, project_name,
+    working_days_per_week,
+};
+
+pub async fn init() {
+    let project_name = project_name::input();
+    let aws_region = aws_region::input();
+    let aws_code_deploy_application_name = aws_code_deploy_application_name::input();
+    let aws_code_deploy_deployment_group_name = aws_code_deploy_deployment_group_name::input();
+    let aws_ecs_commit_tag = aws_ecs_commit_tag::input();
+    let github_token = github_personal_token::input();
+    let owner_repo = github_owner_repo::input();
+    let developer_count = developer_count::input();
+    let working_days_per_week = working_days_per_week::input();
+
+    let uncreated_project = UncreatedProject::AwsCodeDeploy(UncreatedAwsCodeDeployProject {
+        project_name,
+        github_owner_repo: owner_repo,
+        aws_endpoint_url: None,
+        aws_region,
+        aws_code_deploy_application_name,
+        aws_code_deploy_deployment_group_name,
+        aws_ecs_commit_tag,
+        developer_count,
+        working_days_per_week,
+        github_credentials: GitHubCredentials::PersonalToken(github_token),
+        github_api_base_url: None,
+    });
+
+    let workflow = CreateProjectWorkflow {
+        project_io_writer: ProjectConfigIOWriterWithSettingsToml,
+    };
+
+    match workflow.create_project(uncreated_project).await {
+        Ok(_project) => {
+            println!("Complete project creation!");
+        }
+        Err(err) => {
+            println!("Failed to create project: {:?}", err);
+        }
+    }
+}
+
+pub async fn add_project() {
+    let config = ProjectConfigIOReaderWithSettingsToml
+        .read_globals()
+        .await
+        .expect("Failed to read project config");
+
+    let project_name = project_name::input();
+    let aws_region = aws_region::input();
+    let aws_code_deploy_application_name = aws_code_deploy_application_name::input();
+    let aws_code_deploy_deployment_group_name = aws_code_deploy_deployment_group_name::input();
+    let aws_ecs_commit_tag = aws_ecs_commit_tag::input();
+    let github_token = github_personal_token::input_or_default(config.github_personal_token);
+    let owner_repo = github_owner_repo::input();
+    let developer_count = developer_count::input();
+    let working_days_per_week = working_days_per_week::input();
+
+    let uncreated_project = UncreatedProject::AwsCodeDeploy(UncreatedAwsCodeDeployProject {
+        project_name,
+        github_owner_repo: owner_repo,
+        aws_endpoint_url: None,
+        aws_region,
+        aws_code_deploy_application_name,
+        aws_code_deploy_deployment_group_name,
+        aws_ecs_commit_tag,
+        developer_count,
+        working_days_per_week,
+        github_credentials: GitHubCredentials::PersonalToken(github_token),
+        github_api_base_url: None,
+    });
+
+    let workflow = CreateProjectWorkflow {
+        project_io_writer: ProjectConfigIOWriterWithSettingsToml,
+    };
+
+    match workflow.create_project(uncreated_project).await {
+        Ok(_project) => {
+            println!("Complete project creation!");
+        }
+        Err(err) => {
+            println!("Failed to create project: {:?}", err);
+        }
+    }
+}
@@ -3,6 +3,7 @@ use super::input::{
     project_name, working_days_per_week,
 };
 use crate::{
+    common_types::github_credentials::GitHubCredentials,
     dependencies::project_config_io::{
         reader::{
             interface::ProjectConfigIOReader, settings_toml::ProjectConfigIOReaderWithSettingsToml,
@@ -29,7 +30,8 @@ pub async fn init() {
             github_deploy_branch_name: branch,
             developer_count,
             working_days_per_week,
-            github_personal_token: token,
+            github_credentials: GitHubCredentials::PersonalToken(token),
+            github_api_base_url: None,
         });
     let workflow = CreateProjectWorkflow {
         project_io_writer: ProjectConfigIOWriterWithSettingsToml,
@@ -65,7 +67,8 @@ pub async fn add_project() {
             github_deploy_branch_name: branch,
             developer_count,
             working_days_per_week,
-            github_personal_token: token,
+            github_credentials: GitHubCredentials::PersonalToken(token),
+            github_api_base_url: None,
         });
     let workflow = CreateProjectWorkflow {
         project_io_writer: ProjectConfigIOWriterWithSettingsToml,
@@ -1,4 +1,5 @@
 use crate::{
+    common_types::github_credentials::GitHubCredentials,
     dependencies::project_config_io::{
         reader::{
             interface::ProjectConfigIOReader, settings_toml::ProjectConfigIOReaderWithSettingsToml,
@@ -31,7 +32,8 @@ pub async fn init() {
         heroku_auth_token,
         developer_count,
         working_days_per_week,
-        github_personal_token: github_token,
+        github_credentials: GitHubCredentials::PersonalToken(github_token),
+        github_api_base_url: None,
     });
 
     let workflow = CreateProjectWorkflow {
@@ -69,7 +71,8 @@ pub async fn add_project() {
         heroku_auth_token,
         developer_count,
         working_days_per_week,
-        github_personal_token: github_token,
+        github_credentials: GitHubCredentials::PersonalToken(github_token),
+        github_api_base_url: None,
     });
 
     let workflow = CreateProjectWorkflow {
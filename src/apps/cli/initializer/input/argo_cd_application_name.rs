@@ -0,0 +1,17 @@
+use inquire::Text;
+
+use crate::common_types::argo_cd_application_name::ValidatedArgoCdApplicationName;
+
+pub fn input() -> ValidatedArgoCdApplicationName {
+    let value = Text::new("Type an Argo CD application name: ")
+        .prompt()
+        .unwrap();
+    let value = ValidatedArgoCdApplicationName::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid application name");
+        input()
+    }
+}
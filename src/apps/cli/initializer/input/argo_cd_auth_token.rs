@@ -0,0 +1,19 @@
+use inquire::{Password, PasswordDisplayMode};
+
+use crate::common_types::argo_cd_auth_token::ValidatedArgoCdAuthToken;
+
+pub fn input() -> ValidatedArgoCdAuthToken {
+    let value = Password::new("Type an Argo CD auth token: ")
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .without_confirmation()
+        .prompt()
+        .unwrap();
+    let value = ValidatedArgoCdAuthToken::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid token");
+        input()
+    }
+}
@@ -0,0 +1,18 @@
+use inquire::Text;
+
+use crate::common_types::argo_cd_base_url::ValidatedArgoCdBaseUrl;
+
+pub fn input() -> ValidatedArgoCdBaseUrl {
+    let value = Text::new("Type an Argo CD base url: ")
+        .with_placeholder("https://argocd.example.com")
+        .prompt()
+        .unwrap();
+    let value = ValidatedArgoCdBaseUrl::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid url");
+        input()
+    }
+}
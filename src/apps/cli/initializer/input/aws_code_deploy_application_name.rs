@@ -0,0 +1,17 @@
+use inquire::Text;
+
+use crate::common_types::aws_code_deploy_application_name::ValidatedAwsCodeDeployApplicationName;
+
+pub fn input() -> ValidatedAwsCodeDeployApplicationName {
+    let value = Text::new("Type a CodeDeploy application name: ")
+        .prompt()
+        .unwrap();
+    let value = ValidatedAwsCodeDeployApplicationName::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid application name");
+        input()
+    }
+}
@@ -0,0 +1,17 @@
+use inquire::Text;
+
+use crate::common_types::aws_code_deploy_deployment_group_name::ValidatedAwsCodeDeployDeploymentGroupName;
+
+pub fn input() -> ValidatedAwsCodeDeployDeploymentGroupName {
+    let value = Text::new("Type a CodeDeploy deployment group name: ")
+        .prompt()
+        .unwrap();
+    let value = ValidatedAwsCodeDeployDeploymentGroupName::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid deployment group name");
+        input()
+    }
+}
@@ -0,0 +1,15 @@
+use inquire::Text;
+
+pub fn input() -> Option<String> {
+    let value = Text::new(
+        "Type the task definition tag holding the commit SHA (if blank, use the GitHub revision): ",
+    )
+    .prompt()
+    .unwrap();
+
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
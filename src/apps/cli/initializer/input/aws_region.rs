@@ -0,0 +1,15 @@
+use inquire::Text;
+
+use crate::common_types::aws_region::ValidatedAwsRegion;
+
+pub fn input() -> ValidatedAwsRegion {
+    let value = Text::new("Type an AWS region: ").prompt().unwrap();
+    let value = ValidatedAwsRegion::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid region");
+        input()
+    }
+}
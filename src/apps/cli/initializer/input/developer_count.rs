@@ -16,3 +16,18 @@ pub fn input() -> ValidatedDeveloperCount {
         input()
     }
 }
+
+pub fn input_or_default(default_value: u32) -> ValidatedDeveloperCount {
+    let value = Text::new("Type a Developer count: ")
+        .with_default(&default_value.to_string())
+        .prompt()
+        .unwrap();
+    let value = ValidatedDeveloperCount::new(value);
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid developer count");
+        input_or_default(default_value)
+    }
+}
@@ -6,7 +6,7 @@ pub fn input() -> ValidatedGitHubDeploymentEnvironment {
     let value = Text::new("Type a GitHub Deployment environment name: ")
         .prompt()
         .unwrap();
-    let value = ValidatedGitHubDeploymentEnvironment::new(Some(value));
+    let value = ValidatedGitHubDeploymentEnvironment::new(Some(vec![value]), None);
 
     if let Ok(value) = value {
         value
@@ -16,3 +16,18 @@ pub fn input() -> ValidatedGitHubOwnerRepo {
         input()
     }
 }
+
+pub fn input_or_default(default_value: String) -> ValidatedGitHubOwnerRepo {
+    let value = Text::new("Type a GitHub owner/repo: ")
+        .with_default(&default_value)
+        .prompt()
+        .unwrap();
+    let value = ValidatedGitHubOwnerRepo::new(value);
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid owner/repo");
+        input_or_default(default_value)
+    }
+}
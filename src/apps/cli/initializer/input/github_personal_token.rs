@@ -19,7 +19,7 @@ pub fn input() -> ValidatedGitHubPersonalToken {
 }
 
 pub fn input_or_default(
-    default_value: ValidatedGitHubPersonalToken,
+    default_value: Option<ValidatedGitHubPersonalToken>,
 ) -> ValidatedGitHubPersonalToken {
     let value = Password::new("Type a GitHub Personal access token (if blank, use default): ")
         .with_display_mode(PasswordDisplayMode::Masked)
@@ -31,6 +31,6 @@ pub fn input_or_default(
     if let Ok(value) = value {
         value
     } else {
-        default_value
+        default_value.unwrap_or_else(input)
     }
 }
@@ -0,0 +1,15 @@
+use inquire::Text;
+
+use crate::common_types::heroku_pipeline_name::ValidatedHerokuPipelineName;
+
+pub fn input() -> ValidatedHerokuPipelineName {
+    let value = Text::new("Type a Heroku pipeline name: ").prompt().unwrap();
+    let value = ValidatedHerokuPipelineName::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid name");
+        input()
+    }
+}
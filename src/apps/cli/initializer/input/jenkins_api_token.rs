@@ -0,0 +1,19 @@
+use inquire::{Password, PasswordDisplayMode};
+
+use crate::common_types::jenkins_api_token::ValidatedJenkinsApiToken;
+
+pub fn input() -> ValidatedJenkinsApiToken {
+    let value = Password::new("Type a Jenkins API token: ")
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .without_confirmation()
+        .prompt()
+        .unwrap();
+    let value = ValidatedJenkinsApiToken::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid token");
+        input()
+    }
+}
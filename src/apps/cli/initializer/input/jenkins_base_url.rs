@@ -0,0 +1,18 @@
+use inquire::Text;
+
+use crate::common_types::jenkins_base_url::ValidatedJenkinsBaseUrl;
+
+pub fn input() -> ValidatedJenkinsBaseUrl {
+    let value = Text::new("Type a Jenkins base url: ")
+        .with_placeholder("https://jenkins.example.com")
+        .prompt()
+        .unwrap();
+    let value = ValidatedJenkinsBaseUrl::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid url");
+        input()
+    }
+}
@@ -0,0 +1,17 @@
+use inquire::Text;
+
+use crate::common_types::jenkins_job_name::ValidatedJenkinsJobName;
+
+pub fn input() -> ValidatedJenkinsJobName {
+    let value = Text::new("Type a Jenkins job name (folder/job for a job in a folder): ")
+        .prompt()
+        .unwrap();
+    let value = ValidatedJenkinsJobName::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid job name");
+        input()
+    }
+}
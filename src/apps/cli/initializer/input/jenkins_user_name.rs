@@ -0,0 +1,15 @@
+use inquire::Text;
+
+use crate::common_types::jenkins_user_name::ValidatedJenkinsUserName;
+
+pub fn input() -> ValidatedJenkinsUserName {
+    let value = Text::new("Type a Jenkins user name: ").prompt().unwrap();
+    let value = ValidatedJenkinsUserName::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid user name");
+        input()
+    }
+}
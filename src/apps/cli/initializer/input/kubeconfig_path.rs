@@ -0,0 +1,13 @@
+use inquire::Text;
+
+pub fn input() -> Option<String> {
+    let value = Text::new("Type a kubeconfig path (if blank, use KUBECONFIG or ~/.kube/config): ")
+        .prompt()
+        .unwrap();
+
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
@@ -0,0 +1,13 @@
+use inquire::Text;
+
+pub fn input() -> Option<String> {
+    let value = Text::new("Type a kubeconfig context (if blank, use current-context): ")
+        .prompt()
+        .unwrap();
+
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
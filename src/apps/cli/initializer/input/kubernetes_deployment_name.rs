@@ -0,0 +1,18 @@
+use inquire::Text;
+
+use crate::common_types::kubernetes_deployment_name::ValidatedKubernetesDeploymentName;
+
+pub fn input() -> ValidatedKubernetesDeploymentName {
+    let value = Text::new("Type a Kubernetes deployment name: ")
+        .with_placeholder(" ")
+        .prompt()
+        .unwrap();
+    let value = ValidatedKubernetesDeploymentName::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid web:deployment name");
+        input()
+    }
+}
@@ -0,0 +1,18 @@
+use inquire::Text;
+
+use crate::common_types::kubernetes_namespace::ValidatedKubernetesNamespace;
+
+pub fn input() -> ValidatedKubernetesNamespace {
+    let value = Text::new("Type a Kubernetes namespace: ")
+        .with_placeholder(" ")
+        .prompt()
+        .unwrap();
+    let value = ValidatedKubernetesNamespace::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid production:namespace");
+        input()
+    }
+}
@@ -1,3 +1,10 @@
+pub mod argo_cd_application_name;
+pub mod argo_cd_auth_token;
+pub mod argo_cd_base_url;
+pub mod aws_code_deploy_application_name;
+pub mod aws_code_deploy_deployment_group_name;
+pub mod aws_ecs_commit_tag;
+pub mod aws_region;
 pub mod developer_count;
 pub mod github_deploy_branch_name;
 pub mod github_deployment_environment;
@@ -5,5 +12,22 @@ pub mod github_owner_repo;
 pub mod github_personal_token;
 pub mod heroku_app_name;
 pub mod heroku_auth_token;
+pub mod heroku_pipeline_name;
+pub mod jenkins_api_token;
+pub mod jenkins_base_url;
+pub mod jenkins_job_name;
+pub mod jenkins_user_name;
+pub mod kubeconfig_path;
+pub mod kubernetes_commit_annotation;
+pub mod kubernetes_context;
+pub mod kubernetes_deployment_name;
+pub mod kubernetes_namespace;
+pub mod netlify_auth_token;
+pub mod netlify_site_id;
 pub mod project_name;
+pub mod render_api_key;
+pub mod render_service_id;
+pub mod vercel_auth_token;
+pub mod vercel_project_id;
+pub mod vercel_team_id;
 pub mod working_days_per_week;
@@ -0,0 +1,19 @@
+use inquire::{Password, PasswordDisplayMode};
+
+use crate::common_types::netlify_auth_token::ValidatedNetlifyAuthToken;
+
+pub fn input() -> ValidatedNetlifyAuthToken {
+    let value = Password::new("Type a Netlify personal access token: ")
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .without_confirmation()
+        .prompt()
+        .unwrap();
+    let value = ValidatedNetlifyAuthToken::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid token");
+        input()
+    }
+}
@@ -0,0 +1,15 @@
+use inquire::Text;
+
+use crate::common_types::netlify_site_id::ValidatedNetlifySiteId;
+
+pub fn input() -> ValidatedNetlifySiteId {
+    let value = Text::new("Type a Netlify site id: ").prompt().unwrap();
+    let value = ValidatedNetlifySiteId::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid site id");
+        input()
+    }
+}
@@ -0,0 +1,19 @@
+use inquire::{Password, PasswordDisplayMode};
+
+use crate::common_types::render_api_key::ValidatedRenderApiKey;
+
+pub fn input() -> ValidatedRenderApiKey {
+    let value = Password::new("Type a Render API key: ")
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .without_confirmation()
+        .prompt()
+        .unwrap();
+    let value = ValidatedRenderApiKey::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid token");
+        input()
+    }
+}
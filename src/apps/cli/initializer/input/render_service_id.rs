@@ -0,0 +1,15 @@
+use inquire::Text;
+
+use crate::common_types::render_service_id::ValidatedRenderServiceId;
+
+pub fn input() -> ValidatedRenderServiceId {
+    let value = Text::new("Type a Render service id: ").prompt().unwrap();
+    let value = ValidatedRenderServiceId::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid service id");
+        input()
+    }
+}
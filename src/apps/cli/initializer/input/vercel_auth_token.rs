@@ -0,0 +1,19 @@
+use inquire::{Password, PasswordDisplayMode};
+
+use crate::common_types::vercel_auth_token::ValidatedVercelAuthToken;
+
+pub fn input() -> ValidatedVercelAuthToken {
+    let value = Password::new("Type a Vercel auth token: ")
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .without_confirmation()
+        .prompt()
+        .unwrap();
+    let value = ValidatedVercelAuthToken::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid token");
+        input()
+    }
+}
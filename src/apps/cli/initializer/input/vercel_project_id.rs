@@ -0,0 +1,17 @@
+use inquire::Text;
+
+use crate::common_types::vercel_project_id::ValidatedVercelProjectId;
+
+pub fn input() -> ValidatedVercelProjectId {
+    let value = Text::new("Type a Vercel project id or name: ")
+        .prompt()
+        .unwrap();
+    let value = ValidatedVercelProjectId::new(Some(value));
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid project id");
+        input()
+    }
+}
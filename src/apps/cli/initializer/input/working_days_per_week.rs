@@ -16,3 +16,18 @@ pub fn input() -> ValidatedWorkingDaysPerWeek {
         input()
     }
 }
+
+pub fn input_or_default(default_value: f32) -> ValidatedWorkingDaysPerWeek {
+    let value = Text::new("Type a Working days per week: ")
+        .with_default(&default_value.to_string())
+        .prompt()
+        .unwrap();
+    let value = ValidatedWorkingDaysPerWeek::new(value);
+
+    if let Ok(value) = value {
+        value
+    } else {
+        println!("Invalid working days per week");
+        input_or_default(default_value)
+    }
+}
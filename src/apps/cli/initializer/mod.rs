@@ -1,6 +1,14 @@
 pub mod add_project;
+pub mod argo_cd_application;
+pub mod aws_code_deploy;
 pub mod github_deployment;
 pub mod github_pull_request;
+pub mod heroku_pipeline;
 pub mod heroku_release;
 pub mod init;
 pub mod input;
+pub mod jenkins_build;
+pub mod kubernetes_rollout;
+pub mod netlify_deploy;
+pub mod render_deploy;
+pub mod vercel_deployment;
@@ -2,4 +2,5 @@ pub mod config;
 pub mod four_keys;
 pub mod initializer;
 pub mod project;
+pub mod pulls;
 pub mod sub_commands;
@@ -1,13 +1,198 @@
 use anyhow::Result;
 use clap::Subcommand;
+use inquire::Confirm;
 
-use super::initializer;
+use super::initializer::{
+    self,
+    input::{developer_count, github_owner_repo, working_days_per_week},
+};
+use crate::{
+    common_types::{
+        developer_count::ValidatedDeveloperCount, github_owner_repo::ValidatedGitHubOwnerRepo,
+        working_days_per_week::ValidatedWorkingDaysPerWeek,
+    },
+    dependencies::project_config_io::{
+        reader::{
+            interface::ProjectConfigIOReader, settings_toml::ProjectConfigIOReaderWithSettingsToml,
+        },
+        writer::{
+            interface::{ProjectConfigChanges, ProjectConfigIOWriter},
+            settings_toml::ProjectConfigIOWriterWithSettingsToml,
+        },
+    },
+};
 
 #[derive(Subcommand)]
 pub enum ProjectAction {
     Add {},
+    /// List the projects of the config file
+    List {},
+    /// Print the settings of a project with its tokens masked
+    Show {
+        name: String,
+    },
+    /// Change the settings of a project; without options, ask for each one
+    Edit {
+        name: String,
+
+        #[clap(long, required = false)]
+        developer_count: Option<String>,
+
+        #[clap(long, required = false)]
+        working_days_per_week: Option<String>,
+
+        /// The GitHub repository as owner/repo
+        #[clap(long, required = false)]
+        owner_repo: Option<String>,
+
+        /// Set any other setting by its key in the config file, e.g. `lead_time_start=pull_request_created`;
+        /// lists and booleans are written as JSON, e.g. `paths=["services/api/**"]`
+        #[clap(long = "set", value_name = "KEY=VALUE", required = false)]
+        settings: Vec<String>,
+
+        /// Clear an optional setting by its key in the config file
+        #[clap(long, value_name = "KEY", required = false)]
+        unset: Vec<String>,
+    },
+    /// Remove a project from the config file
+    Remove {
+        name: String,
+
+        /// Remove without asking for confirmation
+        #[clap(long)]
+        yes: bool,
+    },
+    Rename {
+        name: String,
+        new_name: String,
+    },
 }
 
 pub async fn add() -> Result<()> {
     initializer::add_project::perform().await
 }
+
+pub async fn list() -> Result<()> {
+    let projects = ProjectConfigIOReaderWithSettingsToml.list().await?;
+    for project in projects {
+        println!(
+            "{}\t{}\t{}/{}",
+            project.project_name,
+            project.deployment_source,
+            project.github_owner,
+            project.github_repo
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn show(name: String) -> Result<()> {
+    let project_config = ProjectConfigIOReaderWithSettingsToml
+        .read_masked(name)
+        .await?;
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&project_config.settings)?
+    );
+
+    Ok(())
+}
+
+/// A `KEY=VALUE` setting, whose value is read as JSON when it is one and as text otherwise
+fn parse_setting(setting: &str) -> Result<(String, serde_json::Value)> {
+    let (key, value) = setting
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("Expected KEY=VALUE: {:?}", setting))?;
+    let value = serde_json::from_str(value)
+        .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+
+    Ok((key.trim().to_string(), value))
+}
+
+pub async fn edit(
+    name: String,
+    developer_count: Option<String>,
+    working_days_per_week: Option<String>,
+    owner_repo: Option<String>,
+    settings: Vec<String>,
+    unset: Vec<String>,
+) -> Result<()> {
+    let mut settings = settings
+        .iter()
+        .map(|setting| parse_setting(setting))
+        .collect::<Result<Vec<_>>>()?;
+    settings.extend(unset.into_iter().map(|key| (key, serde_json::Value::Null)));
+    let (developer_count, working_days_per_week, owner_repo) = if developer_count.is_none()
+        && working_days_per_week.is_none()
+        && owner_repo.is_none()
+        && settings.is_empty()
+    {
+        let current = ProjectConfigIOReaderWithSettingsToml
+            .read_masked(name.clone())
+            .await?;
+        (
+            Some(developer_count::input_or_default(current.developer_count)),
+            Some(working_days_per_week::input_or_default(
+                current.working_days_per_week,
+            )),
+            Some(github_owner_repo::input_or_default(format!(
+                "{}/{}",
+                current.github_owner, current.github_repo
+            ))),
+        )
+    } else {
+        (
+            developer_count
+                .map(ValidatedDeveloperCount::new)
+                .transpose()?,
+            working_days_per_week
+                .map(ValidatedWorkingDaysPerWeek::new)
+                .transpose()?,
+            owner_repo.map(ValidatedGitHubOwnerRepo::new).transpose()?,
+        )
+    };
+    let (github_owner, github_repo) = owner_repo.map(|it| it.get_values()).unzip();
+    ProjectConfigIOWriterWithSettingsToml
+        .update(
+            name.clone(),
+            ProjectConfigChanges {
+                developer_count: developer_count.map(|it| it.to_u32()),
+                working_days_per_week: working_days_per_week.map(|it| it.to_f32()),
+                github_owner,
+                github_repo,
+                settings,
+            },
+        )
+        .await?;
+    println!("Updated the project {}", name);
+
+    Ok(())
+}
+
+pub async fn remove(name: String, yes: bool) -> Result<()> {
+    let confirmed = yes
+        || Confirm::new(&format!("Remove the project {}?", name))
+            .with_default(false)
+            .prompt()?;
+    if confirmed {
+        ProjectConfigIOWriterWithSettingsToml
+            .remove(name.clone())
+            .await?;
+        println!("Removed the project {}", name);
+    }
+
+    Ok(())
+}
+
+pub async fn rename(name: String, new_name: String) -> Result<()> {
+    if new_name.trim().is_empty() {
+        anyhow::bail!("Invalid project name: {:?}", new_name);
+    }
+    ProjectConfigIOWriterWithSettingsToml
+        .rename(name.clone(), new_name.clone())
+        .await?;
+    println!("Renamed the project {} to {}", name, new_name);
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    common_types::date_time_range::DateTimeRange,
+    dependencies::{
+        project_config_io::reader::{
+            interface::ProjectConfigIOReader, settings_toml::ProjectConfigIOReaderWithSettingsToml,
+        },
+        pull_requests_fetcher::github::PullRequestsFetcherWithGitHub,
+    },
+    project_creating::create_project::ProjectCreated,
+    pulls_retrieving::retrieve_pulls::{
+        RetrievePulls, RetrievePullsEvent, RetrievePullsExecutionContext, RetrievePullsWorkflow,
+    },
+};
+
+fn write_standard_out_from_events(events: Vec<RetrievePullsEvent>) {
+    for event in events {
+        match event {
+            RetrievePullsEvent::RetrievePulls(pulls) => {
+                println!("{}", serde_json::to_string_pretty(&pulls).unwrap());
+            }
+        }
+    }
+}
+
+pub async fn get_pulls(
+    project_name: &str,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Result<()> {
+    let config_reader = ProjectConfigIOReaderWithSettingsToml {};
+    let project_config_dto = config_reader.read(project_name.to_string()).await?;
+    let context = RetrievePullsExecutionContext {
+        timeframe: DateTimeRange::new(since, until)?,
+    };
+    let project_config: ProjectCreated = project_config_dto.try_into()?;
+    let repository = project_config.github_repository();
+
+    let workflow = RetrievePullsWorkflow {
+        pull_requests_fetcher: PullRequestsFetcherWithGitHub {
+            github_credentials: repository.github_credentials,
+            github_api_base_url: repository.github_api_base_url,
+            github_owner_repo: repository.github_owner_repo,
+        },
+    };
+    let events = workflow.retrieve_pulls(context).await?;
+    write_standard_out_from_events(events);
+    Ok(())
+}
@@ -18,6 +18,21 @@ pub enum Action {
 
         #[clap(short, long, global = false, required = true)]
         project: String,
+
+        /// Fail when a deployment was skipped or its lead time is degraded
+        #[clap(long, global = false, required = false)]
+        strict: bool,
+    },
+    /// Pull request throughput, review and cycle time
+    Pulls {
+        #[clap(short, long, global = true, required = false)]
+        since: Option<String>,
+
+        #[clap(short, long, global = true, required = false)]
+        until: Option<String>,
+
+        #[clap(short, long, global = false, required = true)]
+        project: String,
     },
     Project {
         #[clap(subcommand)]
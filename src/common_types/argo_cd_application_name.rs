@@ -0,0 +1,37 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedArgoCdApplicationName(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateArgoCdApplicationNameError {
+    #[error("InvalidName: {0}")]
+    InvalidName(String),
+    #[error("InvalidName: {0}")]
+    Required(String),
+}
+
+impl ValidatedArgoCdApplicationName {
+    pub fn new(name: Option<String>) -> Result<Self, ValidateArgoCdApplicationNameError> {
+        if let Some(name) = name {
+            if !name.is_empty() {
+                Ok(ValidatedArgoCdApplicationName(name))
+            } else {
+                Err(ValidateArgoCdApplicationNameError::InvalidName(
+                    "Argo CD application name is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateArgoCdApplicationNameError::Required(
+                "Argo CD application name is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedArgoCdApplicationName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+use thiserror::Error;
+
+use crate::shared::redaction::{register_secret, MASK};
+
+#[derive(Clone)]
+pub struct ValidatedArgoCdAuthToken(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateArgoCdAuthTokenError {
+    #[error("InvalidToken: {0}")]
+    InvalidToken(String),
+    #[error("InvalidToken: {0}")]
+    Required(String),
+}
+
+impl ValidatedArgoCdAuthToken {
+    pub fn new(token: Option<String>) -> Result<Self, ValidateArgoCdAuthTokenError> {
+        if let Some(token) = token {
+            if !token.is_empty() {
+                register_secret(&token);
+                Ok(ValidatedArgoCdAuthToken(token))
+            } else {
+                Err(ValidateArgoCdAuthTokenError::InvalidToken(
+                    "Argo CD auth token is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateArgoCdAuthTokenError::Required(
+                "Argo CD auth token is empty".to_string(),
+            ))
+        }
+    }
+
+    /// The raw secret, only for sending it to the API
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ValidatedArgoCdAuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", MASK)
+    }
+}
+
+impl fmt::Debug for ValidatedArgoCdAuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ValidatedArgoCdAuthToken({})", MASK)
+    }
+}
@@ -0,0 +1,39 @@
+use reqwest::Url;
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedArgoCdBaseUrl(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateArgoCdBaseUrlError {
+    #[error("InvalidUrl: {0}")]
+    InvalidUrl(String),
+    #[error("InvalidUrl: {0}")]
+    Required(String),
+}
+
+impl ValidatedArgoCdBaseUrl {
+    pub fn new(url: Option<String>) -> Result<Self, ValidateArgoCdBaseUrlError> {
+        if let Some(url) = url {
+            match Url::parse(&url) {
+                Ok(parsed) if parsed.scheme() == "http" || parsed.scheme() == "https" => Ok(
+                    ValidatedArgoCdBaseUrl(url.trim_end_matches('/').to_string()),
+                ),
+                _ => Err(ValidateArgoCdBaseUrlError::InvalidUrl(
+                    "Argo CD base url is invalid".to_string(),
+                )),
+            }
+        } else {
+            Err(ValidateArgoCdBaseUrlError::Required(
+                "Argo CD base url is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedArgoCdBaseUrl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
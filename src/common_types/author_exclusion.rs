@@ -0,0 +1,82 @@
+use thiserror::Error;
+use wildmatch::WildMatch;
+
+/// GitHub Apps, Dependabot and Renovate, left out unless `exclude_default_bots` is false
+const DEFAULT_BOT_AUTHORS: [&str; 3] = ["*[bot]", "dependabot*", "renovate*"];
+
+/// The deployment creators and commit authors left out of the metrics, as login globs
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorExclusion {
+    patterns: Vec<WildMatch>,
+}
+
+#[derive(Debug, Error)]
+pub enum ValidateAuthorExclusionError {
+    #[error("An excluded author must not be empty")]
+    EmptyPattern,
+}
+
+impl Default for AuthorExclusion {
+    fn default() -> Self {
+        Self {
+            patterns: DEFAULT_BOT_AUTHORS
+                .iter()
+                .map(|pattern| WildMatch::new(pattern))
+                .collect(),
+        }
+    }
+}
+
+impl AuthorExclusion {
+    pub fn new(
+        excluded_authors: Option<Vec<String>>,
+        exclude_default_bots: Option<bool>,
+    ) -> Result<Self, ValidateAuthorExclusionError> {
+        let excluded_authors = excluded_authors.unwrap_or_default();
+        if excluded_authors
+            .iter()
+            .any(|author| author.trim().is_empty())
+        {
+            return Err(ValidateAuthorExclusionError::EmptyPattern);
+        }
+        let defaults = if exclude_default_bots.unwrap_or(true) {
+            Self::default().patterns
+        } else {
+            vec![]
+        };
+        Ok(Self {
+            patterns: defaults
+                .into_iter()
+                .chain(
+                    excluded_authors
+                        .iter()
+                        .map(|author| WildMatch::new(author.trim())),
+                )
+                .collect(),
+        })
+    }
+
+    /// An unknown author, such as a commit without a GitHub account, is never excluded
+    pub fn is_excluded(&self, login: &str) -> bool {
+        !login.is_empty() && self.patterns.iter().any(|pattern| pattern.matches(login))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuthorExclusion;
+
+    #[test]
+    fn excludes_default_bots_and_configured_authors() {
+        let exclusion = AuthorExclusion::new(Some(vec!["deploy-*".to_string()]), None).unwrap();
+        assert!(exclusion.is_excluded("dependabot[bot]"));
+        assert!(exclusion.is_excluded("renovate-bot"));
+        assert!(exclusion.is_excluded("deploy-robot"));
+        assert!(!exclusion.is_excluded("octocat"));
+        assert!(!exclusion.is_excluded(""));
+
+        let without_defaults = AuthorExclusion::new(None, Some(false)).unwrap();
+        assert!(!without_defaults.is_excluded("dependabot[bot]"));
+        assert!(AuthorExclusion::new(Some(vec![" ".to_string()]), None).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedAwsCodeDeployApplicationName(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateAwsCodeDeployApplicationNameError {
+    #[error("InvalidName: {0}")]
+    InvalidName(String),
+    #[error("InvalidName: {0}")]
+    Required(String),
+}
+
+impl ValidatedAwsCodeDeployApplicationName {
+    pub fn new(name: Option<String>) -> Result<Self, ValidateAwsCodeDeployApplicationNameError> {
+        if let Some(name) = name {
+            if !name.is_empty() {
+                Ok(ValidatedAwsCodeDeployApplicationName(name))
+            } else {
+                Err(ValidateAwsCodeDeployApplicationNameError::InvalidName(
+                    "CodeDeploy application name is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateAwsCodeDeployApplicationNameError::Required(
+                "CodeDeploy application name is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedAwsCodeDeployApplicationName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
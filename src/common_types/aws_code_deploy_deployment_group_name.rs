@@ -0,0 +1,39 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedAwsCodeDeployDeploymentGroupName(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateAwsCodeDeployDeploymentGroupNameError {
+    #[error("InvalidName: {0}")]
+    InvalidName(String),
+    #[error("InvalidName: {0}")]
+    Required(String),
+}
+
+impl ValidatedAwsCodeDeployDeploymentGroupName {
+    pub fn new(
+        name: Option<String>,
+    ) -> Result<Self, ValidateAwsCodeDeployDeploymentGroupNameError> {
+        if let Some(name) = name {
+            if !name.is_empty() {
+                Ok(ValidatedAwsCodeDeployDeploymentGroupName(name))
+            } else {
+                Err(ValidateAwsCodeDeployDeploymentGroupNameError::InvalidName(
+                    "CodeDeploy deployment group name is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateAwsCodeDeployDeploymentGroupNameError::Required(
+                "CodeDeploy deployment group name is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedAwsCodeDeployDeploymentGroupName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
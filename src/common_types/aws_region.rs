@@ -0,0 +1,37 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedAwsRegion(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateAwsRegionError {
+    #[error("InvalidRegion: {0}")]
+    InvalidRegion(String),
+    #[error("InvalidRegion: {0}")]
+    Required(String),
+}
+
+impl ValidatedAwsRegion {
+    pub fn new(region: Option<String>) -> Result<Self, ValidateAwsRegionError> {
+        if let Some(region) = region {
+            if !region.is_empty() {
+                Ok(ValidatedAwsRegion(region))
+            } else {
+                Err(ValidateAwsRegionError::InvalidRegion(
+                    "AWS region is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateAwsRegionError::Required(
+                "AWS region is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedAwsRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -42,6 +42,10 @@ impl DateTimeRange {
         self.since
     }
 
+    pub fn get_until(&self) -> DateTime<Utc> {
+        self.until
+    }
+
     pub fn num_days(&self) -> i64 {
         self.until.signed_duration_since(self.since).num_days() + 1
     }
@@ -3,16 +3,40 @@ use serde::{Deserialize, Serialize};
 const GITHUB_DEPLOYMENT: &str = "git_hub_deployment";
 const GITHUB_PULL_REQUEST: &str = "git_pull_request";
 const HEROKU_RELEASE: &str = "heroku_release";
+const HEROKU_PIPELINE: &str = "heroku_pipeline";
+const ARGO_CD_APPLICATION: &str = "argo_cd_application";
+const KUBERNETES_ROLLOUT: &str = "kubernetes_rollout";
+const VERCEL_DEPLOYMENT: &str = "vercel_deployment";
+const NETLIFY_DEPLOY: &str = "netlify_deploy";
+const RENDER_DEPLOY: &str = "render_deploy";
+const AWS_CODE_DEPLOY: &str = "aws_code_deploy";
+const JENKINS_BUILD: &str = "jenkins_build";
 
 const DISPLAY_GITHUB_DEPLOYMENT: &str = "GitHub Deployment";
 const DISPLAY_GITHUB_PULL_REQUEST: &str = "GitHub Pull Request";
 const DISPLAY_HEROKU_RELEASE: &str = "Heroku Release";
+const DISPLAY_HEROKU_PIPELINE: &str = "Heroku Pipeline";
+const DISPLAY_ARGO_CD_APPLICATION: &str = "Argo CD Application";
+const DISPLAY_KUBERNETES_ROLLOUT: &str = "Kubernetes Rollout";
+const DISPLAY_VERCEL_DEPLOYMENT: &str = "Vercel Deployment";
+const DISPLAY_NETLIFY_DEPLOY: &str = "Netlify Deploy";
+const DISPLAY_RENDER_DEPLOY: &str = "Render Deploy";
+const DISPLAY_AWS_CODE_DEPLOY: &str = "AWS CodeDeploy";
+const DISPLAY_JENKINS_BUILD: &str = "Jenkins Build";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum DeploymentSource {
     GitHubDeployment,
     GitHubPullRequest,
     HerokuRelease,
+    HerokuPipeline,
+    ArgoCdApplication,
+    KubernetesRollout,
+    VercelDeployment,
+    NetlifyDeploy,
+    RenderDeploy,
+    AwsCodeDeploy,
+    JenkinsBuild,
 }
 
 impl DeploymentSource {
@@ -21,9 +45,25 @@ impl DeploymentSource {
             GITHUB_DEPLOYMENT => Ok(DeploymentSource::GitHubDeployment),
             GITHUB_PULL_REQUEST => Ok(DeploymentSource::GitHubPullRequest),
             HEROKU_RELEASE => Ok(DeploymentSource::HerokuRelease),
+            HEROKU_PIPELINE => Ok(DeploymentSource::HerokuPipeline),
+            ARGO_CD_APPLICATION => Ok(DeploymentSource::ArgoCdApplication),
+            KUBERNETES_ROLLOUT => Ok(DeploymentSource::KubernetesRollout),
+            VERCEL_DEPLOYMENT => Ok(DeploymentSource::VercelDeployment),
+            NETLIFY_DEPLOY => Ok(DeploymentSource::NetlifyDeploy),
+            RENDER_DEPLOY => Ok(DeploymentSource::RenderDeploy),
+            AWS_CODE_DEPLOY => Ok(DeploymentSource::AwsCodeDeploy),
+            JENKINS_BUILD => Ok(DeploymentSource::JenkinsBuild),
             DISPLAY_GITHUB_DEPLOYMENT => Ok(DeploymentSource::GitHubDeployment),
             DISPLAY_GITHUB_PULL_REQUEST => Ok(DeploymentSource::GitHubPullRequest),
             DISPLAY_HEROKU_RELEASE => Ok(DeploymentSource::HerokuRelease),
+            DISPLAY_HEROKU_PIPELINE => Ok(DeploymentSource::HerokuPipeline),
+            DISPLAY_ARGO_CD_APPLICATION => Ok(DeploymentSource::ArgoCdApplication),
+            DISPLAY_KUBERNETES_ROLLOUT => Ok(DeploymentSource::KubernetesRollout),
+            DISPLAY_VERCEL_DEPLOYMENT => Ok(DeploymentSource::VercelDeployment),
+            DISPLAY_NETLIFY_DEPLOY => Ok(DeploymentSource::NetlifyDeploy),
+            DISPLAY_RENDER_DEPLOY => Ok(DeploymentSource::RenderDeploy),
+            DISPLAY_AWS_CODE_DEPLOY => Ok(DeploymentSource::AwsCodeDeploy),
+            DISPLAY_JENKINS_BUILD => Ok(DeploymentSource::JenkinsBuild),
             _ => Err("Invalid deployment source"),
         }
     }
@@ -32,6 +72,14 @@ impl DeploymentSource {
             DeploymentSource::GitHubDeployment => DISPLAY_GITHUB_DEPLOYMENT.to_string(),
             DeploymentSource::GitHubPullRequest => DISPLAY_GITHUB_PULL_REQUEST.to_string(),
             DeploymentSource::HerokuRelease => DISPLAY_HEROKU_RELEASE.to_string(),
+            DeploymentSource::HerokuPipeline => DISPLAY_HEROKU_PIPELINE.to_string(),
+            DeploymentSource::ArgoCdApplication => DISPLAY_ARGO_CD_APPLICATION.to_string(),
+            DeploymentSource::KubernetesRollout => DISPLAY_KUBERNETES_ROLLOUT.to_string(),
+            DeploymentSource::VercelDeployment => DISPLAY_VERCEL_DEPLOYMENT.to_string(),
+            DeploymentSource::NetlifyDeploy => DISPLAY_NETLIFY_DEPLOY.to_string(),
+            DeploymentSource::RenderDeploy => DISPLAY_RENDER_DEPLOY.to_string(),
+            DeploymentSource::AwsCodeDeploy => DISPLAY_AWS_CODE_DEPLOY.to_string(),
+            DeploymentSource::JenkinsBuild => DISPLAY_JENKINS_BUILD.to_string(),
         }
     }
     pub fn value(self) -> String {
@@ -39,6 +87,14 @@ impl DeploymentSource {
             DeploymentSource::GitHubDeployment => GITHUB_DEPLOYMENT.to_string(),
             DeploymentSource::GitHubPullRequest => GITHUB_PULL_REQUEST.to_string(),
             DeploymentSource::HerokuRelease => HEROKU_RELEASE.to_string(),
+            DeploymentSource::HerokuPipeline => HEROKU_PIPELINE.to_string(),
+            DeploymentSource::ArgoCdApplication => ARGO_CD_APPLICATION.to_string(),
+            DeploymentSource::KubernetesRollout => KUBERNETES_ROLLOUT.to_string(),
+            DeploymentSource::VercelDeployment => VERCEL_DEPLOYMENT.to_string(),
+            DeploymentSource::NetlifyDeploy => NETLIFY_DEPLOY.to_string(),
+            DeploymentSource::RenderDeploy => RENDER_DEPLOY.to_string(),
+            DeploymentSource::AwsCodeDeploy => AWS_CODE_DEPLOY.to_string(),
+            DeploymentSource::JenkinsBuild => JENKINS_BUILD.to_string(),
         }
     }
 }
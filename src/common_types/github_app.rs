@@ -0,0 +1,66 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone, PartialEq)]
+pub struct ValidatedGitHubApp {
+    app_id: u64,
+    private_key_path: String,
+    installation_id: u64,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateGitHubAppError {
+    #[error("InvalidApp: {0}")]
+    InvalidApp(String),
+    #[error("InvalidApp: {0}")]
+    Required(String),
+}
+
+impl ValidatedGitHubApp {
+    pub fn new(
+        app_id: Option<u64>,
+        private_key_path: Option<String>,
+        installation_id: Option<u64>,
+    ) -> Result<Self, ValidateGitHubAppError> {
+        match (app_id, private_key_path, installation_id) {
+            (Some(app_id), Some(private_key_path), Some(installation_id)) => {
+                if private_key_path.is_empty() {
+                    Err(ValidateGitHubAppError::InvalidApp(
+                        "GitHub App private key path is empty".to_string(),
+                    ))
+                } else {
+                    Ok(ValidatedGitHubApp {
+                        app_id,
+                        private_key_path,
+                        installation_id,
+                    })
+                }
+            }
+            _ => Err(ValidateGitHubAppError::Required(
+                "GitHub App needs an app id, a private key path and an installation id".to_string(),
+            )),
+        }
+    }
+
+    pub fn get_app_id(&self) -> u64 {
+        self.app_id
+    }
+
+    pub fn get_private_key_path(&self) -> String {
+        self.private_key_path.clone()
+    }
+
+    pub fn get_installation_id(&self) -> u64 {
+        self.installation_id
+    }
+}
+
+impl fmt::Display for ValidatedGitHubApp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "app {} (installation {})",
+            self.app_id, self.installation_id
+        )
+    }
+}
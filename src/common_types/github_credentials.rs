@@ -0,0 +1,8 @@
+use super::{github_app::ValidatedGitHubApp, github_personal_token::ValidatedGitHubPersonalToken};
+
+/// How to authenticate to GitHub: as a user with a token, or as an installation of a GitHub App
+#[derive(Clone)]
+pub enum GitHubCredentials {
+    PersonalToken(ValidatedGitHubPersonalToken),
+    App(ValidatedGitHubApp),
+}
@@ -1,8 +1,35 @@
 use std::fmt;
 use thiserror::Error;
+use wildmatch::WildMatch;
 
-#[derive(Clone)]
-pub struct ValidatedGitHubDeploymentEnvironment(pub(super) String);
+const COUNT_EACH: &str = "count_each";
+const DEDUPE_BY_COMMIT: &str = "dedupe_by_commit";
+
+/// How the deployments of several environments are merged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GitHubDeploymentEnvironmentMerge {
+    /// Every deployment counts, so a commit shipped to three regions is three deployments
+    #[default]
+    CountEach,
+    /// A commit counts once, when it was first deployed to any of the environments
+    DedupeByCommit,
+}
+
+impl GitHubDeploymentEnvironmentMerge {
+    pub fn value(&self) -> &'static str {
+        match self {
+            GitHubDeploymentEnvironmentMerge::CountEach => COUNT_EACH,
+            GitHubDeploymentEnvironmentMerge::DedupeByCommit => DEDUPE_BY_COMMIT,
+        }
+    }
+}
+
+/// The names of the GitHub deployment environments of a project, as globs
+#[derive(Debug, Clone)]
+pub struct ValidatedGitHubDeploymentEnvironment {
+    names: Vec<String>,
+    merge: GitHubDeploymentEnvironmentMerge,
+}
 
 #[derive(Debug, Error, Clone)]
 pub enum ValidateGitHubDeploymentEnvironmentError {
@@ -13,25 +40,99 @@ pub enum ValidateGitHubDeploymentEnvironmentError {
 }
 
 impl ValidatedGitHubDeploymentEnvironment {
-    pub fn new(token: Option<String>) -> Result<Self, ValidateGitHubDeploymentEnvironmentError> {
-        if let Some(token) = token {
-            if !token.is_empty() {
-                Ok(ValidatedGitHubDeploymentEnvironment(token))
-            } else {
-                Err(ValidateGitHubDeploymentEnvironmentError::Invalid(
-                    "GitHub deployment environment name is invalid".to_string(),
-                ))
+    pub fn new(
+        names: Option<Vec<String>>,
+        merge: Option<String>,
+    ) -> Result<Self, ValidateGitHubDeploymentEnvironmentError> {
+        let names = names.unwrap_or_default();
+        if names.is_empty() {
+            return Err(ValidateGitHubDeploymentEnvironmentError::Required(
+                "GitHub deployment environment name is empty".to_string(),
+            ));
+        }
+        if names.iter().any(|name| name.is_empty()) {
+            return Err(ValidateGitHubDeploymentEnvironmentError::Invalid(
+                "GitHub deployment environment name is invalid".to_string(),
+            ));
+        }
+        let merge = match merge.as_deref() {
+            None | Some(COUNT_EACH) => GitHubDeploymentEnvironmentMerge::CountEach,
+            Some(DEDUPE_BY_COMMIT) => GitHubDeploymentEnvironmentMerge::DedupeByCommit,
+            Some(other) => {
+                return Err(ValidateGitHubDeploymentEnvironmentError::Invalid(format!(
+                    "Specify count_each or dedupe_by_commit: {}",
+                    other
+                )))
             }
+        };
+        Ok(ValidatedGitHubDeploymentEnvironment { names, merge })
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+
+    pub fn merge(&self) -> GitHubDeploymentEnvironmentMerge {
+        self.merge
+    }
+
+    /// The names to filter on in the query, none when a glob needs every environment read
+    pub fn literal_names(&self) -> Option<Vec<String>> {
+        if self
+            .names
+            .iter()
+            .any(|name| name.contains('*') || name.contains('?'))
+        {
+            None
         } else {
-            Err(ValidateGitHubDeploymentEnvironmentError::Required(
-                "GitHub deployment environment name is empty".to_string(),
-            ))
+            Some(self.names.clone())
         }
     }
+
+    pub fn is_match(&self, environment: &str) -> bool {
+        self.names
+            .iter()
+            .any(|name| WildMatch::new(name).matches(environment))
+    }
 }
 
 impl fmt::Display for ValidatedGitHubDeploymentEnvironment {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.names.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GitHubDeploymentEnvironmentMerge, ValidatedGitHubDeploymentEnvironment};
+
+    #[test]
+    fn matches_the_names_and_globs() {
+        let environment = ValidatedGitHubDeploymentEnvironment::new(
+            Some(vec!["production-*".to_string(), "staging".to_string()]),
+            Some("dedupe_by_commit".to_string()),
+        )
+        .unwrap();
+        assert!(environment.is_match("production-eu"));
+        assert!(environment.is_match("staging"));
+        assert!(!environment.is_match("preview"));
+        assert_eq!(environment.literal_names(), None);
+        assert_eq!(
+            environment.merge(),
+            GitHubDeploymentEnvironmentMerge::DedupeByCommit
+        );
+
+        let single =
+            ValidatedGitHubDeploymentEnvironment::new(Some(vec!["production".to_string()]), None)
+                .unwrap();
+        assert_eq!(single.literal_names(), Some(vec!["production".to_string()]));
+        assert_eq!(single.merge(), GitHubDeploymentEnvironmentMerge::CountEach);
+
+        assert!(ValidatedGitHubDeploymentEnvironment::new(Some(vec![]), None).is_err());
+        assert!(ValidatedGitHubDeploymentEnvironment::new(
+            Some(vec!["production".to_string()]),
+            Some("each".to_string())
+        )
+        .is_err());
     }
 }
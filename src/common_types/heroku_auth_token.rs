@@ -1,6 +1,8 @@
 use std::fmt;
 use thiserror::Error;
 
+use crate::shared::redaction::{register_secret, MASK};
+
 #[derive(Clone)]
 pub struct ValidatedHerokuAuthToken(pub(super) String);
 
@@ -16,6 +18,7 @@ impl ValidatedHerokuAuthToken {
     pub fn new(token: Option<String>) -> Result<Self, ValidateHerokuAuthTokenError> {
         if let Some(token) = token {
             if token.len() > 20 {
+                register_secret(&token);
                 Ok(ValidatedHerokuAuthToken(token))
             } else {
                 Err(ValidateHerokuAuthTokenError::InvalidToken(
@@ -28,10 +31,21 @@ impl ValidatedHerokuAuthToken {
             ))
         }
     }
+
+    /// The raw secret, only for sending it to the API
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
 }
 
 impl fmt::Display for ValidatedHerokuAuthToken {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", MASK)
+    }
+}
+
+impl fmt::Debug for ValidatedHerokuAuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ValidatedHerokuAuthToken({})", MASK)
     }
 }
@@ -0,0 +1,37 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedHerokuPipelineName(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateHerokuPipelineNameError {
+    #[error("InvalidName: {0}")]
+    InvalidName(String),
+    #[error("InvalidName: {0}")]
+    Required(String),
+}
+
+impl ValidatedHerokuPipelineName {
+    pub fn new(token: Option<String>) -> Result<Self, ValidateHerokuPipelineNameError> {
+        if let Some(token) = token {
+            if token.len() > 1 {
+                Ok(ValidatedHerokuPipelineName(token))
+            } else {
+                Err(ValidateHerokuPipelineNameError::InvalidName(
+                    "Heroku pipeline name is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateHerokuPipelineNameError::Required(
+                "Heroku pipeline name is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedHerokuPipelineName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+use thiserror::Error;
+
+use crate::shared::redaction::{register_secret, MASK};
+
+#[derive(Clone)]
+pub struct ValidatedJenkinsApiToken(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateJenkinsApiTokenError {
+    #[error("InvalidToken: {0}")]
+    InvalidToken(String),
+    #[error("InvalidToken: {0}")]
+    Required(String),
+}
+
+impl ValidatedJenkinsApiToken {
+    pub fn new(token: Option<String>) -> Result<Self, ValidateJenkinsApiTokenError> {
+        if let Some(token) = token {
+            if !token.is_empty() {
+                register_secret(&token);
+                Ok(ValidatedJenkinsApiToken(token))
+            } else {
+                Err(ValidateJenkinsApiTokenError::InvalidToken(
+                    "Jenkins API token is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateJenkinsApiTokenError::Required(
+                "Jenkins API token is empty".to_string(),
+            ))
+        }
+    }
+
+    /// The raw secret, only for sending it to the API
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ValidatedJenkinsApiToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", MASK)
+    }
+}
+
+impl fmt::Debug for ValidatedJenkinsApiToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ValidatedJenkinsApiToken({})", MASK)
+    }
+}
@@ -0,0 +1,39 @@
+use reqwest::Url;
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedJenkinsBaseUrl(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateJenkinsBaseUrlError {
+    #[error("InvalidUrl: {0}")]
+    InvalidUrl(String),
+    #[error("InvalidUrl: {0}")]
+    Required(String),
+}
+
+impl ValidatedJenkinsBaseUrl {
+    pub fn new(url: Option<String>) -> Result<Self, ValidateJenkinsBaseUrlError> {
+        if let Some(url) = url {
+            match Url::parse(&url) {
+                Ok(parsed) if parsed.scheme() == "http" || parsed.scheme() == "https" => Ok(
+                    ValidatedJenkinsBaseUrl(url.trim_end_matches('/').to_string()),
+                ),
+                _ => Err(ValidateJenkinsBaseUrlError::InvalidUrl(
+                    "Jenkins base url is invalid".to_string(),
+                )),
+            }
+        } else {
+            Err(ValidateJenkinsBaseUrlError::Required(
+                "Jenkins base url is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedJenkinsBaseUrl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
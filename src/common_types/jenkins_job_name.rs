@@ -0,0 +1,37 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedJenkinsJobName(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateJenkinsJobNameError {
+    #[error("InvalidName: {0}")]
+    InvalidName(String),
+    #[error("InvalidName: {0}")]
+    Required(String),
+}
+
+impl ValidatedJenkinsJobName {
+    pub fn new(name: Option<String>) -> Result<Self, ValidateJenkinsJobNameError> {
+        if let Some(name) = name {
+            if !name.is_empty() {
+                Ok(ValidatedJenkinsJobName(name))
+            } else {
+                Err(ValidateJenkinsJobNameError::InvalidName(
+                    "Jenkins job name is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateJenkinsJobNameError::Required(
+                "Jenkins job name is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedJenkinsJobName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
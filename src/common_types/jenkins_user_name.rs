@@ -0,0 +1,37 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedJenkinsUserName(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateJenkinsUserNameError {
+    #[error("InvalidName: {0}")]
+    InvalidName(String),
+    #[error("InvalidName: {0}")]
+    Required(String),
+}
+
+impl ValidatedJenkinsUserName {
+    pub fn new(name: Option<String>) -> Result<Self, ValidateJenkinsUserNameError> {
+        if let Some(name) = name {
+            if !name.is_empty() {
+                Ok(ValidatedJenkinsUserName(name))
+            } else {
+                Err(ValidateJenkinsUserNameError::InvalidName(
+                    "Jenkins user name is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateJenkinsUserNameError::Required(
+                "Jenkins user name is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedJenkinsUserName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,39 @@
+use regex::Regex;
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedKubernetesDeploymentName(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateKubernetesDeploymentNameError {
+    #[error("InvalidName: {0}")]
+    InvalidName(String),
+    #[error("InvalidName: {0}")]
+    Required(String),
+}
+
+impl ValidatedKubernetesDeploymentName {
+    pub fn new(name: Option<String>) -> Result<Self, ValidateKubernetesDeploymentNameError> {
+        if let Some(name) = name {
+            let re = Regex::new(r"^[a-z0-9]([-a-z0-9.]*[a-z0-9])?$").unwrap();
+            if re.is_match(&name) {
+                Ok(ValidatedKubernetesDeploymentName(name))
+            } else {
+                Err(ValidateKubernetesDeploymentNameError::InvalidName(
+                    "Kubernetes deployment name is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateKubernetesDeploymentNameError::Required(
+                "Kubernetes deployment name is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedKubernetesDeploymentName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
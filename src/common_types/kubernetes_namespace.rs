@@ -0,0 +1,39 @@
+use regex::Regex;
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedKubernetesNamespace(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateKubernetesNamespaceError {
+    #[error("InvalidName: {0}")]
+    InvalidName(String),
+    #[error("InvalidName: {0}")]
+    Required(String),
+}
+
+impl ValidatedKubernetesNamespace {
+    pub fn new(name: Option<String>) -> Result<Self, ValidateKubernetesNamespaceError> {
+        if let Some(name) = name {
+            let re = Regex::new(r"^[a-z0-9]([-a-z0-9.]*[a-z0-9])?$").unwrap();
+            if re.is_match(&name) {
+                Ok(ValidatedKubernetesNamespace(name))
+            } else {
+                Err(ValidateKubernetesNamespaceError::InvalidName(
+                    "Kubernetes namespace is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateKubernetesNamespaceError::Required(
+                "Kubernetes namespace is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedKubernetesNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,43 @@
+use thiserror::Error;
+
+const REPOSITORY_CREATED: &str = "repository_created";
+const EXCLUDE: &str = "exclude";
+
+/// What the lead time of a deployment without a previous one starts at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeadTimeFallback {
+    /// The repository creation, however long ago it is
+    #[default]
+    RepositoryCreated,
+    /// The repository creation, but at most this many days before the deployment
+    RepositoryCreatedCapped(u32),
+    /// No lead time at all
+    Exclude,
+}
+
+#[derive(Debug, Error)]
+pub enum ValidateLeadTimeFallbackError {
+    #[error("Specify repository_created or exclude: {0}")]
+    Invalid(String),
+    #[error("The cap must be a positive number of days")]
+    InvalidCap,
+}
+
+impl LeadTimeFallback {
+    pub fn new(
+        value: Option<String>,
+        cap_days: Option<u32>,
+    ) -> Result<Self, ValidateLeadTimeFallbackError> {
+        match (value.as_deref(), cap_days) {
+            (Some(EXCLUDE), _) => Ok(LeadTimeFallback::Exclude),
+            (None | Some(REPOSITORY_CREATED), None) => Ok(LeadTimeFallback::RepositoryCreated),
+            (None | Some(REPOSITORY_CREATED), Some(0)) => {
+                Err(ValidateLeadTimeFallbackError::InvalidCap)
+            }
+            (None | Some(REPOSITORY_CREATED), Some(days)) => {
+                Ok(LeadTimeFallback::RepositoryCreatedCapped(days))
+            }
+            (Some(other), _) => Err(ValidateLeadTimeFallbackError::Invalid(other.to_string())),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use thiserror::Error;
+
+const FIRST_COMMIT_AUTHORED: &str = "first_commit_authored";
+const FIRST_COMMIT_COMMITTED: &str = "first_commit_committed";
+const PULL_REQUEST_CREATED: &str = "pull_request_created";
+const PULL_REQUEST_REVIEW_REQUESTED: &str = "pull_request_review_requested";
+const BRANCH_FIRST_PUSHED: &str = "branch_first_pushed";
+
+/// The event a deployment's lead time is measured from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeadTimeStart {
+    #[default]
+    FirstCommitAuthored,
+    FirstCommitCommitted,
+    PullRequestCreated,
+    PullRequestReviewRequested,
+    BranchFirstPushed,
+}
+
+#[derive(Debug, Error)]
+pub enum ValidateLeadTimeStartError {
+    #[error("Specify one of first_commit_authored, first_commit_committed, pull_request_created, pull_request_review_requested or branch_first_pushed: {0}")]
+    Invalid(String),
+}
+
+impl LeadTimeStart {
+    pub fn new(value: Option<String>) -> Result<Self, ValidateLeadTimeStartError> {
+        match value.as_deref() {
+            None => Ok(LeadTimeStart::default()),
+            Some(FIRST_COMMIT_AUTHORED) => Ok(LeadTimeStart::FirstCommitAuthored),
+            Some(FIRST_COMMIT_COMMITTED) => Ok(LeadTimeStart::FirstCommitCommitted),
+            Some(PULL_REQUEST_CREATED) => Ok(LeadTimeStart::PullRequestCreated),
+            Some(PULL_REQUEST_REVIEW_REQUESTED) => Ok(LeadTimeStart::PullRequestReviewRequested),
+            Some(BRANCH_FIRST_PUSHED) => Ok(LeadTimeStart::BranchFirstPushed),
+            Some(other) => Err(ValidateLeadTimeStartError::Invalid(other.to_string())),
+        }
+    }
+    /// Whether the start point is read from the pull requests of the deployed commits
+    pub fn is_pull_request_based(self) -> bool {
+        matches!(
+            self,
+            LeadTimeStart::PullRequestCreated
+                | LeadTimeStart::PullRequestReviewRequested
+                | LeadTimeStart::BranchFirstPushed
+        )
+    }
+}
@@ -1,14 +1,39 @@
+pub mod argo_cd_application_name;
+pub mod argo_cd_auth_token;
+pub mod argo_cd_base_url;
+pub mod author_exclusion;
+pub mod aws_code_deploy_application_name;
+pub mod aws_code_deploy_deployment_group_name;
+pub mod aws_region;
 pub mod commit;
 pub mod daily_items;
 pub mod date_time_range;
 pub mod deploy_branch_name;
 pub mod deployment_source;
 pub mod developer_count;
+pub mod github_app;
+pub mod github_credentials;
 pub mod github_deployment_environment;
 pub mod github_owner_repo;
 pub mod github_personal_token;
 pub mod heroku_app_name;
 pub mod heroku_auth_token;
+pub mod heroku_pipeline_name;
+pub mod jenkins_api_token;
+pub mod jenkins_base_url;
+pub mod jenkins_job_name;
+pub mod jenkins_user_name;
+pub mod kubernetes_deployment_name;
+pub mod kubernetes_namespace;
+pub mod lead_time_fallback;
+pub mod lead_time_start;
 pub mod monthly_items;
+pub mod netlify_auth_token;
+pub mod netlify_site_id;
+pub mod path_filter;
+pub mod render_api_key;
+pub mod render_service_id;
+pub mod vercel_auth_token;
+pub mod vercel_project_id;
 pub mod weekly_items;
 pub mod working_days_per_week;
@@ -0,0 +1,51 @@
+use std::fmt;
+use thiserror::Error;
+
+use crate::shared::redaction::{register_secret, MASK};
+
+#[derive(Clone)]
+pub struct ValidatedNetlifyAuthToken(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateNetlifyAuthTokenError {
+    #[error("InvalidToken: {0}")]
+    InvalidToken(String),
+    #[error("InvalidToken: {0}")]
+    Required(String),
+}
+
+impl ValidatedNetlifyAuthToken {
+    pub fn new(token: Option<String>) -> Result<Self, ValidateNetlifyAuthTokenError> {
+        if let Some(token) = token {
+            if !token.is_empty() {
+                register_secret(&token);
+                Ok(ValidatedNetlifyAuthToken(token))
+            } else {
+                Err(ValidateNetlifyAuthTokenError::InvalidToken(
+                    "Netlify auth token is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateNetlifyAuthTokenError::Required(
+                "Netlify auth token is empty".to_string(),
+            ))
+        }
+    }
+
+    /// The raw secret, only for sending it to the API
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ValidatedNetlifyAuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", MASK)
+    }
+}
+
+impl fmt::Debug for ValidatedNetlifyAuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ValidatedNetlifyAuthToken({})", MASK)
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedNetlifySiteId(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateNetlifySiteIdError {
+    #[error("InvalidId: {0}")]
+    InvalidId(String),
+    #[error("InvalidId: {0}")]
+    Required(String),
+}
+
+impl ValidatedNetlifySiteId {
+    pub fn new(id: Option<String>) -> Result<Self, ValidateNetlifySiteIdError> {
+        if let Some(id) = id {
+            if !id.is_empty() {
+                Ok(ValidatedNetlifySiteId(id))
+            } else {
+                Err(ValidateNetlifySiteIdError::InvalidId(
+                    "Netlify site id is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateNetlifySiteIdError::Required(
+                "Netlify site id is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedNetlifySiteId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
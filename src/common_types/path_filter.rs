@@ -0,0 +1,57 @@
+use thiserror::Error;
+use wildmatch::WildMatch;
+
+/// The paths of a repository a project is scoped to, as globs; no glob means the whole repository
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PathFilter {
+    patterns: Vec<WildMatch>,
+}
+
+#[derive(Debug, Error)]
+pub enum ValidatePathFilterError {
+    #[error("A path must not be empty")]
+    EmptyPattern,
+}
+
+impl PathFilter {
+    pub fn new(paths: Option<Vec<String>>) -> Result<Self, ValidatePathFilterError> {
+        let paths = paths.unwrap_or_default();
+        if paths.iter().any(|path| path.trim().is_empty()) {
+            return Err(ValidatePathFilterError::EmptyPattern);
+        }
+        Ok(Self {
+            patterns: paths
+                .iter()
+                .map(|path| WildMatch::new(path.trim().trim_start_matches('/')))
+                .collect(),
+        })
+    }
+
+    pub fn is_scoped(&self) -> bool {
+        !self.patterns.is_empty()
+    }
+
+    pub fn is_match(&self, file: &str) -> bool {
+        !self.is_scoped() || self.patterns.iter().any(|pattern| pattern.matches(file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PathFilter;
+
+    #[test]
+    fn matches_files_under_the_globs() {
+        let filter = PathFilter::new(Some(vec![
+            "/services/api/*".to_string(),
+            "libs/*.rs".to_string(),
+        ]))
+        .unwrap();
+        assert!(filter.is_match("services/api/src/main.rs"));
+        assert!(filter.is_match("libs/shared/mod.rs"));
+        assert!(!filter.is_match("services/web/index.ts"));
+
+        assert!(PathFilter::default().is_match("anything"));
+        assert!(PathFilter::new(Some(vec!["".to_string()])).is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+use thiserror::Error;
+
+use crate::shared::redaction::{register_secret, MASK};
+
+#[derive(Clone)]
+pub struct ValidatedRenderApiKey(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateRenderApiKeyError {
+    #[error("InvalidToken: {0}")]
+    InvalidToken(String),
+    #[error("InvalidToken: {0}")]
+    Required(String),
+}
+
+impl ValidatedRenderApiKey {
+    pub fn new(token: Option<String>) -> Result<Self, ValidateRenderApiKeyError> {
+        if let Some(token) = token {
+            if !token.is_empty() {
+                register_secret(&token);
+                Ok(ValidatedRenderApiKey(token))
+            } else {
+                Err(ValidateRenderApiKeyError::InvalidToken(
+                    "Render API key is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateRenderApiKeyError::Required(
+                "Render API key is empty".to_string(),
+            ))
+        }
+    }
+
+    /// The raw secret, only for sending it to the API
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ValidatedRenderApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", MASK)
+    }
+}
+
+impl fmt::Debug for ValidatedRenderApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ValidatedRenderApiKey({})", MASK)
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedRenderServiceId(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateRenderServiceIdError {
+    #[error("InvalidId: {0}")]
+    InvalidId(String),
+    #[error("InvalidId: {0}")]
+    Required(String),
+}
+
+impl ValidatedRenderServiceId {
+    pub fn new(id: Option<String>) -> Result<Self, ValidateRenderServiceIdError> {
+        if let Some(id) = id {
+            if !id.is_empty() {
+                Ok(ValidatedRenderServiceId(id))
+            } else {
+                Err(ValidateRenderServiceIdError::InvalidId(
+                    "Render service id is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateRenderServiceIdError::Required(
+                "Render service id is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedRenderServiceId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
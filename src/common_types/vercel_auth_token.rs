@@ -0,0 +1,51 @@
+use std::fmt;
+use thiserror::Error;
+
+use crate::shared::redaction::{register_secret, MASK};
+
+#[derive(Clone)]
+pub struct ValidatedVercelAuthToken(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateVercelAuthTokenError {
+    #[error("InvalidToken: {0}")]
+    InvalidToken(String),
+    #[error("InvalidToken: {0}")]
+    Required(String),
+}
+
+impl ValidatedVercelAuthToken {
+    pub fn new(token: Option<String>) -> Result<Self, ValidateVercelAuthTokenError> {
+        if let Some(token) = token {
+            if !token.is_empty() {
+                register_secret(&token);
+                Ok(ValidatedVercelAuthToken(token))
+            } else {
+                Err(ValidateVercelAuthTokenError::InvalidToken(
+                    "Vercel auth token is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateVercelAuthTokenError::Required(
+                "Vercel auth token is empty".to_string(),
+            ))
+        }
+    }
+
+    /// The raw secret, only for sending it to the API
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ValidatedVercelAuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", MASK)
+    }
+}
+
+impl fmt::Debug for ValidatedVercelAuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ValidatedVercelAuthToken({})", MASK)
+    }
+}
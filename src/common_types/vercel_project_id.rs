@@ -0,0 +1,37 @@
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Clone)]
+pub struct ValidatedVercelProjectId(pub(super) String);
+
+#[derive(Debug, Error, Clone)]
+pub enum ValidateVercelProjectIdError {
+    #[error("InvalidId: {0}")]
+    InvalidId(String),
+    #[error("InvalidId: {0}")]
+    Required(String),
+}
+
+impl ValidatedVercelProjectId {
+    pub fn new(id: Option<String>) -> Result<Self, ValidateVercelProjectIdError> {
+        if let Some(id) = id {
+            if !id.is_empty() {
+                Ok(ValidatedVercelProjectId(id))
+            } else {
+                Err(ValidateVercelProjectIdError::InvalidId(
+                    "Vercel project id is invalid".to_string(),
+                ))
+            }
+        } else {
+            Err(ValidateVercelProjectIdError::Required(
+                "Vercel project id is empty".to_string(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for ValidatedVercelProjectId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
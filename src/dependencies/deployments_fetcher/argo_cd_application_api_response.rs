@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+//------------------------
+// Argo CD Application API
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct ArgoCdApplicationItem {
+    pub(super) metadata: ArgoCdApplicationMetadata,
+    pub(super) status: ArgoCdApplicationStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct ArgoCdApplicationMetadata {
+    pub(super) name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct ArgoCdApplicationStatus {
+    #[serde(default)]
+    pub(super) history: Vec<ArgoCdApplicationHistoryItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub(super) struct ArgoCdApplicationHistoryItem {
+    pub(super) id: u64,
+    pub(super) revision: Option<String>,
+    // Multi-source applications record one revision per source instead of `revision`
+    pub(super) revisions: Option<Vec<String>>,
+    pub(super) deployed_at: chrono::DateTime<chrono::Utc>,
+    pub(super) deploy_started_at: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -0,0 +1,417 @@
+use async_trait::async_trait;
+
+use super::{
+    argo_cd_application_api_response::{ArgoCdApplicationHistoryItem, ArgoCdApplicationItem},
+    argo_cd_application_types::{ArgoCdSync, ArgoCdSyncOrRepositoryInfo},
+    interface::{
+        BaseCommitShaOrRepositoryInfo, DataQualityWarning, DeploymentInfo, DeploymentLog,
+        DeploymentsFetcher, DeploymentsFetcherError, DeploymentsFetcherParams, FetchedDeployments,
+    },
+    shared::{
+        attach_commits, create_http_client, get_commit, get_created_at, GitHubRepositoryInfo,
+    },
+};
+use crate::{
+    common_types::{
+        argo_cd_application_name::ValidatedArgoCdApplicationName,
+        argo_cd_auth_token::ValidatedArgoCdAuthToken, argo_cd_base_url::ValidatedArgoCdBaseUrl,
+        commit::Commit, date_time_range::DateTimeRange, github_credentials::GitHubCredentials,
+        github_owner_repo::ValidatedGitHubOwnerRepo,
+    },
+    shared::non_empty_vec::NonEmptyVec,
+};
+
+async fn fetch_application(
+    argo_cd_base_url: ValidatedArgoCdBaseUrl,
+    argo_cd_auth_token: ValidatedArgoCdAuthToken,
+    argo_cd_application_name: ValidatedArgoCdApplicationName,
+) -> Result<ArgoCdApplicationItem, DeploymentsFetcherError> {
+    let client = create_http_client();
+    let url = format!(
+        "{base_url}/api/v1/applications/{app_name}",
+        base_url = argo_cd_base_url,
+        app_name = argo_cd_application_name
+    );
+    let response = client
+        .get(url)
+        .header(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {token}", token = argo_cd_auth_token.expose()),
+        )
+        .header(reqwest::header::ACCEPT, "application/json")
+        .send()
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::FetchError)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(DeploymentsFetcherError::InvalidResponse(format!(
+            "status: {:?}",
+            status
+        )));
+    }
+    let application = response
+        .json::<ArgoCdApplicationItem>()
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::FetchError)?;
+
+    Ok(application)
+}
+
+fn get_revision(history: &ArgoCdApplicationHistoryItem) -> Option<String> {
+    history
+        .revision
+        .clone()
+        .or_else(|| {
+            history
+                .revisions
+                .as_ref()
+                .and_then(|revisions| revisions.first().cloned())
+        })
+        .filter(|revision| !revision.is_empty())
+}
+
+fn pick_sync_histories(
+    application: ArgoCdApplicationItem,
+) -> Vec<(ArgoCdApplicationHistoryItem, String)> {
+    let mut histories = application.status.history;
+    histories.sort_by_key(|history| history.deployed_at);
+
+    // A re-sync of the same revision does not ship any new change
+    let mut previous_revision: Option<String> = None;
+    histories
+        .into_iter()
+        .filter_map(|history| {
+            let revision = get_revision(&history)?;
+            if previous_revision.as_ref() == Some(&revision) {
+                return None;
+            }
+            previous_revision = Some(revision.clone());
+            Some((history, revision))
+        })
+        .collect()
+}
+
+/// The syncs in the timeframe, and the last one before it as the base of the first
+fn slice_sync_histories(
+    histories: Vec<(ArgoCdApplicationHistoryItem, String)>,
+    timeframe: &DateTimeRange,
+) -> Vec<(ArgoCdApplicationHistoryItem, String)> {
+    let last_before_since = histories
+        .iter()
+        .rposition(|(history, _)| history.deployed_at < timeframe.get_since());
+    histories
+        .into_iter()
+        .enumerate()
+        .filter(|(index, (history, _))| {
+            Some(*index) == last_before_since || timeframe.is_include(&history.deployed_at)
+        })
+        .map(|(_, history)| history)
+        .collect()
+}
+
+fn sync_histories_without_commit(
+    application: &ArgoCdApplicationItem,
+    params: &DeploymentsFetcherParams,
+) -> Vec<DataQualityWarning> {
+    application
+        .status
+        .history
+        .iter()
+        .filter(|history| params.timeframe.is_include(&history.deployed_at))
+        .filter(|history| get_revision(history).is_none())
+        .map(|history| {
+            DataQualityWarning::new(
+                format!("{} sync {}", application.metadata.name, history.id),
+                "Skipped: the sync has no revision",
+            )
+        })
+        .collect()
+}
+
+async fn attach_commit(
+    github_credentials: GitHubCredentials,
+    github_api_base_url: Option<String>,
+    github_owner_repo: ValidatedGitHubOwnerRepo,
+    history: ArgoCdApplicationHistoryItem,
+    revision: String,
+) -> Result<ArgoCdSyncOrRepositoryInfo, DeploymentsFetcherError> {
+    let commit = get_commit(
+        github_owner_repo,
+        github_credentials,
+        github_api_base_url,
+        &revision,
+    )
+    .await?;
+
+    Ok(ArgoCdSyncOrRepositoryInfo::ArgoCdSync(ArgoCdSync {
+        history,
+        revision,
+        commit,
+    }))
+}
+
+fn convert_to_items(
+    sync_nodes: NonEmptyVec<ArgoCdSyncOrRepositoryInfo>,
+) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
+    let mut sorted: NonEmptyVec<ArgoCdSyncOrRepositoryInfo> = sync_nodes;
+    sorted.sort_by_key(|a| match a {
+        ArgoCdSyncOrRepositoryInfo::ArgoCdSync(sync) => sync.history.deployed_at,
+        ArgoCdSyncOrRepositoryInfo::RepositoryInfo(info) => info.created_at,
+    });
+    let (first_item, rest) = sorted.get();
+
+    let rest = rest
+        .into_iter()
+        .flat_map(|x| match x {
+            ArgoCdSyncOrRepositoryInfo::ArgoCdSync(sync) => Some(sync),
+            ArgoCdSyncOrRepositoryInfo::RepositoryInfo(_info) => None,
+        })
+        .collect::<Vec<ArgoCdSync>>();
+
+    let first_commit: BaseCommitShaOrRepositoryInfo = match first_item {
+        ArgoCdSyncOrRepositoryInfo::ArgoCdSync(sync) => {
+            BaseCommitShaOrRepositoryInfo::BaseCommitSha(sync.commit.sha)
+        }
+        ArgoCdSyncOrRepositoryInfo::RepositoryInfo(info) => {
+            BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(info.created_at)
+        }
+    };
+
+    let deployment_items = rest
+        .iter()
+        .scan(
+            first_commit,
+            |previous: &mut BaseCommitShaOrRepositoryInfo, sync: &ArgoCdSync| {
+                let committed_at = sync.commit.commit.author.as_ref().and_then(|x| x.date);
+                let creator_login = sync
+                    .commit
+                    .author
+                    .as_ref()
+                    .map(|x| x.login.clone())
+                    .unwrap_or_default();
+                let commit_item = Commit {
+                    sha: sync.commit.sha.clone(),
+                    message: sync.commit.commit.message.clone(),
+                    resource_path: sync.commit.html_url.clone(),
+                    committed_at: committed_at.unwrap_or(sync.history.deployed_at),
+                    creator_login: creator_login.clone(),
+                };
+                let deployment_item = DeploymentLog {
+                    info: DeploymentInfo::ArgoCdSync {
+                        id: sync.history.id,
+                        revision: sync.revision.clone(),
+                    },
+                    head_commit: commit_item,
+                    base: previous.clone(),
+                    creator_login,
+                    deployed_at: sync.history.deployed_at,
+                };
+                *previous = BaseCommitShaOrRepositoryInfo::BaseCommitSha(sync.commit.sha.clone());
+                Some(deployment_item)
+            },
+        )
+        .collect::<Vec<DeploymentLog>>();
+
+    Ok(deployment_items)
+}
+
+pub struct DeploymentsFetcherWithArgoCdApplication {
+    pub argo_cd_base_url: ValidatedArgoCdBaseUrl,
+    pub argo_cd_auth_token: ValidatedArgoCdAuthToken,
+    pub argo_cd_application_name: ValidatedArgoCdApplicationName,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+}
+#[async_trait]
+impl DeploymentsFetcher for DeploymentsFetcherWithArgoCdApplication {
+    async fn fetch(
+        &self,
+        params: DeploymentsFetcherParams,
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
+        let application = fetch_application(
+            self.argo_cd_base_url.clone(),
+            self.argo_cd_auth_token.clone(),
+            self.argo_cd_application_name.clone(),
+        )
+        .await?;
+        log::debug!(
+            "argo cd application: {:?}, history: {:?}",
+            application.metadata.name,
+            application.status.history.len()
+        );
+        let mut warnings = sync_histories_without_commit(&application, &params);
+        let application_name = application.metadata.name.clone();
+        let histories = slice_sync_histories(pick_sync_histories(application), &params.timeframe);
+        let (mut syncs, unresolved) = attach_commits(
+            histories
+                .into_iter()
+                .map(|(history, revision)| {
+                    (
+                        format!("{} sync {}", application_name, history.id),
+                        attach_commit(
+                            self.github_credentials.clone(),
+                            self.github_api_base_url.clone(),
+                            self.github_owner_repo.clone(),
+                            history,
+                            revision,
+                        ),
+                    )
+                })
+                .collect(),
+            "Skipped: the revision is not a commit on GitHub",
+        )
+        .await?;
+        warnings.extend(unresolved);
+        let repo_created_at = get_created_at(
+            &self.github_credentials,
+            self.github_api_base_url.as_deref(),
+            &self.github_owner_repo,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::GetRepositoryCreatedAtError)?;
+        log::debug!("repo_created_at: {:#?}", repo_created_at);
+        syncs.push(ArgoCdSyncOrRepositoryInfo::RepositoryInfo(
+            GitHubRepositoryInfo {
+                created_at: repo_created_at,
+            },
+        ));
+        let non_empty_nodes = NonEmptyVec::new(syncs)
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::DeploymentsFetcherResultIsEmptyList)?;
+
+        convert_to_items(non_empty_nodes).map(|logs| {
+            FetchedDeployments::from(logs)
+                .with_warnings(warnings)
+                .excluding(&params.author_exclusion)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{convert_to_items, fetch_application, pick_sync_histories, slice_sync_histories};
+    use crate::{
+        common_types::{
+            argo_cd_application_name::ValidatedArgoCdApplicationName,
+            argo_cd_auth_token::ValidatedArgoCdAuthToken, argo_cd_base_url::ValidatedArgoCdBaseUrl,
+            date_time_range::DateTimeRange,
+        },
+        dependencies::deployments_fetcher::{
+            argo_cd_application_api_response::ArgoCdApplicationHistoryItem,
+            argo_cd_application_types::{ArgoCdSync, ArgoCdSyncOrRepositoryInfo},
+            interface::BaseCommitShaOrRepositoryInfo,
+            shared::GitHubRepositoryInfo,
+        },
+        shared::{datetime_utc::parse, non_empty_vec::NonEmptyVec},
+        tests::factories::repo_commit::build_repo_commit,
+    };
+
+    fn history(id: u64, revision: &str, deployed_at: &str) -> ArgoCdApplicationHistoryItem {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "revision": revision,
+            "deployedAt": deployed_at
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetch_sync_histories_from_application() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/applications/guestbook"))
+            .and(header("authorization", "Bearer argo-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "metadata": { "name": "guestbook" },
+                "status": {
+                    "history": [
+                        { "id": 2, "revision": "bbb", "deployedAt": "2023-03-02T10:00:00Z" },
+                        { "id": 1, "revision": "aaa", "deployedAt": "2023-03-01T10:00:00Z" },
+                        { "id": 3, "revision": "bbb", "deployedAt": "2023-03-03T10:00:00Z" },
+                        { "id": 4, "revisions": ["ccc"], "deployedAt": "2023-03-04T10:00:00Z" }
+                    ]
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let application = fetch_application(
+            ValidatedArgoCdBaseUrl::new(Some(server.uri())).unwrap(),
+            ValidatedArgoCdAuthToken::new(Some("argo-token".to_string())).unwrap(),
+            ValidatedArgoCdApplicationName::new(Some("guestbook".to_string())).unwrap(),
+        )
+        .await
+        .unwrap();
+        let revisions = pick_sync_histories(application)
+            .into_iter()
+            .map(|(history, revision)| (history.id, revision))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            revisions,
+            vec![
+                (1, "aaa".to_string()),
+                (2, "bbb".to_string()),
+                (4, "ccc".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn chain_each_sync_to_the_previous_revision() {
+        let timeframe = DateTimeRange::new(
+            parse("2023-03-01 00:00:00").unwrap(),
+            parse("2023-03-31 00:00:00").unwrap(),
+        )
+        .unwrap();
+        let histories = slice_sync_histories(
+            vec![
+                (history(1, "aaa", "2023-02-01T10:00:00Z"), "aaa".to_string()),
+                (history(2, "bbb", "2023-02-10T10:00:00Z"), "bbb".to_string()),
+                (history(3, "ccc", "2023-03-05T10:00:00Z"), "ccc".to_string()),
+                (history(4, "ddd", "2023-03-10T10:00:00Z"), "ddd".to_string()),
+            ],
+            &timeframe,
+        );
+        assert_eq!(
+            histories.iter().map(|(h, _)| h.id).collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+
+        let mut syncs: Vec<ArgoCdSyncOrRepositoryInfo> = histories
+            .into_iter()
+            .map(|(history, revision)| {
+                ArgoCdSyncOrRepositoryInfo::ArgoCdSync(ArgoCdSync {
+                    commit: build_repo_commit(&revision, "2023-01-01T10:00:00Z"),
+                    history,
+                    revision,
+                })
+            })
+            .collect();
+        syncs.push(ArgoCdSyncOrRepositoryInfo::RepositoryInfo(
+            GitHubRepositoryInfo {
+                created_at: parse("2022-01-01 00:00:00").unwrap(),
+            },
+        ));
+        let logs = convert_to_items(NonEmptyVec::new(syncs).unwrap()).unwrap();
+
+        let bases = logs
+            .iter()
+            .map(|log| match &log.base {
+                BaseCommitShaOrRepositoryInfo::BaseCommitSha(sha) => sha.clone(),
+                BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(_) => "created".to_string(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(bases, vec!["created", "bbb", "ccc"]);
+        assert_eq!(logs[2].head_commit.sha, "ddd");
+    }
+}
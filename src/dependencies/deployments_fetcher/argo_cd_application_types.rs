@@ -0,0 +1,19 @@
+use octocrab::models::repos::RepoCommit;
+
+use super::{
+    argo_cd_application_api_response::ArgoCdApplicationHistoryItem, shared::GitHubRepositoryInfo,
+};
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // most are ArgoCdSync
+pub(super) enum ArgoCdSyncOrRepositoryInfo {
+    ArgoCdSync(ArgoCdSync),
+    RepositoryInfo(GitHubRepositoryInfo),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct ArgoCdSync {
+    pub history: ArgoCdApplicationHistoryItem,
+    pub revision: String,
+    pub commit: RepoCommit,
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+// The JSON 1.1 protocol encodes timestamps as epoch seconds with a fraction
+fn deserialize_epoch_seconds<'de, D>(
+    deserializer: D,
+) -> Result<chrono::DateTime<chrono::Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let seconds = f64::deserialize(deserializer)?;
+    chrono::TimeZone::timestamp_millis_opt(&chrono::Utc, (seconds * 1000.0).round() as i64)
+        .single()
+        .ok_or(serde::de::Error::custom("timestamp is out of range"))
+}
+
+fn deserialize_optional_epoch_seconds<'de, D>(
+    deserializer: D,
+) -> Result<Option<chrono::DateTime<chrono::Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Wrapper(
+        #[serde(deserialize_with = "deserialize_epoch_seconds")] chrono::DateTime<chrono::Utc>,
+    );
+    Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(date_time)| date_time))
+}
+
+//------------------------
+// CodeDeploy ListDeployments
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AwsCodeDeployListDeploymentsResponse {
+    #[serde(default)]
+    pub(super) deployments: Vec<String>,
+    pub(super) next_token: Option<String>,
+}
+
+//------------------------
+// CodeDeploy BatchGetDeployments
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AwsCodeDeployBatchGetDeploymentsResponse {
+    #[serde(default)]
+    pub(super) deployments_info: Vec<AwsCodeDeployDeploymentItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AwsCodeDeployDeploymentItem {
+    pub(super) deployment_id: String,
+    pub(super) status: String,
+    #[serde(deserialize_with = "deserialize_epoch_seconds")]
+    pub(super) create_time: chrono::DateTime<chrono::Utc>,
+    #[serde(default, deserialize_with = "deserialize_optional_epoch_seconds")]
+    pub(super) complete_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub(super) creator: Option<String>,
+    pub(super) revision: Option<AwsCodeDeployRevision>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AwsCodeDeployRevision {
+    pub(super) revision_type: String,
+    pub(super) git_hub_location: Option<AwsCodeDeployGitHubLocation>,
+    pub(super) app_spec_content: Option<AwsCodeDeployAppSpecContent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AwsCodeDeployGitHubLocation {
+    pub(super) repository: Option<String>,
+    pub(super) commit_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct AwsCodeDeployAppSpecContent {
+    pub(super) content: Option<String>,
+}
+
+//------------------------
+// AppSpec file of an ECS deployment
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct AwsEcsAppSpec {
+    #[serde(default)]
+    pub(super) resources: Vec<std::collections::HashMap<String, AwsEcsAppSpecResource>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct AwsEcsAppSpecResource {
+    pub(super) properties: AwsEcsAppSpecProperties,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct AwsEcsAppSpecProperties {
+    pub(super) task_definition: String,
+}
+
+//------------------------
+// ECS DescribeTaskDefinition
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct AwsEcsDescribeTaskDefinitionResponse {
+    #[serde(default)]
+    pub(super) tags: Vec<AwsEcsTag>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct AwsEcsTag {
+    pub(super) key: String,
+    pub(super) value: String,
+}
@@ -0,0 +1,614 @@
+use async_trait::async_trait;
+use futures::future::{join_all, try_join_all};
+use itertools::Itertools;
+use reqwest::Url;
+
+use super::{
+    aws_code_deploy_api_response::{
+        AwsCodeDeployBatchGetDeploymentsResponse, AwsCodeDeployDeploymentItem,
+        AwsCodeDeployListDeploymentsResponse, AwsEcsAppSpec, AwsEcsDescribeTaskDefinitionResponse,
+    },
+    aws_code_deploy_signer::{load_credentials, sign, AwsCredentials, SigningRequest},
+    aws_code_deploy_types::{AwsCodeDeployDeployment, AwsCodeDeployDeploymentOrRepositoryInfo},
+    interface::{
+        BaseCommitShaOrRepositoryInfo, DataQualityWarning, DeploymentInfo, DeploymentLog,
+        DeploymentsFetcher, DeploymentsFetcherError, DeploymentsFetcherParams, FetchedDeployments,
+    },
+    shared::{attach_commits, get_commit, get_created_at, GitHubRepositoryInfo},
+};
+use crate::{
+    common_types::{
+        aws_code_deploy_application_name::ValidatedAwsCodeDeployApplicationName,
+        aws_code_deploy_deployment_group_name::ValidatedAwsCodeDeployDeploymentGroupName,
+        aws_region::ValidatedAwsRegion, commit::Commit, github_credentials::GitHubCredentials,
+        github_owner_repo::ValidatedGitHubOwnerRepo,
+    },
+    shared::{
+        non_empty_vec::NonEmptyVec,
+        rate_limit::{send_with_retry, DEFAULT_RETRY_POLICY},
+    },
+};
+
+const CODE_DEPLOY_SERVICE: &str = "codedeploy";
+const CODE_DEPLOY_TARGET_PREFIX: &str = "CodeDeploy_20141006";
+const ECS_SERVICE: &str = "ecs";
+const ECS_TARGET_PREFIX: &str = "AmazonEC2ContainerServiceV20141113";
+const SUCCEEDED_STATUS: &str = "Succeeded";
+const GITHUB_REVISION_TYPE: &str = "GitHub";
+// BatchGetDeployments accepts at most 25 deployment ids
+const BATCH_GET_LIMIT: usize = 25;
+
+struct AwsJsonClient {
+    credentials: AwsCredentials,
+    region: String,
+    service: &'static str,
+    target_prefix: &'static str,
+    endpoint: String,
+}
+
+impl AwsJsonClient {
+    fn new(
+        credentials: AwsCredentials,
+        region: &ValidatedAwsRegion,
+        service: &'static str,
+        target_prefix: &'static str,
+        endpoint_url: Option<&str>,
+    ) -> Self {
+        let endpoint = endpoint_url
+            .map(|url| url.trim_end_matches('/').to_string())
+            .unwrap_or(format!(
+                "https://{service}.{region}.amazonaws.com",
+                service = service,
+                region = region
+            ));
+        AwsJsonClient {
+            credentials,
+            region: region.to_string(),
+            service,
+            target_prefix,
+            endpoint,
+        }
+    }
+
+    async fn call<T: serde::de::DeserializeOwned>(
+        &self,
+        operation: &str,
+        body: serde_json::Value,
+    ) -> Result<T, DeploymentsFetcherError> {
+        let url = Url::parse(&format!("{}/", self.endpoint))
+            .map_err(|e| DeploymentsFetcherError::CreateAPIClientError(anyhow::anyhow!(e)))?;
+        let payload = body.to_string();
+        let target = format!("{}.{}", self.target_prefix, operation);
+        let headers = [
+            ("content-type", "application/x-amz-json-1.1"),
+            ("x-amz-target", target.as_str()),
+        ];
+        let signed_headers = sign(
+            &self.credentials,
+            &self.region,
+            self.service,
+            SigningRequest {
+                method: "POST",
+                url: &url,
+                headers: &headers,
+                payload: payload.as_bytes(),
+            },
+            chrono::Utc::now(),
+        );
+        // Every call is a POST to the same URL, which the response cache must not serve
+        let mut request = reqwest::Client::new().post(url).body(payload);
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+        for (name, value) in signed_headers {
+            request = request.header(name, value);
+        }
+        let response = send_with_retry(&DEFAULT_RETRY_POLICY, || {
+            request
+                .try_clone()
+                .expect("A request with a string body can be cloned")
+                .send()
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::FetchError)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(DeploymentsFetcherError::InvalidResponse(format!(
+                "{} status: {:?}",
+                operation, status
+            )));
+        }
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)
+    }
+}
+
+async fn fetch_deployments(
+    code_deploy: &AwsJsonClient,
+    application_name: &ValidatedAwsCodeDeployApplicationName,
+    deployment_group_name: &ValidatedAwsCodeDeployDeploymentGroupName,
+    params: &DeploymentsFetcherParams,
+) -> Result<Vec<AwsCodeDeployDeploymentItem>, DeploymentsFetcherError> {
+    let since = params.timeframe.get_since();
+    let mut deployments: Vec<AwsCodeDeployDeploymentItem> = vec![];
+    let mut next_token: Option<String> = None;
+    loop {
+        let mut body = serde_json::json!({
+            "applicationName": application_name.to_string(),
+            "deploymentGroupName": deployment_group_name.to_string(),
+            "includeOnlyStatuses": [SUCCEEDED_STATUS],
+        });
+        if let Some(next_token) = &next_token {
+            body["nextToken"] = serde_json::json!(next_token);
+        }
+        let list: AwsCodeDeployListDeploymentsResponse =
+            code_deploy.call("ListDeployments", body).await?;
+        let pages = try_join_all(list.deployments.chunks(BATCH_GET_LIMIT).map(|ids| {
+            code_deploy.call::<AwsCodeDeployBatchGetDeploymentsResponse>(
+                "BatchGetDeployments",
+                serde_json::json!({ "deploymentIds": ids }),
+            )
+        }))
+        .await?;
+        let page = pages
+            .into_iter()
+            .flat_map(|page| page.deployments_info)
+            .collect::<Vec<_>>();
+        // Deployments come newest first, so the page reaching before the timeframe
+        // already holds the deployment that the first one is compared against
+        let reached_since = page
+            .iter()
+            .map(|deployment| deployment.create_time)
+            .min()
+            .is_none_or(|create_time| create_time < since);
+        deployments.extend(page);
+        match list.next_token {
+            Some(token) if !reached_since => next_token = Some(token),
+            _ => break,
+        }
+    }
+
+    Ok(deployments)
+}
+
+fn find_task_definition(app_spec_content: &str) -> Option<String> {
+    // The AppSpec of an ECS deployment can be either YAML or JSON
+    let app_spec: AwsEcsAppSpec = serde_yaml::from_str(app_spec_content).ok()?;
+    app_spec
+        .resources
+        .into_iter()
+        .flat_map(|resource| resource.into_values())
+        .map(|resource| resource.properties.task_definition)
+        .next()
+}
+
+async fn resolve_commit_sha(
+    ecs: &AwsJsonClient,
+    ecs_commit_tag: Option<&str>,
+    deployment: &AwsCodeDeployDeploymentItem,
+) -> Result<Option<String>, DeploymentsFetcherError> {
+    let Some(revision) = &deployment.revision else {
+        return Ok(None);
+    };
+    if revision.revision_type == GITHUB_REVISION_TYPE {
+        return Ok(revision
+            .git_hub_location
+            .as_ref()
+            .and_then(|location| location.commit_id.clone()));
+    }
+    let (Some(tag), Some(task_definition)) = (
+        ecs_commit_tag,
+        revision
+            .app_spec_content
+            .as_ref()
+            .and_then(|app_spec| app_spec.content.as_deref())
+            .and_then(find_task_definition),
+    ) else {
+        return Ok(None);
+    };
+    let described: AwsEcsDescribeTaskDefinitionResponse = ecs
+        .call(
+            "DescribeTaskDefinition",
+            serde_json::json!({ "taskDefinition": task_definition, "include": ["TAGS"] }),
+        )
+        .await?;
+
+    Ok(described
+        .tags
+        .into_iter()
+        .find(|t| t.key == tag)
+        .map(|t| t.value))
+}
+
+fn pick_deployments(
+    deployments: Vec<(AwsCodeDeployDeploymentItem, Option<String>)>,
+) -> Vec<(AwsCodeDeployDeploymentItem, String)> {
+    deployments
+        .into_iter()
+        .filter(|(deployment, _)| deployment.status == SUCCEEDED_STATUS)
+        .filter_map(|(deployment, sha)| {
+            if sha.is_none() {
+                log::debug!(
+                    "skip deployment without a commit: {}",
+                    deployment.deployment_id
+                );
+            }
+            Some((deployment, sha?))
+        })
+        .sorted_by_key(|(deployment, _)| deployment.create_time)
+        // A redeploy of the same commit does not ship any new change
+        .dedup_by(|(_, a), (_, b)| a == b)
+        .collect()
+}
+
+fn deployments_without_commit(
+    deployments: &[(AwsCodeDeployDeploymentItem, Option<String>)],
+    params: &DeploymentsFetcherParams,
+) -> Vec<DataQualityWarning> {
+    deployments
+        .iter()
+        .filter(|(deployment, sha)| {
+            deployment.status == SUCCEEDED_STATUS
+                && sha.is_none()
+                && params.timeframe.is_include(&deployment.create_time)
+        })
+        .map(|(deployment, _)| {
+            DataQualityWarning::new(
+                deployment.deployment_id.clone(),
+                "Skipped: no commit could be resolved for the deployment",
+            )
+        })
+        .collect()
+}
+
+async fn attach_commit(
+    github_credentials: GitHubCredentials,
+    github_api_base_url: Option<String>,
+    github_owner_repo: ValidatedGitHubOwnerRepo,
+    deployment: AwsCodeDeployDeploymentItem,
+    sha: String,
+) -> Result<AwsCodeDeployDeploymentOrRepositoryInfo, DeploymentsFetcherError> {
+    let commit = get_commit(
+        github_owner_repo,
+        github_credentials,
+        github_api_base_url,
+        &sha,
+    )
+    .await?;
+
+    Ok(
+        AwsCodeDeployDeploymentOrRepositoryInfo::AwsCodeDeployDeployment(AwsCodeDeployDeployment {
+            deployment,
+            commit,
+        }),
+    )
+}
+
+fn convert_to_items(
+    deployment_nodes: NonEmptyVec<AwsCodeDeployDeploymentOrRepositoryInfo>,
+) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
+    let mut sorted: NonEmptyVec<AwsCodeDeployDeploymentOrRepositoryInfo> = deployment_nodes;
+    sorted.sort_by_key(|a| match a {
+        AwsCodeDeployDeploymentOrRepositoryInfo::AwsCodeDeployDeployment(deployment) => {
+            deployment.deployment.create_time
+        }
+        AwsCodeDeployDeploymentOrRepositoryInfo::RepositoryInfo(info) => info.created_at,
+    });
+    let (first_item, rest) = sorted.get();
+
+    let rest = rest
+        .into_iter()
+        .flat_map(|x| match x {
+            AwsCodeDeployDeploymentOrRepositoryInfo::AwsCodeDeployDeployment(deployment) => {
+                Some(deployment)
+            }
+            AwsCodeDeployDeploymentOrRepositoryInfo::RepositoryInfo(_info) => None,
+        })
+        .collect::<Vec<AwsCodeDeployDeployment>>();
+
+    let first_commit: BaseCommitShaOrRepositoryInfo = match first_item {
+        AwsCodeDeployDeploymentOrRepositoryInfo::AwsCodeDeployDeployment(deployment) => {
+            BaseCommitShaOrRepositoryInfo::BaseCommitSha(deployment.commit.sha)
+        }
+        AwsCodeDeployDeploymentOrRepositoryInfo::RepositoryInfo(info) => {
+            BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(info.created_at)
+        }
+    };
+
+    let deployment_items = rest
+        .iter()
+        .scan(
+            first_commit,
+            |previous: &mut BaseCommitShaOrRepositoryInfo, deployment: &AwsCodeDeployDeployment| {
+                let deployed_at = deployment
+                    .deployment
+                    .complete_time
+                    .unwrap_or(deployment.deployment.create_time);
+                let committed_at = deployment
+                    .commit
+                    .commit
+                    .author
+                    .as_ref()
+                    .and_then(|x| x.date);
+                let creator_login = deployment
+                    .commit
+                    .author
+                    .as_ref()
+                    .map(|x| x.login.clone())
+                    .unwrap_or_default();
+                let commit_item = Commit {
+                    sha: deployment.commit.sha.clone(),
+                    message: deployment.commit.commit.message.clone(),
+                    resource_path: deployment.commit.html_url.clone(),
+                    committed_at: committed_at.unwrap_or(deployed_at),
+                    creator_login: creator_login.clone(),
+                };
+                let deployment_item = DeploymentLog {
+                    info: DeploymentInfo::AwsCodeDeployDeployment {
+                        id: deployment.deployment.deployment_id.clone(),
+                    },
+                    head_commit: commit_item,
+                    base: previous.clone(),
+                    creator_login,
+                    deployed_at,
+                };
+                *previous =
+                    BaseCommitShaOrRepositoryInfo::BaseCommitSha(deployment.commit.sha.clone());
+                Some(deployment_item)
+            },
+        )
+        .collect::<Vec<DeploymentLog>>();
+
+    Ok(deployment_items)
+}
+
+pub struct DeploymentsFetcherWithAwsCodeDeploy {
+    pub aws_endpoint_url: Option<String>,
+    pub aws_region: ValidatedAwsRegion,
+    pub aws_code_deploy_application_name: ValidatedAwsCodeDeployApplicationName,
+    pub aws_code_deploy_deployment_group_name: ValidatedAwsCodeDeployDeploymentGroupName,
+    pub aws_ecs_commit_tag: Option<String>,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+}
+#[async_trait]
+impl DeploymentsFetcher for DeploymentsFetcherWithAwsCodeDeploy {
+    async fn fetch(
+        &self,
+        params: DeploymentsFetcherParams,
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
+        let credentials = load_credentials()?;
+        let code_deploy = AwsJsonClient::new(
+            credentials.clone(),
+            &self.aws_region,
+            CODE_DEPLOY_SERVICE,
+            CODE_DEPLOY_TARGET_PREFIX,
+            self.aws_endpoint_url.as_deref(),
+        );
+        let ecs = AwsJsonClient::new(
+            credentials,
+            &self.aws_region,
+            ECS_SERVICE,
+            ECS_TARGET_PREFIX,
+            self.aws_endpoint_url.as_deref(),
+        );
+        let deployments = fetch_deployments(
+            &code_deploy,
+            &self.aws_code_deploy_application_name,
+            &self.aws_code_deploy_deployment_group_name,
+            &params,
+        )
+        .await?;
+        log::debug!("aws code deploy deployments: {:?}", deployments.len());
+        let resolved = join_all(deployments.into_iter().map(|deployment| {
+            let ecs = &ecs;
+            async move {
+                // A task definition that cannot be described leaves the deployment without a commit
+                let sha = resolve_commit_sha(ecs, self.aws_ecs_commit_tag.as_deref(), &deployment)
+                    .await
+                    .unwrap_or_else(|e| {
+                        log::warn!("{}: {:?}", deployment.deployment_id, e);
+                        None
+                    });
+                (deployment, sha)
+            }
+        }))
+        .await;
+        let mut warnings = deployments_without_commit(&resolved, &params);
+        let picked = pick_deployments(resolved);
+        let (mut deployments, unresolved) = attach_commits(
+            picked
+                .into_iter()
+                .map(|(deployment, sha)| {
+                    (
+                        deployment.deployment_id.clone(),
+                        attach_commit(
+                            self.github_credentials.clone(),
+                            self.github_api_base_url.clone(),
+                            self.github_owner_repo.clone(),
+                            deployment,
+                            sha,
+                        ),
+                    )
+                })
+                .collect(),
+            "Skipped: the deployment commit is not a commit on GitHub",
+        )
+        .await?;
+        warnings.extend(unresolved);
+        let repo_created_at = get_created_at(
+            &self.github_credentials,
+            self.github_api_base_url.as_deref(),
+            &self.github_owner_repo,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::GetRepositoryCreatedAtError)?;
+        log::debug!("repo_created_at: {:#?}", repo_created_at);
+        deployments.push(AwsCodeDeployDeploymentOrRepositoryInfo::RepositoryInfo(
+            GitHubRepositoryInfo {
+                created_at: repo_created_at,
+            },
+        ));
+        let non_empty_nodes = NonEmptyVec::new(deployments)
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::DeploymentsFetcherResultIsEmptyList)?;
+
+        convert_to_items(non_empty_nodes).map(|logs| {
+            FetchedDeployments::from(logs)
+                .with_warnings(warnings)
+                .excluding(&params.author_exclusion)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use wiremock::{
+        matchers::{body_partial_json, header, header_exists, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{
+        fetch_deployments, pick_deployments, resolve_commit_sha, AwsJsonClient,
+        CODE_DEPLOY_SERVICE, CODE_DEPLOY_TARGET_PREFIX, ECS_SERVICE, ECS_TARGET_PREFIX,
+    };
+    use crate::{
+        common_types::{
+            author_exclusion::AuthorExclusion,
+            aws_code_deploy_application_name::ValidatedAwsCodeDeployApplicationName,
+            aws_code_deploy_deployment_group_name::ValidatedAwsCodeDeployDeploymentGroupName,
+            aws_region::ValidatedAwsRegion, date_time_range::DateTimeRange,
+        },
+        dependencies::deployments_fetcher::{
+            aws_code_deploy_signer::AwsCredentials, interface::DeploymentsFetcherParams,
+        },
+    };
+
+    fn client(server: &MockServer, service: &'static str, prefix: &'static str) -> AwsJsonClient {
+        AwsJsonClient::new(
+            AwsCredentials {
+                access_key_id: "AKIDEXAMPLE".to_string(),
+                secret_access_key: "secret".to_string(),
+                session_token: None,
+            },
+            &ValidatedAwsRegion::new(Some("ap-northeast-1".to_string())).unwrap(),
+            service,
+            prefix,
+            Some(&server.uri()),
+        )
+    }
+
+    #[tokio::test]
+    async fn fetch_succeeded_deployments_and_resolve_commits() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header(
+                "x-amz-target",
+                "CodeDeploy_20141006.ListDeployments",
+            ))
+            .and(header_exists("authorization"))
+            .and(body_partial_json(serde_json::json!({
+                "applicationName": "my-app",
+                "deploymentGroupName": "production",
+                "includeOnlyStatuses": ["Succeeded"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "deployments": ["d-3", "d-2", "d-1"],
+                "nextToken": "token-2"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header(
+                "x-amz-target",
+                "CodeDeploy_20141006.BatchGetDeployments",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "deploymentsInfo": [
+                    { "deploymentId": "d-3", "status": "Succeeded",
+                      "createTime": 1677924000.5, "completeTime": 1677924600.0,
+                      "revision": { "revisionType": "AppSpecContent",
+                        "appSpecContent": { "content": "version: 0.0\nResources:\n  - TargetService:\n      Type: AWS::ECS::Service\n      Properties:\n        TaskDefinition: \"arn:aws:ecs:ap-northeast-1:123456789012:task-definition/web:42\"\n" } } },
+                    { "deploymentId": "d-2", "status": "Succeeded",
+                      "createTime": 1677837600.0, "completeTime": 1677838200.0,
+                      "revision": { "revisionType": "GitHub",
+                        "gitHubLocation": { "repository": "owner/repo", "commitId": "bbb" } } },
+                    { "deploymentId": "d-1", "status": "Succeeded",
+                      "createTime": 1676887200.0, "completeTime": 1676887800.0,
+                      "revision": { "revisionType": "GitHub",
+                        "gitHubLocation": { "repository": "owner/repo", "commitId": "aaa" } } }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header(
+                "x-amz-target",
+                "AmazonEC2ContainerServiceV20141113.DescribeTaskDefinition",
+            ))
+            .and(body_partial_json(serde_json::json!({
+                "taskDefinition": "arn:aws:ecs:ap-northeast-1:123456789012:task-definition/web:42",
+                "include": ["TAGS"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "taskDefinition": { "family": "web", "revision": 42 },
+                "tags": [{ "key": "git-sha", "value": "ccc" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let timeframe = DateTimeRange::new(
+            Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap(),
+        )
+        .unwrap();
+        let code_deploy = client(&server, CODE_DEPLOY_SERVICE, CODE_DEPLOY_TARGET_PREFIX);
+        let ecs = client(&server, ECS_SERVICE, ECS_TARGET_PREFIX);
+        let deployments = fetch_deployments(
+            &code_deploy,
+            &ValidatedAwsCodeDeployApplicationName::new(Some("my-app".to_string())).unwrap(),
+            &ValidatedAwsCodeDeployDeploymentGroupName::new(Some("production".to_string()))
+                .unwrap(),
+            &DeploymentsFetcherParams {
+                timeframe,
+                author_exclusion: AuthorExclusion::default(),
+            },
+        )
+        .await
+        .unwrap();
+        let mut resolved = vec![];
+        for deployment in deployments {
+            let sha = resolve_commit_sha(&ecs, Some("git-sha"), &deployment)
+                .await
+                .unwrap();
+            resolved.push((deployment, sha));
+        }
+        let picked = pick_deployments(resolved)
+            .into_iter()
+            .map(|(deployment, sha)| (deployment.deployment_id, sha))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            picked,
+            vec![
+                ("d-1".to_string(), "aaa".to_string()),
+                ("d-2".to_string(), "bbb".to_string()),
+                ("d-3".to_string(), "ccc".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use itertools::Itertools;
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+use std::{env, fmt, fs, path::PathBuf};
+
+use super::interface::DeploymentsFetcherError;
+use crate::shared::redaction::{register_secret, MASK};
+
+//------------------------
+// credentials
+//------------------------
+#[derive(Clone)]
+pub(super) struct AwsCredentials {
+    pub(super) access_key_id: String,
+    pub(super) secret_access_key: String,
+    pub(super) session_token: Option<String>,
+}
+
+impl fmt::Debug for AwsCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AwsCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &MASK)
+            .field("session_token", &self.session_token.as_ref().map(|_| MASK))
+            .finish()
+    }
+}
+
+fn register_credentials(credentials: AwsCredentials) -> AwsCredentials {
+    register_secret(&credentials.secret_access_key);
+    if let Some(session_token) = &credentials.session_token {
+        register_secret(session_token);
+    }
+    credentials
+}
+
+fn default_credentials_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("AWS_SHARED_CREDENTIALS_FILE") {
+        return Some(PathBuf::from(path));
+    }
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(".aws").join("credentials"))
+}
+
+fn parse_credentials_file(content: &str, profile: &str) -> Option<AwsCredentials> {
+    let mut in_profile = false;
+    let mut access_key_id = None;
+    let mut secret_access_key = None;
+    let mut session_token = None;
+    for line in content.lines().map(|line| line.trim()) {
+        if line.starts_with('[') && line.ends_with(']') {
+            in_profile = line[1..line.len() - 1].trim() == profile;
+            continue;
+        }
+        if !in_profile {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            let value = Some(value.trim().to_string());
+            match key.trim() {
+                "aws_access_key_id" => access_key_id = value,
+                "aws_secret_access_key" => secret_access_key = value,
+                "aws_session_token" => session_token = value,
+                _ => {}
+            }
+        }
+    }
+
+    Some(AwsCredentials {
+        access_key_id: access_key_id?,
+        secret_access_key: secret_access_key?,
+        session_token,
+    })
+}
+
+/// Resolves credentials the way the AWS CLI does for static keys:
+/// environment variables first, then the shared credentials file.
+pub(super) fn load_credentials() -> Result<AwsCredentials, DeploymentsFetcherError> {
+    if let (Ok(access_key_id), Ok(secret_access_key)) = (
+        env::var("AWS_ACCESS_KEY_ID"),
+        env::var("AWS_SECRET_ACCESS_KEY"),
+    ) {
+        return Ok(register_credentials(AwsCredentials {
+            access_key_id,
+            secret_access_key,
+            session_token: env::var("AWS_SESSION_TOKEN").ok(),
+        }));
+    }
+    let profile = env::var("AWS_PROFILE").unwrap_or("default".to_string());
+    default_credentials_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| parse_credentials_file(&content, &profile))
+        .map(register_credentials)
+        .ok_or(DeploymentsFetcherError::CreateAPIClientError(
+            anyhow::anyhow!("Cannot find AWS credentials for profile {}", profile),
+        ))
+}
+
+//------------------------
+// Signature Version 4
+//------------------------
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn uri_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn canonical_query(url: &Url) -> String {
+    url.query_pairs()
+        .map(|(key, value)| (uri_encode(&key), uri_encode(&value)))
+        .sorted()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .join("&")
+}
+
+pub(super) struct SigningRequest<'a> {
+    pub(super) method: &'a str,
+    pub(super) url: &'a Url,
+    /// Every header that is sent besides `host` and the ones returned by `sign`
+    pub(super) headers: &'a [(&'a str, &'a str)],
+    pub(super) payload: &'a [u8],
+}
+
+/// Returns the headers to add to the request, including `authorization`.
+pub(super) fn sign(
+    credentials: &AwsCredentials,
+    region: &str,
+    service: &str,
+    request: SigningRequest,
+    now: DateTime<Utc>,
+) -> Vec<(String, String)> {
+    let SigningRequest {
+        method,
+        url,
+        headers,
+        payload,
+    } = request;
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+    let host = match url.port() {
+        Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+        None => url.host_str().unwrap_or_default().to_string(),
+    };
+
+    let mut added_headers = vec![("x-amz-date".to_string(), amz_date.clone())];
+    if let Some(session_token) = &credentials.session_token {
+        added_headers.push(("x-amz-security-token".to_string(), session_token.clone()));
+    }
+    let signed = headers
+        .iter()
+        .map(|(name, value)| (name.to_lowercase(), value.trim().to_string()))
+        .chain(std::iter::once(("host".to_string(), host)))
+        .chain(added_headers.clone())
+        .sorted()
+        .collect::<Vec<_>>();
+    let canonical_headers = signed
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value))
+        .join("");
+    let signed_headers = signed.iter().map(|(name, _)| name.as_str()).join(";");
+
+    let canonical_request = [
+        method.to_string(),
+        url.path().to_string(),
+        canonical_query(url),
+        canonical_headers,
+        signed_headers.clone(),
+        hex::encode(Sha256::digest(payload)),
+    ]
+    .join("\n");
+    let scope = format!("{}/{}/{}/aws4_request", date, region, service);
+    let string_to_sign = [
+        "AWS4-HMAC-SHA256".to_string(),
+        amz_date,
+        scope.clone(),
+        hex::encode(Sha256::digest(canonical_request.as_bytes())),
+    ]
+    .join("\n");
+
+    let signing_key = [region, service, "aws4_request"].iter().fold(
+        hmac_sha256(
+            format!("AWS4{}", credentials.secret_access_key).as_bytes(),
+            &date,
+        ),
+        |key, part| hmac_sha256(&key, part),
+    );
+    let signature = hex::encode(hmac_sha256(&signing_key, &string_to_sign));
+
+    added_headers.push((
+        "authorization".to_string(),
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            credentials.access_key_id, scope, signed_headers, signature
+        ),
+    ));
+    added_headers
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use reqwest::Url;
+
+    use super::{parse_credentials_file, sign, AwsCredentials, SigningRequest};
+
+    // The example request of the AWS General Reference for Signature Version 4
+    #[test]
+    fn sign_request_as_documented() {
+        let credentials = AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: None,
+        };
+        let url =
+            Url::parse("https://iam.amazonaws.com/?Action=ListUsers&Version=2010-05-08").unwrap();
+
+        let headers = sign(
+            &credentials,
+            "us-east-1",
+            "iam",
+            SigningRequest {
+                method: "GET",
+                url: &url,
+                headers: &[(
+                    "Content-Type",
+                    "application/x-www-form-urlencoded; charset=utf-8",
+                )],
+                payload: b"",
+            },
+            Utc.with_ymd_and_hms(2015, 8, 30, 12, 36, 0).unwrap(),
+        );
+
+        assert_eq!(
+            headers,
+            vec![
+                ("x-amz-date".to_string(), "20150830T123600Z".to_string()),
+                (
+                    "authorization".to_string(),
+                    "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/iam/aws4_request, SignedHeaders=content-type;host;x-amz-date, Signature=5d672d79c15b13162d9279b0855cfba6789a8edb4c82c400e06b5924a6f2b5d7".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn read_profile_from_credentials_file() {
+        let content = "[default]\naws_access_key_id = AKIDDEFAULT\naws_secret_access_key = default-secret\n\n[deploy]\naws_access_key_id=AKIDDEPLOY\naws_secret_access_key=deploy-secret\naws_session_token=deploy-token\n";
+
+        let credentials = parse_credentials_file(content, "deploy").unwrap();
+
+        assert_eq!(credentials.access_key_id, "AKIDDEPLOY");
+        assert_eq!(credentials.secret_access_key, "deploy-secret");
+        assert_eq!(credentials.session_token, Some("deploy-token".to_string()));
+        assert!(parse_credentials_file(content, "missing").is_none());
+    }
+}
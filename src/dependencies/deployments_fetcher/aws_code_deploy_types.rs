@@ -0,0 +1,18 @@
+use octocrab::models::repos::RepoCommit;
+
+use super::{
+    aws_code_deploy_api_response::AwsCodeDeployDeploymentItem, shared::GitHubRepositoryInfo,
+};
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // most are AwsCodeDeployDeployment
+pub(super) enum AwsCodeDeployDeploymentOrRepositoryInfo {
+    AwsCodeDeployDeployment(AwsCodeDeployDeployment),
+    RepositoryInfo(GitHubRepositoryInfo),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct AwsCodeDeployDeployment {
+    pub deployment: AwsCodeDeployDeploymentItem,
+    pub commit: RepoCommit,
+}
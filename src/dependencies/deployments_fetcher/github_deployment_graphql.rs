@@ -1,20 +1,18 @@
-use crate::common_types::{
-    github_deployment_environment::ValidatedGitHubDeploymentEnvironment,
-    github_owner_repo::ValidatedGitHubOwnerRepo,
-};
+use crate::common_types::github_owner_repo::ValidatedGitHubOwnerRepo;
 use chrono::{DateTime, Utc};
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
 pub(super) fn deployments_query(
     owner_repo: &ValidatedGitHubOwnerRepo,
-    environment: &ValidatedGitHubDeploymentEnvironment,
+    environments: Option<Vec<String>>,
     after: Option<String>,
 ) -> String {
     let query = format!("
         query {{
             repository_owner: repositoryOwner(login: \"{owner}\") {{
                 repository(name: \"{repo}\") {{
-                    deployments(first: 100, environments: [\"{environment}\"], orderBy: {{field: CREATED_AT, direction: DESC}}{after}) {{
+                    deployments(first: 100{environments}, orderBy: {{field: CREATED_AT, direction: DESC}}{after}) {{
                         nodes {{
                             id
                             commit {{
@@ -60,7 +58,7 @@ pub(super) fn deployments_query(
                 }}
             }}
         }}
-    ", owner = owner_repo.get_owner(), repo = owner_repo.get_repo(), environment = environment, after = after.map_or_else(|| "".to_owned(), |cursor| format!(", after: \"{}\"", cursor)));
+    ", owner = owner_repo.get_owner(), repo = owner_repo.get_repo(), environments = environments.map_or_else(|| "".to_owned(), |names| format!(", environments: [{}]", names.iter().map(|name| format!("\"{}\"", name)).join(", "))), after = after.map_or_else(|| "".to_owned(), |cursor| format!(", after: \"{}\"", cursor)));
 
     query
 }
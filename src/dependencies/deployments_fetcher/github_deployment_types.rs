@@ -1,15 +1,18 @@
-use async_trait::async_trait;
+use std::sync::Arc;
+
 use chrono::{DateTime, Utc};
 use octocrab::Octocrab;
 
 use crate::common_types::{
-    date_time_range::DateTimeRange, github_personal_token::ValidatedGitHubPersonalToken,
+    date_time_range::DateTimeRange, github_credentials::GitHubCredentials,
+    github_deployment_environment::GitHubDeploymentEnvironmentMerge,
 };
 
 use super::{
     github_deployment_graphql::{
         DeploymentsDeploymentsNodeGraphQLResponse, DeploymentsDeploymentsStatusNodeGraphQLResponse,
     },
+    github_pagination::Page,
     interface::{BaseCommitShaOrRepositoryInfo, DeploymentLog, DeploymentsFetcherError},
 };
 
@@ -17,18 +20,9 @@ use super::{
 // Fetching step
 // ---------------------------
 pub(super) type GetClient =
-    fn(&ValidatedGitHubPersonalToken) -> Result<Octocrab, DeploymentsFetcherError>;
-
-pub(super) struct FetchResult {
-    pub(super) data: Vec<DeploymentsDeploymentsNodeGraphQLResponse>,
-    pub(super) after: Option<String>,
-    pub(super) has_next_page: bool,
-}
+    fn(&GitHubCredentials, Option<&str>) -> Result<Arc<Octocrab>, DeploymentsFetcherError>;
 
-#[async_trait]
-pub(super) trait GitHubDeploymentsFetcher {
-    async fn fetch(&self, after: Option<String>) -> Result<FetchResult, DeploymentsFetcherError>;
-}
+pub(super) type FetchResult = Page<DeploymentsDeploymentsNodeGraphQLResponse>;
 
 // ---------------------------
 // Filtering step
@@ -43,6 +37,14 @@ pub(super) type GetSucceededStatuses = fn(
 // ---------------------------
 // Collecting step
 // ---------------------------
+pub(super) type ReachesBeforeSince =
+    fn(nodes: &[DeploymentsDeploymentsNodeGraphQLResponse], timeframe: &DateTimeRange) -> bool;
+
+pub(super) type GroupByEnvironment = fn(
+    nodes: Vec<DeploymentsDeploymentsNodeGraphQLResponse>,
+    merge: GitHubDeploymentEnvironmentMerge,
+) -> Vec<Vec<DeploymentsDeploymentsNodeGraphQLResponse>>;
+
 pub(super) type SliceDeploymentNodes = fn(
     nodes: Vec<DeploymentsDeploymentsNodeGraphQLResponse>,
     timeframe: &DateTimeRange,
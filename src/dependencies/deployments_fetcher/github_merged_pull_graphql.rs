@@ -3,15 +3,17 @@ use serde::{Deserialize, Serialize};
 
 use crate::common_types::github_owner_repo::ValidatedGitHubOwnerRepo;
 
-pub(super) fn merged_pulls_query(
+/// The pull requests in `state`, most recently updated first, with their review activity and size
+pub(crate) fn merged_pulls_query(
     owner_repo: ValidatedGitHubOwnerRepo,
+    state: &str,
     after: Option<String>,
 ) -> String {
     let query = format!("
         query {{
           repository_owner: repositoryOwner(login: \"{owner}\") {{
             repository(name: \"{repo}\") {{
-              pulls: pullRequests(first: 100, states: [MERGED], orderBy: {{field: CREATED_AT, direction: DESC}}{after}) {{
+              pulls: pullRequests(first: 100, states: [{state}], orderBy: {{field: UPDATED_AT, direction: DESC}}{after}) {{
                 nodes {{
                   id
                   number
@@ -20,10 +22,26 @@ pub(super) fn merged_pulls_query(
                     id
                     name
                   }}
+                  author {{
+                    login
+                  }}
                   merged_by: mergedBy {{
                     login
                   }}
+                  created_at: createdAt
                   merged_at: mergedAt
+                  updated_at: updatedAt
+                  additions
+                  deletions
+                  changed_files: changedFiles
+                  reviews(first: 1, states: [COMMENTED, APPROVED, CHANGES_REQUESTED, DISMISSED]) {{
+                    nodes {{
+                      submitted_at: submittedAt
+                    }}
+                  }}
+                  changes_requested: reviews(states: [CHANGES_REQUESTED]) {{
+                    total_count: totalCount
+                  }}
                   merge_commit: mergeCommit {{
                     id
                     sha: oid
@@ -46,100 +64,126 @@ pub(super) fn merged_pulls_query(
             }}
           }}
         }}
-    ", owner = owner_repo.get_owner(), repo = owner_repo.get_repo(), after = after.map_or_else(|| "".to_owned(), |cursor| format!(", after: \"{}\"", cursor)));
+    ", owner = owner_repo.get_owner(), repo = owner_repo.get_repo(), state = state, after = after.map_or_else(|| "".to_owned(), |cursor| format!(", after: \"{}\"", cursor)));
 
     query
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsResponse {
-    pub(super) data: MergedPullsData,
+pub(crate) struct MergedPullsResponse {
+    pub(crate) data: MergedPullsData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(crate) struct MergedPullsData {
+    pub(crate) repository_owner: MergedPullsRepositoryOwner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(crate) struct MergedPullsRepositoryOwner {
+    pub(crate) repository: MergedPullsRepository,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(crate) struct MergedPullsRepository {
+    pub(crate) pulls: MergedPullsPulls,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsData {
-    pub(super) repository_owner: MergedPullsRepositoryOwner,
+pub(crate) struct MergedPullsPulls {
+    pub(crate) nodes: Vec<MergedPullsPullsNode>,
+    pub(crate) page_info: MergedPullsPageInfo,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsRepositoryOwner {
-    pub(super) repository: MergedPullsRepository,
+pub(crate) struct MergedPullsPullsNode {
+    pub(crate) id: String,
+    pub(crate) number: u64,
+    pub(crate) title: String,
+    pub(crate) base_ref: Option<MergedPullsBaseRef>,
+    pub(crate) author: Option<MergedPullsUser>,
+    pub(crate) merged_by: Option<MergedPullsUser>,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) merged_at: Option<DateTime<Utc>>,
+    pub(crate) updated_at: DateTime<Utc>,
+    pub(crate) additions: u64,
+    pub(crate) deletions: u64,
+    pub(crate) changed_files: u64,
+    pub(crate) reviews: MergedPullsReviews,
+    pub(crate) changes_requested: MergedPullsReviewCount,
+    pub(crate) merge_commit: Option<MergedPullsCommit>,
+    pub(crate) base_commit_sha: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsRepository {
-    pub(super) pulls: MergedPullsPulls,
+pub(crate) struct MergedPullsReviews {
+    pub(crate) nodes: Vec<MergedPullsReview>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsPulls {
-    pub(super) nodes: Vec<MergedPullsPullsNode>,
-    pub(super) page_info: MergedPullsPageInfo,
+pub(crate) struct MergedPullsReview {
+    pub(crate) submitted_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsPullsNode {
-    pub(super) id: String,
-    pub(super) number: u64,
-    pub(super) title: String,
-    pub(super) base_ref: Option<MergedPullsBaseRef>,
-    pub(super) merged_by: Option<MergedPullsUser>,
-    pub(super) merged_at: Option<DateTime<Utc>>,
-    pub(super) merge_commit: Option<MergedPullsCommit>,
-    pub(super) base_commit_sha: String,
+pub(crate) struct MergedPullsReviewCount {
+    pub(crate) total_count: u32,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsBaseRef {
-    pub(super) id: String,
-    pub(super) name: String,
+pub(crate) struct MergedPullsBaseRef {
+    pub(crate) id: String,
+    pub(crate) name: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsCommits {
-    pub(super) nodes: Vec<MergedPullsCommitsNode>,
+pub(crate) struct MergedPullsCommits {
+    pub(crate) nodes: Vec<MergedPullsCommitsNode>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsCommitsNode {
-    pub(super) id: String,
-    pub(super) commit: MergedPullsCommit,
+pub(crate) struct MergedPullsCommitsNode {
+    pub(crate) id: String,
+    pub(crate) commit: MergedPullsCommit,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsCommit {
-    pub(super) id: String,
-    pub(super) sha: String,
-    pub(super) message: String,
-    pub(super) resource_path: String,
-    pub(super) committed_date: DateTime<Utc>,
-    pub(super) author: Option<MergedPullsCommitAuthor>,
+pub(crate) struct MergedPullsCommit {
+    pub(crate) id: String,
+    pub(crate) sha: String,
+    pub(crate) message: String,
+    pub(crate) resource_path: String,
+    pub(crate) committed_date: DateTime<Utc>,
+    pub(crate) author: Option<MergedPullsCommitAuthor>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsCommitAuthor {
-    pub(super) user: Option<MergedPullsUser>,
+pub(crate) struct MergedPullsCommitAuthor {
+    pub(crate) user: Option<MergedPullsUser>,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsUser {
-    pub(super) login: String,
+pub(crate) struct MergedPullsUser {
+    pub(crate) login: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub(super) struct MergedPullsPageInfo {
-    pub(super) end_cursor: Option<String>,
-    pub(super) has_next_page: bool,
+pub(crate) struct MergedPullsPageInfo {
+    pub(crate) end_cursor: Option<String>,
+    pub(crate) has_next_page: bool,
 }
@@ -1,31 +1,33 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use futures::{pin_mut, TryStreamExt};
 use octocrab::Octocrab;
 use wildmatch::WildMatch;
 
 use crate::{
     common_types::{
         commit::Commit, deploy_branch_name::ValidatedDeployBranchName,
-        github_owner_repo::ValidatedGitHubOwnerRepo,
-        github_personal_token::ValidatedGitHubPersonalToken,
+        github_credentials::GitHubCredentials, github_owner_repo::ValidatedGitHubOwnerRepo,
     },
     dependencies::deployments_fetcher::github_merged_pull_graphql::MergedPullsResponse,
+    shared::github_client::{build_github_graphql_client, send_graphql},
 };
 
 use super::{
     github_merged_pull_graphql::{merged_pulls_query, MergedPullsPullsNode},
-    github_merged_pull_types::{CollectToItems, GetClient, GitHubMergedPullsFetcher},
+    github_merged_pull_types::{CollectToItems, FetchResult, GetClient},
+    github_pagination::{paginate, PageFetcher},
     interface::{
-        BaseCommitShaOrRepositoryInfo, DeploymentInfo, DeploymentLog, DeploymentsFetcher,
-        DeploymentsFetcherError, DeploymentsFetcherParams,
+        BaseCommitShaOrRepositoryInfo, DataQualityWarning, DeploymentInfo, DeploymentLog,
+        DeploymentsFetcher, DeploymentsFetcherError, DeploymentsFetcherParams, FetchedDeployments,
     },
 };
 
-const get_client: GetClient = |
-    github_personal_token: ValidatedGitHubPersonalToken,
-| -> Result<Octocrab, DeploymentsFetcherError> {
-    let client = Octocrab::builder()
-        .personal_token(github_personal_token.to_string())
-        .build()
+const get_client: GetClient = |github_credentials: GitHubCredentials,
+                               github_api_base_url: Option<&str>|
+ -> Result<Arc<Octocrab>, DeploymentsFetcherError> {
+    let client = build_github_graphql_client(&github_credentials, github_api_base_url)
         .map_err(|e| anyhow::anyhow!(e))
         .map_err(DeploymentsFetcherError::CreateAPIClientError)?;
 
@@ -33,124 +35,80 @@ const get_client: GetClient = |
 };
 
 struct GitHubMergedPullsFetcherImpl {
-    github_personal_token: ValidatedGitHubPersonalToken,
+    github_credentials: GitHubCredentials,
+    github_api_base_url: Option<String>,
     github_owner_repo: ValidatedGitHubOwnerRepo,
-    deploy_trigger_branch: ValidatedDeployBranchName,
 }
 #[async_trait]
-impl GitHubMergedPullsFetcher for GitHubMergedPullsFetcherImpl {
-    async fn fetch(
-        &self,
-        params: DeploymentsFetcherParams,
-    ) -> Result<Vec<MergedPullsPullsNode>, DeploymentsFetcherError> {
-        let mut after: Option<String> = None;
-        let mut has_next_page = true;
-        let mut items: Vec<MergedPullsPullsNode> = Vec::new();
-        let github_client = get_client(self.github_personal_token.clone())?;
-
-        // 全ページ取得
-        while has_next_page {
-            let query = merged_pulls_query(self.github_owner_repo.clone(), after);
-
-            let results: MergedPullsResponse = github_client
-                .graphql(&query)
-                .await
-                .map_err(|e| anyhow::anyhow!(e))
-                .map_err(DeploymentsFetcherError::FetchError)?;
-            let new_nodes = results
-                .data
-                .repository_owner
-                .repository
-                .pulls
-                .nodes
-                .iter()
-                .filter(|it| {
-                    let branch_ok = if let Some(base_ref) = &it.base_ref {
-                        let wild_match = WildMatch::new(&self.deploy_trigger_branch.to_string());
-                        wild_match.matches(&base_ref.name)
-                    } else {
-                        false
-                    };
-                    branch_ok
-                        && it
-                            .merged_at
-                            .map_or(false, |merged_at| params.timeframe.is_include(&merged_at))
-                })
-                .cloned()
-                .collect::<Vec<MergedPullsPullsNode>>();
-            items = [&items[..], &new_nodes[..]].concat();
-            has_next_page = results
-                .data
-                .repository_owner
-                .repository
-                .pulls
-                .page_info
-                .has_next_page;
-            after = results
-                .data
-                .repository_owner
-                .repository
-                .pulls
-                .page_info
-                .end_cursor;
-        }
+impl PageFetcher for GitHubMergedPullsFetcherImpl {
+    type Item = MergedPullsPullsNode;
 
-        Ok(items)
+    async fn fetch(&self, after: Option<String>) -> Result<FetchResult, DeploymentsFetcherError> {
+        let github_client = get_client(
+            self.github_credentials.clone(),
+            self.github_api_base_url.as_deref(),
+        )?;
+
+        let query = merged_pulls_query(self.github_owner_repo.clone(), "MERGED", after);
+
+        let results: MergedPullsResponse = send_graphql(&github_client, &query)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?;
+        let pulls = results.data.repository_owner.repository.pulls;
+
+        Ok(FetchResult {
+            data: pulls.nodes,
+            after: pulls.page_info.end_cursor,
+            has_next_page: pulls.page_info.has_next_page,
+        })
     }
 }
 
-const collect_to_logs: CollectToItems = |nodes: Vec<MergedPullsPullsNode>| -> Vec<DeploymentLog> {
-    nodes
-        .into_iter()
-        .map(|node| {
-            let head_commit = node
-                .merge_commit
-                .map(|node| Commit {
-                    sha: node.sha,
-                    message: node.message,
-                    resource_path: node.resource_path,
-                    committed_at: node.committed_date,
-                    creator_login: node
-                        .author
-                        .and_then(|x| x.user)
-                        .map(|x| x.login)
-                        .unwrap_or_else(|| "".to_string()),
-                })
-                .ok_or(DeploymentsFetcherError::InvalidResponse(
-                    "merge commit is empty".to_string(),
-                ));
-            let deployed_at = node
-                .merged_at
-                .ok_or(DeploymentsFetcherError::InvalidResponse(
-                    "merged_at is empty".to_string(),
-                ));
-            if let (Ok(head_commit), Ok(deployed_at)) = (head_commit, deployed_at) {
-                Ok(DeploymentLog {
-                    info: DeploymentInfo::GithubMergedPullRequest {
-                        id: node.id,
-                        number: node.number,
-                        title: node.title,
-                    },
-                    head_commit,
-                    base: BaseCommitShaOrRepositoryInfo::BaseCommitSha(node.base_commit_sha),
-                    creator_login: node
-                        .merged_by
-                        .map(|x| x.login)
-                        .unwrap_or_else(|| "".to_string()),
-                    deployed_at,
-                })
-            } else {
-                Err(DeploymentsFetcherError::InvalidResponse(
-                    "Invalid response".to_string(),
-                ))
-            }
-        })
-        .filter_map(Result::ok)
-        .collect()
+const collect_to_logs: CollectToItems = |nodes: Vec<MergedPullsPullsNode>| -> FetchedDeployments {
+    let mut fetched = FetchedDeployments::default();
+    for node in nodes {
+        let (Some(merge_commit), Some(deployed_at)) = (node.merge_commit, node.merged_at) else {
+            fetched.warnings.push(DataQualityWarning::new(
+                format!("#{}", node.number),
+                "Skipped: the pull request has no merge commit",
+            ));
+            continue;
+        };
+        let head_commit = Commit {
+            sha: merge_commit.sha,
+            message: merge_commit.message,
+            resource_path: merge_commit.resource_path,
+            committed_at: merge_commit.committed_date,
+            creator_login: merge_commit
+                .author
+                .and_then(|x| x.user)
+                .map(|x| x.login)
+                .unwrap_or_else(|| "".to_string()),
+        };
+        fetched.logs.push(DeploymentLog {
+            info: DeploymentInfo::GithubMergedPullRequest {
+                id: node.id,
+                number: node.number,
+                title: node.title,
+            },
+            head_commit,
+            base: BaseCommitShaOrRepositoryInfo::BaseCommitSha(node.base_commit_sha),
+            // The author tells a bot's pull request from a person's, whoever merged it
+            creator_login: node
+                .author
+                .or(node.merged_by)
+                .map(|x| x.login)
+                .unwrap_or_else(|| "".to_string()),
+            deployed_at,
+        });
+    }
+    fetched
 };
 
 pub struct DeploymentsFetcherWithGithubMergedPullRequest {
-    pub github_personal_token: ValidatedGitHubPersonalToken,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
     pub github_owner_repo: ValidatedGitHubOwnerRepo,
     pub deploy_trigger_branch: ValidatedDeployBranchName,
 }
@@ -159,14 +117,33 @@ impl DeploymentsFetcher for DeploymentsFetcherWithGithubMergedPullRequest {
     async fn fetch(
         &self,
         params: DeploymentsFetcherParams,
-    ) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
         let fetcher = GitHubMergedPullsFetcherImpl {
-            github_personal_token: self.github_personal_token.clone(),
+            github_credentials: self.github_credentials.clone(),
+            github_api_base_url: self.github_api_base_url.clone(),
             github_owner_repo: self.github_owner_repo.clone(),
-            deploy_trigger_branch: self.deploy_trigger_branch.clone(),
         };
-        let deployment_logs = fetcher.fetch(params).await?;
-        let colleted_items = collect_to_logs(deployment_logs);
+        let since = params.timeframe.get_since();
+        // Pulls come most recently updated first, and a pull is never merged after its last update
+        let stream = paginate(fetcher, move |page: &FetchResult| {
+            page.data.iter().any(|node| node.updated_at < since)
+        });
+        pin_mut!(stream);
+        let wild_match = WildMatch::new(&self.deploy_trigger_branch.to_string());
+        let mut merged_pulls: Vec<MergedPullsPullsNode> = Vec::new();
+        while let Some(page) = stream.try_next().await? {
+            merged_pulls.extend(page.data.into_iter().filter(|it| {
+                let branch_ok = it
+                    .base_ref
+                    .as_ref()
+                    .is_some_and(|base_ref| wild_match.matches(&base_ref.name));
+                branch_ok
+                    && it
+                        .merged_at
+                        .is_some_and(|merged_at| params.timeframe.is_include(&merged_at))
+            }));
+        }
+        let colleted_items = collect_to_logs(merged_pulls).excluding(&params.author_exclusion);
 
         Ok(colleted_items)
     }
@@ -178,28 +155,83 @@ mod tests {
 
     use crate::{
         common_types::{
-            date_time_range::DateTimeRange, deploy_branch_name::ValidatedDeployBranchName,
+            author_exclusion::AuthorExclusion, date_time_range::DateTimeRange,
+            deploy_branch_name::ValidatedDeployBranchName, github_credentials::GitHubCredentials,
             github_owner_repo::ValidatedGitHubOwnerRepo,
             github_personal_token::ValidatedGitHubPersonalToken,
         },
         dependencies::deployments_fetcher::{
-            github_merged_pull_impl::DeploymentsFetcherWithGithubMergedPullRequest,
-            interface::{DeploymentsFetcher, DeploymentsFetcherParams},
+            github_merged_pull_graphql::MergedPullsPullsNode,
+            github_merged_pull_impl::{
+                collect_to_logs, DeploymentsFetcherWithGithubMergedPullRequest,
+            },
+            interface::{DataQualityWarning, DeploymentsFetcher, DeploymentsFetcherParams},
         },
         shared::datetime_utc,
     };
 
+    fn merged_pull(number: u64, merge_commit: serde_json::Value) -> MergedPullsPullsNode {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("PR_{}", number),
+            "number": number,
+            "title": "Fix bug",
+            "base_ref": { "id": "REF_main", "name": "main" },
+            "merged_by": { "login": "dev" },
+            "created_at": "2023-02-28T10:00:00Z",
+            "merged_at": "2023-03-01T10:00:00Z",
+            "updated_at": "2023-03-01T10:00:00Z",
+            "additions": 10,
+            "deletions": 2,
+            "changed_files": 3,
+            "reviews": { "nodes": [] },
+            "changes_requested": { "total_count": 0 },
+            "merge_commit": merge_commit,
+            "base_commit_sha": "aaa"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn pulls_without_merge_commit_become_warnings() {
+        let fetched = collect_to_logs(vec![
+            merged_pull(
+                1,
+                serde_json::json!({
+                    "id": "C_bbb",
+                    "sha": "bbb",
+                    "message": "Fix bug",
+                    "resource_path": "/owner/repo/commit/bbb",
+                    "committed_date": "2023-03-01T09:00:00Z",
+                    "author": null
+                }),
+            ),
+            merged_pull(2, serde_json::Value::Null),
+        ]);
+
+        assert_eq!(fetched.logs.len(), 1);
+        assert_eq!(
+            fetched.warnings,
+            vec![DataQualityWarning::new(
+                "#2",
+                "Skipped: the pull request has no merge commit"
+            )]
+        );
+    }
+
     #[tokio::test]
     async fn test() {
         match env::var("GITHUB_PERSONAL_TOKEN") {
             Ok(token) => {
                 let token = token.parse::<String>().unwrap();
-                let token = ValidatedGitHubPersonalToken::new(Some(token)).unwrap();
+                let token = GitHubCredentials::PersonalToken(
+                    ValidatedGitHubPersonalToken::new(Some(token)).unwrap(),
+                );
                 let owner_repo =
                     ValidatedGitHubOwnerRepo::new("shwld/revelup-note".to_string()).unwrap();
                 let branch_name = ValidatedDeployBranchName::new(Some("main".to_string())).unwrap();
                 let fetcher = DeploymentsFetcherWithGithubMergedPullRequest {
-                    github_personal_token: token,
+                    github_credentials: token,
+                    github_api_base_url: None,
                     github_owner_repo: owner_repo,
                     deploy_trigger_branch: branch_name,
                 };
@@ -208,7 +240,12 @@ mod tests {
                     datetime_utc::parse("2021-06-01 00:00:00").unwrap(),
                 )
                 .unwrap();
-                let result = fetcher.fetch(DeploymentsFetcherParams { timeframe }).await;
+                let result = fetcher
+                    .fetch(DeploymentsFetcherParams {
+                        timeframe,
+                        author_exclusion: AuthorExclusion::default(),
+                    })
+                    .await;
                 println!("{:#?}", result);
                 assert!(result.is_ok());
             }
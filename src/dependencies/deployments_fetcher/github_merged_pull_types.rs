@@ -1,28 +1,24 @@
-use async_trait::async_trait;
+use std::sync::Arc;
+
 use octocrab::Octocrab;
 
 use super::{
     github_merged_pull_graphql::MergedPullsPullsNode,
-    interface::{DeploymentLog, DeploymentsFetcherError, DeploymentsFetcherParams},
+    github_pagination::Page,
+    interface::{DeploymentsFetcherError, FetchedDeployments},
 };
-use crate::common_types::github_personal_token::ValidatedGitHubPersonalToken;
+use crate::common_types::github_credentials::GitHubCredentials;
 
 pub(super) type GetClient =
-    fn(ValidatedGitHubPersonalToken) -> Result<Octocrab, DeploymentsFetcherError>;
+    fn(GitHubCredentials, Option<&str>) -> Result<Arc<Octocrab>, DeploymentsFetcherError>;
 
 // ---------------------------
 // Fetching step
 // ---------------------------
 
-#[async_trait]
-pub(super) trait GitHubMergedPullsFetcher {
-    async fn fetch(
-        &self,
-        params: DeploymentsFetcherParams,
-    ) -> Result<Vec<MergedPullsPullsNode>, DeploymentsFetcherError>;
-}
+pub(super) type FetchResult = Page<MergedPullsPullsNode>;
 
 // ---------------------------
 // Collecting step
 // ---------------------------
-pub(super) type CollectToItems = fn(items: Vec<MergedPullsPullsNode>) -> Vec<DeploymentLog>;
+pub(super) type CollectToItems = fn(items: Vec<MergedPullsPullsNode>) -> FetchedDeployments;
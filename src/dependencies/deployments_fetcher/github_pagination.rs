@@ -0,0 +1,189 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use futures::{stream, Stream};
+use tokio::task::JoinHandle;
+
+use super::interface::DeploymentsFetcherError;
+
+// ---------------------------
+// Cursor-based pagination over GitHub GraphQL connections
+// ---------------------------
+#[derive(Debug, Clone)]
+pub(super) struct Page<T> {
+    pub(super) data: Vec<T>,
+    pub(super) after: Option<String>,
+    pub(super) has_next_page: bool,
+}
+
+#[async_trait]
+pub(super) trait PageFetcher: Send + Sync + 'static {
+    type Item: Send + 'static;
+
+    async fn fetch(
+        &self,
+        after: Option<String>,
+    ) -> Result<Page<Self::Item>, DeploymentsFetcherError>;
+}
+
+/// A page request running in the background; it is aborted when dropped,
+/// so that a consumer stopping early does not leave a request behind.
+struct PendingPage<T>(JoinHandle<Result<Page<T>, DeploymentsFetcherError>>);
+
+impl<T> Future for PendingPage<T> {
+    type Output = Result<Page<T>, DeploymentsFetcherError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map(|joined| {
+            joined
+                .map_err(|e| anyhow!(e))
+                .map_err(DeploymentsFetcherError::FetchError)
+                .and_then(|page| page)
+        })
+    }
+}
+
+impl<T> Drop for PendingPage<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+fn request_page<F: PageFetcher>(fetcher: Arc<F>, after: Option<String>) -> PendingPage<F::Item> {
+    PendingPage(tokio::spawn(async move { fetcher.fetch(after).await }))
+}
+
+/// Streams the pages of a connection, newest first.
+///
+/// The next page is requested as soon as the current one arrives, so it is
+/// fetched while the caller processes the current page. The stream ends after
+/// the last page, after the first page for which `is_last_page` returns true
+/// (e.g. once it reaches past the timeframe), or after yielding an error.
+pub(super) fn paginate<F, P>(
+    fetcher: F,
+    is_last_page: P,
+) -> impl Stream<Item = Result<Page<F::Item>, DeploymentsFetcherError>>
+where
+    F: PageFetcher,
+    P: Fn(&Page<F::Item>) -> bool + Send + Sync + 'static,
+{
+    let fetcher = Arc::new(fetcher);
+    let is_last_page = Arc::new(is_last_page);
+    let first_page = request_page(fetcher.clone(), None);
+
+    stream::unfold(Some(first_page), move |pending| {
+        let fetcher = fetcher.clone();
+        let is_last_page = is_last_page.clone();
+        async move {
+            let page = match pending?.await {
+                Ok(page) => page,
+                Err(e) => return Some((Err(e), None)),
+            };
+            let next_page = if page.has_next_page && !is_last_page(&page) {
+                Some(request_page(fetcher, page.after.clone()))
+            } else {
+                None
+            };
+            Some((Ok(page), next_page))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use anyhow::anyhow;
+    use async_trait::async_trait;
+    use futures::{StreamExt, TryStreamExt};
+
+    use super::{paginate, Page, PageFetcher};
+    use crate::dependencies::deployments_fetcher::interface::DeploymentsFetcherError;
+
+    struct NumberPages {
+        pages: usize,
+        failing_page: Option<usize>,
+        requests: Arc<AtomicUsize>,
+    }
+    #[async_trait]
+    impl PageFetcher for NumberPages {
+        type Item = usize;
+
+        async fn fetch(
+            &self,
+            after: Option<String>,
+        ) -> Result<Page<usize>, DeploymentsFetcherError> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            let index = after.map_or(0, |cursor| cursor.parse::<usize>().unwrap());
+            if self.failing_page == Some(index) {
+                return Err(DeploymentsFetcherError::FetchError(anyhow!(
+                    "page {}", index
+                )));
+            }
+            Ok(Page {
+                data: vec![index],
+                after: Some((index + 1).to_string()),
+                has_next_page: index + 1 < self.pages,
+            })
+        }
+    }
+
+    fn number_pages(pages: usize, failing_page: Option<usize>) -> (NumberPages, Arc<AtomicUsize>) {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let fetcher = NumberPages {
+            pages,
+            failing_page,
+            requests: requests.clone(),
+        };
+        (fetcher, requests)
+    }
+
+    #[tokio::test]
+    async fn yields_every_page_in_order() {
+        let (fetcher, requests) = number_pages(3, None);
+        let pages: Vec<Page<usize>> = paginate(fetcher, |_| false).try_collect().await.unwrap();
+
+        assert_eq!(
+            pages
+                .iter()
+                .flat_map(|p| p.data.clone())
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn propagates_errors_and_ends() {
+        let (fetcher, _) = number_pages(5, Some(1));
+        let results: Vec<_> = paginate(fetcher, |_| false).collect().await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(matches!(
+            results[1],
+            Err(DeploymentsFetcherError::FetchError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn stops_after_the_last_page_without_requesting_more() {
+        let (fetcher, requests) = number_pages(10, None);
+        let pages: Vec<Page<usize>> = paginate(fetcher, |page| page.data.contains(&1))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+}
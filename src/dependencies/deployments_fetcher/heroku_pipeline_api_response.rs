@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+//------------------------
+// Heroku Pipeline API
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct HerokuPipelineItem {
+    pub(super) id: String,
+    pub(super) name: String,
+}
+
+//------------------------
+// Heroku Pipeline Coupling API
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct HerokuPipelineCouplingItem {
+    pub(super) id: String,
+    pub(super) app: HerokuPipelineCouplingApp,
+    pub(super) stage: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct HerokuPipelineCouplingApp {
+    pub(super) id: String,
+}
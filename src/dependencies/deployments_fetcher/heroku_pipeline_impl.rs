@@ -0,0 +1,307 @@
+use async_trait::async_trait;
+use futures::future::try_join_all;
+use itertools::Itertools;
+
+use super::{
+    heroku_pipeline_api_response::{HerokuPipelineCouplingItem, HerokuPipelineItem},
+    heroku_release_api_response::HerokuReleaseItem,
+    heroku_release_impl::{
+        attach_commit, convert_to_items, fetch_deployments, releases_without_commit_author,
+        HEROKU_API_BASE_URL,
+    },
+    heroku_release_types::HerokuReleaseOrRepositoryInfo,
+    interface::{
+        DeploymentsFetcher, DeploymentsFetcherError, DeploymentsFetcherParams, FetchedDeployments,
+    },
+    shared::{attach_commits, create_http_client, get_created_at, GitHubRepositoryInfo},
+};
+use crate::{
+    common_types::{
+        github_credentials::GitHubCredentials, github_owner_repo::ValidatedGitHubOwnerRepo,
+        heroku_app_name::ValidatedHerokuAppName, heroku_auth_token::ValidatedHerokuAuthToken,
+        heroku_pipeline_name::ValidatedHerokuPipelineName,
+    },
+    shared::non_empty_vec::NonEmptyVec,
+};
+
+const PRODUCTION_STAGE: &str = "production";
+
+async fn get_heroku_json<T: serde::de::DeserializeOwned>(
+    heroku_auth_token: ValidatedHerokuAuthToken,
+    url: String,
+) -> Result<T, DeploymentsFetcherError> {
+    let client = create_http_client();
+    let response = client
+        .get(url)
+        .header(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {token}", token = heroku_auth_token.expose()),
+        )
+        .header(
+            reqwest::header::ACCEPT,
+            "application/vnd.heroku+json; version=3",
+        )
+        .send()
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::FetchError)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(DeploymentsFetcherError::InvalidResponse(format!(
+            "status: {:?}",
+            status
+        )));
+    }
+
+    response
+        .json::<T>()
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::FetchError)
+}
+
+async fn fetch_production_apps(
+    base_url: &str,
+    heroku_pipeline_name: ValidatedHerokuPipelineName,
+    heroku_auth_token: ValidatedHerokuAuthToken,
+) -> Result<Vec<ValidatedHerokuAppName>, DeploymentsFetcherError> {
+    let pipeline: HerokuPipelineItem = get_heroku_json(
+        heroku_auth_token.clone(),
+        format!(
+            "{base_url}/pipelines/{pipeline_name}",
+            base_url = base_url,
+            pipeline_name = heroku_pipeline_name
+        ),
+    )
+    .await?;
+    let couplings: Vec<HerokuPipelineCouplingItem> = get_heroku_json(
+        heroku_auth_token,
+        format!(
+            "{base_url}/pipelines/{pipeline_id}/pipeline-couplings",
+            base_url = base_url,
+            pipeline_id = pipeline.id
+        ),
+    )
+    .await?;
+
+    let apps = couplings
+        .into_iter()
+        .filter(|coupling| coupling.stage == PRODUCTION_STAGE)
+        .map(|coupling| {
+            // The app id is accepted wherever the Platform API takes an app name
+            ValidatedHerokuAppName::new(Some(coupling.app.id))
+                .map_err(|e| DeploymentsFetcherError::InvalidResponse(e.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if apps.is_empty() {
+        return Err(DeploymentsFetcherError::InvalidResponse(format!(
+            "pipeline {} has no production app",
+            pipeline.name
+        )));
+    }
+
+    Ok(apps)
+}
+
+// A promotion copies the slug of the upstream app, so every production app
+// releases the same slug. Only the first release of each slug ships new code.
+fn pick_first_releases_by_slug(releases: Vec<HerokuReleaseItem>) -> Vec<HerokuReleaseItem> {
+    releases
+        .into_iter()
+        .filter(|release| release.slug.is_some())
+        .sorted_by_key(|release| release.created_at)
+        .unique_by(|release| release.slug.clone().map(|slug| slug.id))
+        .collect()
+}
+
+pub struct DeploymentsFetcherWithHerokuPipeline {
+    pub heroku_pipeline_name: ValidatedHerokuPipelineName,
+    pub heroku_auth_token: ValidatedHerokuAuthToken,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+}
+#[async_trait]
+impl DeploymentsFetcher for DeploymentsFetcherWithHerokuPipeline {
+    async fn fetch(
+        &self,
+        params: DeploymentsFetcherParams,
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
+        let production_apps = fetch_production_apps(
+            HEROKU_API_BASE_URL,
+            self.heroku_pipeline_name.clone(),
+            self.heroku_auth_token.clone(),
+        )
+        .await?;
+        log::debug!(
+            "heroku production apps: {:?}",
+            production_apps
+                .iter()
+                .map(|app| app.to_string())
+                .collect::<Vec<_>>()
+        );
+        let releases = try_join_all(production_apps.iter().map(|app| {
+            fetch_deployments(
+                app.clone(),
+                self.heroku_auth_token.clone(),
+                DeploymentsFetcherParams {
+                    timeframe: params.timeframe.clone(),
+                    author_exclusion: params.author_exclusion.clone(),
+                },
+            )
+        }))
+        .await?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<HerokuReleaseItem>>();
+        let first_releases = pick_first_releases_by_slug(releases);
+        let (mut deployments, unresolved) = attach_commits(
+            first_releases
+                .into_iter()
+                .map(|release| {
+                    let app_name = ValidatedHerokuAppName::new(Some(release.app.id.clone()))
+                        .map_err(|e| DeploymentsFetcherError::InvalidResponse(e.to_string()));
+                    (
+                        format!("{} v{}", release.app.name, release.version),
+                        async move {
+                            attach_commit(
+                                app_name?,
+                                self.heroku_auth_token.clone(),
+                                self.github_credentials.clone(),
+                                self.github_api_base_url.clone(),
+                                self.github_owner_repo.clone(),
+                                release,
+                            )
+                            .await
+                        },
+                    )
+                })
+                .collect(),
+            "Skipped: the slug commit is not a commit on GitHub",
+        )
+        .await?;
+        let mut warnings = releases_without_commit_author(&deployments, &params);
+        warnings.extend(unresolved);
+        let repo_created_at = get_created_at(
+            &self.github_credentials,
+            self.github_api_base_url.as_deref(),
+            &self.github_owner_repo,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::GetRepositoryCreatedAtError)?;
+        log::debug!("repo_created_at: {:#?}", repo_created_at);
+        deployments.push(HerokuReleaseOrRepositoryInfo::RepositoryInfo(
+            GitHubRepositoryInfo {
+                created_at: repo_created_at,
+            },
+        ));
+        let non_empty_nodes = NonEmptyVec::new(deployments)
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::DeploymentsFetcherResultIsEmptyList)?;
+
+        convert_to_items(non_empty_nodes).map(|logs| {
+            FetchedDeployments::from(logs)
+                .with_warnings(warnings)
+                .excluding(&params.author_exclusion)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{fetch_production_apps, pick_first_releases_by_slug};
+    use crate::{
+        common_types::{
+            heroku_auth_token::ValidatedHerokuAuthToken,
+            heroku_pipeline_name::ValidatedHerokuPipelineName,
+        },
+        dependencies::deployments_fetcher::heroku_release_api_response::HerokuReleaseItem,
+    };
+
+    fn release(app: &str, version: u64, slug: &str, created_at: &str) -> HerokuReleaseItem {
+        serde_json::from_value(serde_json::json!({
+            "addon_plan_names": [],
+            "app": { "id": app, "name": app },
+            "created_at": created_at,
+            "description": "Deploy",
+            "status": "succeeded",
+            "id": format!("{}-v{}", app, version),
+            "slug": { "id": slug },
+            "updated_at": created_at,
+            "user": { "email": "dev@example.com", "id": "user" },
+            "version": version,
+            "current": false,
+            "output_stream_url": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn keep_only_first_release_of_promoted_slug() {
+        let releases = vec![
+            release("prod-us", 10, "slug-a", "2023-03-01T10:00:00Z"),
+            release("prod-eu", 20, "slug-a", "2023-03-01T09:00:00Z"),
+            release("prod-us", 11, "slug-b", "2023-03-02T10:00:00Z"),
+            release("prod-eu", 21, "slug-b", "2023-03-02T11:00:00Z"),
+            release("prod-us", 12, "slug-a", "2023-03-03T10:00:00Z"),
+        ];
+
+        let picked = pick_first_releases_by_slug(releases)
+            .into_iter()
+            .map(|release| (release.app.name, release.version))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            picked,
+            vec![("prod-eu".to_string(), 20), ("prod-us".to_string(), 11)]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_the_production_apps_of_the_pipeline() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/pipelines/my-pipeline"))
+            .and(header(
+                "authorization",
+                "Bearer heroku-token-0123456789abcdef",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "pipeline-id",
+                "name": "my-pipeline"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/pipelines/pipeline-id/pipeline-couplings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "coupling-1", "app": { "id": "staging-app-id" }, "stage": "staging" },
+                { "id": "coupling-2", "app": { "id": "prod-us-id" }, "stage": "production" },
+                { "id": "coupling-3", "app": { "id": "prod-eu-id" }, "stage": "production" },
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let apps = fetch_production_apps(
+            &server.uri(),
+            ValidatedHerokuPipelineName::new(Some("my-pipeline".to_string())).unwrap(),
+            ValidatedHerokuAuthToken::new(Some("heroku-token-0123456789abcdef".to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|app| app.to_string())
+        .collect::<Vec<_>>();
+
+        assert_eq!(apps, vec!["prod-us-id", "prod-eu-id"]);
+    }
+}
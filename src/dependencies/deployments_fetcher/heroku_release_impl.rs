@@ -1,41 +1,31 @@
 use async_trait::async_trait;
-use futures::future::try_join_all;
-use http_cache_reqwest::{CACacheManager, Cache, CacheMode, HttpCache};
-use octocrab::{models::repos::RepoCommit, Octocrab};
-use reqwest::Client;
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 
 use super::{
     heroku_release_api_response::{HerokuReleaseItem, HerokuSlugItem},
     heroku_release_types::{HerokuRelease, HerokuReleaseOrRepositoryInfo},
     interface::{
-        BaseCommitShaOrRepositoryInfo, DeploymentsFetcher, DeploymentsFetcherError,
-        DeploymentsFetcherParams,
+        BaseCommitShaOrRepositoryInfo, DataQualityWarning, DeploymentsFetcher,
+        DeploymentsFetcherError, DeploymentsFetcherParams, FetchedDeployments,
     },
 };
 use crate::{
     common_types::{
-        commit::Commit, github_owner_repo::ValidatedGitHubOwnerRepo,
-        github_personal_token::ValidatedGitHubPersonalToken,
-        heroku_app_name::ValidatedHerokuAppName, heroku_auth_token::ValidatedHerokuAuthToken,
+        commit::Commit, github_credentials::GitHubCredentials,
+        github_owner_repo::ValidatedGitHubOwnerRepo, heroku_app_name::ValidatedHerokuAppName,
+        heroku_auth_token::ValidatedHerokuAuthToken,
     },
     dependencies::deployments_fetcher::{
-        heroku_release_types::GitHubRepositoryInfo,
         interface::{DeploymentInfo, DeploymentLog},
-        shared::get_created_at,
+        shared::{
+            attach_commits, create_http_client, create_uncached_http_client, get_commit,
+            get_created_at, GitHubRepositoryInfo,
+        },
     },
     shared::non_empty_vec::NonEmptyVec,
 };
 
-pub fn create_http_client() -> ClientWithMiddleware {
-    ClientBuilder::new(Client::new())
-        .with(Cache(HttpCache {
-            mode: CacheMode::Default,
-            manager: CACacheManager::default(),
-            options: None,
-        }))
-        .build()
-}
+pub(super) const HEROKU_API_BASE_URL: &str = "https://api.heroku.com";
+const HEROKU_RELEASES_FIRST_RANGE: &str = "version ..; order=desc, max=1000;";
 
 async fn get_slug(
     heroku_app_name: ValidatedHerokuAppName,
@@ -44,7 +34,8 @@ async fn get_slug(
 ) -> Result<HerokuSlugItem, DeploymentsFetcherError> {
     let client = create_http_client();
     let url = format!(
-        "https://api.heroku.com/apps/{app_name}/slugs/{slug_id}",
+        "{base_url}/apps/{app_name}/slugs/{slug_id}",
+        base_url = HEROKU_API_BASE_URL,
         app_name = heroku_app_name,
         slug_id = slug_id
     );
@@ -52,7 +43,7 @@ async fn get_slug(
         .get(url)
         .header(
             reqwest::header::AUTHORIZATION,
-            format!("Bearer {token}", token = heroku_auth_token),
+            format!("Bearer {token}", token = heroku_auth_token.expose()),
         )
         .header(
             reqwest::header::ACCEPT,
@@ -61,71 +52,87 @@ async fn get_slug(
         .send()
         .await
         .map_err(|e| anyhow::anyhow!(e))
-        .map_err(DeploymentsFetcherError::CommitIsNotFound)?
+        .and_then(|response| response.error_for_status().map_err(|e| anyhow::anyhow!(e)))
+        .map_err(DeploymentsFetcherError::FetchError)?
         .json::<HerokuSlugItem>()
         .await
-        .map_err(|e| anyhow::anyhow!(e))
-        .map_err(DeploymentsFetcherError::CommitIsNotFound)?;
+        .map_err(|e| DeploymentsFetcherError::InvalidResponse(e.to_string()))?;
 
     Ok(slug)
 }
 
-async fn get_commit(
-    github_owner_repo: ValidatedGitHubOwnerRepo,
-    github_personal_token: ValidatedGitHubPersonalToken,
-    sha: &str,
-) -> Result<RepoCommit, DeploymentsFetcherError> {
-    let octocrab = Octocrab::builder()
-        .personal_token(github_personal_token.to_string())
-        .build()
-        .map_err(|e| anyhow::anyhow!(e))
-        .map_err(DeploymentsFetcherError::CreateAPIClientError)?;
-    let commit: RepoCommit = octocrab
-        .get(
-            format!(
-                "/repos/{owner}/{repo}/commits/{ref}",
-                owner = github_owner_repo.get_owner(),
-                repo = github_owner_repo.get_repo(),
-                ref = &sha
-            ),
-            None::<&()>,
-        )
-        .await
-        .map_err(|e| anyhow::anyhow!(e))
-        .map_err(DeploymentsFetcherError::CommitIsNotFound)?;
-
-    Ok(commit)
+pub(super) async fn fetch_deployments(
+    heroku_app_name: ValidatedHerokuAppName,
+    heroku_auth_token: ValidatedHerokuAuthToken,
+    params: DeploymentsFetcherParams,
+) -> Result<Vec<HerokuReleaseItem>, DeploymentsFetcherError> {
+    fetch_releases(
+        HEROKU_API_BASE_URL,
+        heroku_app_name,
+        heroku_auth_token,
+        params,
+    )
+    .await
 }
 
-async fn fetch_deployments(
+async fn fetch_releases(
+    base_url: &str,
     heroku_app_name: ValidatedHerokuAppName,
     heroku_auth_token: ValidatedHerokuAuthToken,
-    _params: DeploymentsFetcherParams,
+    params: DeploymentsFetcherParams,
 ) -> Result<Vec<HerokuReleaseItem>, DeploymentsFetcherError> {
-    let client = create_http_client();
+    // Every page shares the same URL and differs only in the Range header,
+    // so the URL-keyed response cache would hand back a wrong page
+    let client = create_uncached_http_client();
     let url = format!(
-        "https://api.heroku.com/apps/{app_name}/releases",
+        "{base_url}/apps/{app_name}/releases",
+        base_url = base_url,
         app_name = heroku_app_name
     );
-    let releases: Vec<HerokuReleaseItem> = client
-        .get(url)
-        .header(
-            reqwest::header::AUTHORIZATION,
-            format!("Bearer {token}", token = heroku_auth_token),
-        )
-        .header(
-            reqwest::header::ACCEPT,
-            "application/vnd.heroku+json; version=3",
-        )
-        .header(reqwest::header::RANGE, "version ..; order=desc;")
-        .send()
-        .await
-        .map_err(|e| anyhow::anyhow!(e))
-        .map_err(DeploymentsFetcherError::FetchError)?
-        .json::<Vec<HerokuReleaseItem>>()
-        .await
-        .map_err(|e| anyhow::anyhow!(e))
-        .map_err(DeploymentsFetcherError::FetchError)?;
+    let since = params.timeframe.get_since();
+    let mut releases: Vec<HerokuReleaseItem> = vec![];
+    let mut range = Some(HEROKU_RELEASES_FIRST_RANGE.to_string());
+    while let Some(current_range) = range {
+        let response = client
+            .get(&url)
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!("Bearer {token}", token = heroku_auth_token.expose()),
+            )
+            .header(
+                reqwest::header::ACCEPT,
+                "application/vnd.heroku+json; version=3",
+            )
+            .header(reqwest::header::RANGE, current_range)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(DeploymentsFetcherError::InvalidResponse(format!(
+                "status: {:?}",
+                status
+            )));
+        }
+        range = response
+            .headers()
+            .get("Next-Range")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+        let page = response
+            .json::<Vec<HerokuReleaseItem>>()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?;
+        // Releases come newest first, so once a page reaches before the timeframe,
+        // it already holds the release that the first deployment is compared against
+        let reached_since = page.last().is_none_or(|release| release.created_at < since);
+        releases.extend(page);
+        if reached_since {
+            break;
+        }
+    }
 
     let succeeded_releases = releases
         .into_iter()
@@ -135,10 +142,11 @@ async fn fetch_deployments(
     Ok(succeeded_releases)
 }
 
-async fn attach_commit(
+pub(super) async fn attach_commit(
     heroku_app_name: ValidatedHerokuAppName,
     heroku_auth_token: ValidatedHerokuAuthToken,
-    github_personal_token: ValidatedGitHubPersonalToken,
+    github_credentials: GitHubCredentials,
+    github_api_base_url: Option<String>,
     github_owner_repo: ValidatedGitHubOwnerRepo,
     release: HerokuReleaseItem,
 ) -> Result<HerokuReleaseOrRepositoryInfo, DeploymentsFetcherError> {
@@ -151,14 +159,47 @@ async fn attach_commit(
                 "slug is None".to_string(),
             ))?;
     let slug = get_slug(heroku_app_name, heroku_auth_token.clone(), &slug_id).await?;
-    let commit = get_commit(github_owner_repo, github_personal_token, &slug.commit).await?;
+    let commit = get_commit(
+        github_owner_repo,
+        github_credentials,
+        github_api_base_url,
+        &slug.commit,
+    )
+    .await?;
 
     Ok(HerokuReleaseOrRepositoryInfo::HerokuRelease(
         HerokuRelease { release, commit },
     ))
 }
 
-fn convert_to_items(
+fn has_commit_author(release: &HerokuRelease) -> bool {
+    let author_date = release.commit.commit.author.as_ref().and_then(|x| x.date);
+    author_date.is_some() && release.commit.author.is_some()
+}
+
+pub(super) fn releases_without_commit_author(
+    deployments: &[HerokuReleaseOrRepositoryInfo],
+    params: &DeploymentsFetcherParams,
+) -> Vec<DataQualityWarning> {
+    deployments
+        .iter()
+        .flat_map(|x| match x {
+            HerokuReleaseOrRepositoryInfo::HerokuRelease(release) => Some(release),
+            HerokuReleaseOrRepositoryInfo::RepositoryInfo(_info) => None,
+        })
+        .filter(|release| {
+            params.timeframe.is_include(&release.release.created_at) && !has_commit_author(release)
+        })
+        .map(|release| {
+            DataQualityWarning::new(
+                format!("{} v{}", release.release.app.name, release.release.version),
+                "Skipped: the released commit has no GitHub author",
+            )
+        })
+        .collect()
+}
+
+pub(super) fn convert_to_items(
     deployment_nodes: NonEmptyVec<HerokuReleaseOrRepositoryInfo>,
 ) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
     let mut sorted: NonEmptyVec<HerokuReleaseOrRepositoryInfo> = deployment_nodes;
@@ -207,14 +248,11 @@ fn convert_to_items(
 
     let deployment_items = rest
         .iter()
+        // Skipping a release leaves its changes to the next one
+        .filter(|release| has_commit_author(release))
         .scan(
             first_commit,
             |previous: &mut BaseCommitShaOrRepositoryInfo, release: &HerokuRelease| {
-                let author_date = release.clone().commit.commit.author.and_then(|x| x.date);
-                let author_login = release.clone().commit.author.map(|x| x.login);
-                if author_date.is_none() || author_login.is_none() {
-                    return None;
-                }
                 let commit_item = Commit {
                     sha: release.clone().commit.sha,
                     message: release.clone().commit.commit.message,
@@ -251,7 +289,8 @@ fn convert_to_items(
 pub struct DeploymentsFetcherWithHerokuRelease {
     pub heroku_app_name: ValidatedHerokuAppName,
     pub heroku_auth_token: ValidatedHerokuAuthToken,
-    pub github_personal_token: ValidatedGitHubPersonalToken,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
     pub github_owner_repo: ValidatedGitHubOwnerRepo,
 }
 #[async_trait]
@@ -259,27 +298,46 @@ impl DeploymentsFetcher for DeploymentsFetcherWithHerokuRelease {
     async fn fetch(
         &self,
         params: DeploymentsFetcherParams,
-    ) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
         let succeeded_releases = fetch_deployments(
             self.heroku_app_name.clone(),
             self.heroku_auth_token.clone(),
-            params,
+            DeploymentsFetcherParams {
+                timeframe: params.timeframe.clone(),
+                author_exclusion: params.author_exclusion.clone(),
+            },
         )
         .await?;
-        let mut deployments = try_join_all(succeeded_releases.iter().map(|release| {
-            attach_commit(
-                self.heroku_app_name.clone(),
-                self.heroku_auth_token.clone(),
-                self.github_personal_token.clone(),
-                self.github_owner_repo.clone(),
-                release.clone(),
-            )
-        }))
+        let (mut deployments, unresolved) = attach_commits(
+            succeeded_releases
+                .into_iter()
+                .map(|release| {
+                    (
+                        format!("v{}", release.version),
+                        attach_commit(
+                            self.heroku_app_name.clone(),
+                            self.heroku_auth_token.clone(),
+                            self.github_credentials.clone(),
+                            self.github_api_base_url.clone(),
+                            self.github_owner_repo.clone(),
+                            release,
+                        ),
+                    )
+                })
+                .collect(),
+            "Skipped: the slug commit is not a commit on GitHub",
+        )
         .await?;
-        let repo_created_at = get_created_at(&self.github_personal_token, &self.github_owner_repo)
-            .await
-            .map_err(|e| anyhow::anyhow!(e))
-            .map_err(DeploymentsFetcherError::GetRepositoryCreatedAtError)?;
+        let mut warnings = releases_without_commit_author(&deployments, &params);
+        warnings.extend(unresolved);
+        let repo_created_at = get_created_at(
+            &self.github_credentials,
+            self.github_api_base_url.as_deref(),
+            &self.github_owner_repo,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::GetRepositoryCreatedAtError)?;
         log::debug!("repo_created_at: {:#?}", repo_created_at);
         deployments.push(HerokuReleaseOrRepositoryInfo::RepositoryInfo(
             GitHubRepositoryInfo {
@@ -292,6 +350,113 @@ impl DeploymentsFetcher for DeploymentsFetcherWithHerokuRelease {
 
         let deployment_items = convert_to_items(non_empty_nodes)?;
 
-        Ok(deployment_items)
+        Ok(FetchedDeployments::from(deployment_items)
+            .with_warnings(warnings)
+            .excluding(&params.author_exclusion))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use wiremock::{
+        matchers::{headers, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::fetch_releases;
+    use crate::{
+        common_types::{
+            author_exclusion::AuthorExclusion, date_time_range::DateTimeRange,
+            heroku_app_name::ValidatedHerokuAppName, heroku_auth_token::ValidatedHerokuAuthToken,
+        },
+        dependencies::deployments_fetcher::interface::DeploymentsFetcherParams,
+    };
+
+    fn release(version: u64, created_at: &str) -> serde_json::Value {
+        serde_json::json!({
+            "addon_plan_names": [],
+            "app": { "id": "app-id", "name": "my-app" },
+            "created_at": created_at,
+            "description": format!("Deploy v{}", version),
+            "status": "succeeded",
+            "id": format!("release-{}", version),
+            "slug": { "id": format!("slug-{}", version) },
+            "updated_at": created_at,
+            "user": { "email": "dev@example.com", "id": "user" },
+            "version": version,
+            "current": version == 6,
+            "output_stream_url": null
+        })
+    }
+
+    #[tokio::test]
+    async fn follow_next_range_until_releases_are_older_than_timeframe() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/apps/my-app/releases"))
+            .and(headers(
+                "range",
+                vec!["version ..; order=desc", "max=1000;"],
+            ))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("Next-Range", "]5..; order=desc, max=2;")
+                    .set_body_json(serde_json::json!([
+                        release(6, "2023-03-20T10:00:00Z"),
+                        release(5, "2023-03-15T10:00:00Z"),
+                    ])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/apps/my-app/releases"))
+            .and(headers("range", vec!["]5..; order=desc", "max=2;"]))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("Next-Range", "]3..; order=desc, max=2;")
+                    .set_body_json(serde_json::json!([
+                        release(4, "2023-03-05T10:00:00Z"),
+                        release(3, "2023-02-25T10:00:00Z"),
+                    ])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/apps/my-app/releases"))
+            .and(headers("range", vec!["]3..; order=desc", "max=2;"]))
+            .respond_with(ResponseTemplate::new(206).set_body_json(serde_json::json!([
+                release(2, "2023-02-20T10:00:00Z"),
+                release(1, "2023-02-15T10:00:00Z"),
+            ])))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let timeframe = DateTimeRange::new(
+            Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap(),
+        )
+        .unwrap();
+        let releases = fetch_releases(
+            &server.uri(),
+            ValidatedHerokuAppName::new(Some("my-app".to_string())).unwrap(),
+            ValidatedHerokuAuthToken::new(Some("heroku-token-0123456789abcdef".to_string()))
+                .unwrap(),
+            DeploymentsFetcherParams {
+                timeframe,
+                author_exclusion: AuthorExclusion::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let versions = releases
+            .iter()
+            .map(|release| release.version)
+            .collect::<Vec<_>>();
+        assert_eq!(versions, vec![6, 5, 4, 3]);
     }
 }
@@ -1,12 +1,6 @@
-use chrono::{DateTime, Utc};
 use octocrab::models::repos::RepoCommit;
 
-use super::heroku_release_api_response::HerokuReleaseItem;
-
-#[derive(Debug, Clone)]
-pub(super) struct GitHubRepositoryInfo {
-    pub created_at: DateTime<Utc>,
-}
+use super::{heroku_release_api_response::HerokuReleaseItem, shared::GitHubRepositoryInfo};
 
 #[derive(Debug, Clone)]
 #[allow(clippy::large_enum_variant)] // most are HerokuRelease
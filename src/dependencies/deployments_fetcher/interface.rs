@@ -3,11 +3,14 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::common_types::{commit::Commit, date_time_range::DateTimeRange};
+use crate::common_types::{
+    author_exclusion::AuthorExclusion, commit::Commit, date_time_range::DateTimeRange,
+};
 
 // Input
 pub struct DeploymentsFetcherParams {
     pub timeframe: DateTimeRange,
+    pub author_exclusion: AuthorExclusion,
 }
 
 #[derive(Debug, Clone)]
@@ -20,6 +23,7 @@ pub enum BaseCommitShaOrRepositoryInfo {
 pub enum DeploymentInfo {
     GithubDeployment {
         id: String,
+        environment: String,
     },
     GithubMergedPullRequest {
         id: String,
@@ -30,6 +34,32 @@ pub enum DeploymentInfo {
         id: String,
         version: u64,
     },
+    ArgoCdSync {
+        id: u64,
+        revision: String,
+    },
+    KubernetesReplicaSet {
+        name: String,
+        revision: u64,
+    },
+    VercelDeployment {
+        id: String,
+        url: String,
+    },
+    NetlifyDeploy {
+        id: String,
+    },
+    RenderDeploy {
+        id: String,
+    },
+    AwsCodeDeployDeployment {
+        id: String,
+    },
+    JenkinsBuild {
+        number: u64,
+        url: String,
+        failed_builds: Vec<u64>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -41,6 +71,72 @@ pub struct DeploymentLog {
     pub deployed_at: DateTime<Utc>,
 }
 
+/// A deployment that was left out, or kept with incomplete data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataQualityWarning {
+    /// The deployment id at the provider, or its head commit
+    pub deployment: String,
+    pub reason: String,
+}
+impl DataQualityWarning {
+    pub fn new(deployment: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            deployment: deployment.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FetchedDeployments {
+    pub logs: Vec<DeploymentLog>,
+    pub warnings: Vec<DataQualityWarning>,
+    /// The deployments left out because of their creator
+    pub excluded_count: u32,
+}
+impl From<Vec<DeploymentLog>> for FetchedDeployments {
+    fn from(logs: Vec<DeploymentLog>) -> Self {
+        Self {
+            logs,
+            ..Default::default()
+        }
+    }
+}
+impl FetchedDeployments {
+    pub(super) fn with_warnings(mut self, warnings: Vec<DataQualityWarning>) -> Self {
+        self.warnings.extend(warnings);
+        self
+    }
+    /// Leaves out the deployments of excluded creators. The next deployment takes over the base
+    /// of one left out, so that the commits it shipped still count.
+    pub(super) fn excluding(self, author_exclusion: &AuthorExclusion) -> Self {
+        let mut logs = self.logs;
+        logs.sort_by_key(|log| log.deployed_at);
+        let mut kept = Vec::with_capacity(logs.len());
+        let mut excluded_count = self.excluded_count;
+        let mut left_out: Option<DeploymentLog> = None;
+        for mut log in logs {
+            if let Some(left_out) = left_out.take() {
+                if matches!(&log.base, BaseCommitShaOrRepositoryInfo::BaseCommitSha(sha) if *sha == left_out.head_commit.sha)
+                {
+                    log.base = left_out.base;
+                }
+            }
+            if author_exclusion.is_excluded(&log.creator_login) {
+                excluded_count += 1;
+                left_out = Some(log);
+            } else {
+                kept.push(log);
+            }
+        }
+        Self {
+            logs: kept,
+            warnings: self.warnings,
+            excluded_count,
+        }
+    }
+}
+
 // Errors
 #[derive(Debug, Error)]
 pub enum DeploymentsFetcherError {
@@ -64,5 +160,50 @@ pub trait DeploymentsFetcher {
     async fn fetch(
         &self,
         params: DeploymentsFetcherParams,
-    ) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError>;
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError>;
+}
+/// Lets the CLI pick the fetcher of the configured deployment source at runtime
+#[async_trait]
+impl DeploymentsFetcher for Box<dyn DeploymentsFetcher + Send + Sync> {
+    async fn fetch(
+        &self,
+        params: DeploymentsFetcherParams,
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
+        self.as_ref().fetch(params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BaseCommitShaOrRepositoryInfo, DeploymentLog, FetchedDeployments};
+    use crate::{
+        common_types::{author_exclusion::AuthorExclusion, commit::Commit},
+        tests::factories::deployment_log::build_deployment_log,
+    };
+
+    #[test]
+    fn excluding_hands_the_base_of_a_left_out_deployment_to_the_next() {
+        let bot_deployment = DeploymentLog {
+            head_commit: Commit {
+                sha: "bot".to_string(),
+                ..build_deployment_log("2023-01-08 10:00:00").head_commit
+            },
+            creator_login: "dependabot[bot]".to_string(),
+            ..build_deployment_log("2023-01-08 10:00:00")
+        };
+        let deployment = DeploymentLog {
+            base: BaseCommitShaOrRepositoryInfo::BaseCommitSha("bot".to_string()),
+            ..build_deployment_log("2023-01-10 10:00:00")
+        };
+
+        let fetched = FetchedDeployments::from(vec![deployment, bot_deployment])
+            .excluding(&AuthorExclusion::default());
+
+        assert_eq!(fetched.excluded_count, 1);
+        assert_eq!(fetched.logs.len(), 1);
+        assert!(matches!(
+            &fetched.logs[0].base,
+            BaseCommitShaOrRepositoryInfo::BaseCommitSha(sha) if sha == "base"
+        ));
+    }
 }
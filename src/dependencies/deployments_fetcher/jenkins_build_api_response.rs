@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+//------------------------
+// Jenkins Remote Access API (job)
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub(super) struct JenkinsJobResponse {
+    #[serde(default)]
+    pub(super) all_builds: Vec<JenkinsBuildItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct JenkinsBuildItem {
+    pub(super) number: u64,
+    pub(super) url: String,
+    pub(super) result: Option<String>,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub(super) timestamp: chrono::DateTime<chrono::Utc>,
+    pub(super) duration: i64,
+    #[serde(default)]
+    pub(super) actions: Vec<JenkinsBuildAction>,
+}
+
+// Most actions are empty objects once filtered by the tree parameter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub(super) struct JenkinsBuildAction {
+    pub(super) last_built_revision: Option<JenkinsRevision>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct JenkinsRevision {
+    #[serde(rename = "SHA1")]
+    pub(super) sha1: String,
+}
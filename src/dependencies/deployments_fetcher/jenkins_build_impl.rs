@@ -0,0 +1,414 @@
+use async_trait::async_trait;
+use itertools::Itertools;
+
+use super::{
+    interface::{
+        BaseCommitShaOrRepositoryInfo, DataQualityWarning, DeploymentInfo, DeploymentLog,
+        DeploymentsFetcher, DeploymentsFetcherError, DeploymentsFetcherParams, FetchedDeployments,
+    },
+    jenkins_build_api_response::{JenkinsBuildItem, JenkinsJobResponse},
+    jenkins_build_types::{JenkinsBuild, JenkinsBuildOrRepositoryInfo, JenkinsDeploymentBuild},
+    shared::{
+        attach_commits, create_http_client, get_commit, get_created_at, GitHubRepositoryInfo,
+    },
+};
+use crate::{
+    common_types::{
+        commit::Commit, github_credentials::GitHubCredentials,
+        github_owner_repo::ValidatedGitHubOwnerRepo, jenkins_api_token::ValidatedJenkinsApiToken,
+        jenkins_base_url::ValidatedJenkinsBaseUrl, jenkins_job_name::ValidatedJenkinsJobName,
+        jenkins_user_name::ValidatedJenkinsUserName,
+    },
+    shared::non_empty_vec::NonEmptyVec,
+};
+
+const SUCCESS_RESULT: &str = "SUCCESS";
+const FAILED_RESULTS: [&str; 2] = ["FAILURE", "UNSTABLE"];
+const BUILD_TREE: &str = "number,url,result,timestamp,duration,actions[lastBuiltRevision[SHA1]]";
+const PER_PAGE: usize = 100;
+
+// A job in a folder is addressed as `folder/job` and lives at `job/folder/job/job`
+fn job_path(jenkins_job_name: &ValidatedJenkinsJobName) -> String {
+    jenkins_job_name
+        .to_string()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| format!("job/{}", segment))
+        .join("/")
+}
+
+async fn fetch_builds(
+    jenkins_base_url: &ValidatedJenkinsBaseUrl,
+    jenkins_job_name: &ValidatedJenkinsJobName,
+    jenkins_user_name: &ValidatedJenkinsUserName,
+    jenkins_api_token: &ValidatedJenkinsApiToken,
+    params: &DeploymentsFetcherParams,
+) -> Result<Vec<JenkinsBuildItem>, DeploymentsFetcherError> {
+    let client = create_http_client();
+    let url = format!(
+        "{base_url}/{job_path}/api/json",
+        base_url = jenkins_base_url,
+        job_path = job_path(jenkins_job_name)
+    );
+    let since = params.timeframe.get_since();
+    let mut builds: Vec<JenkinsBuildItem> = vec![];
+    loop {
+        // `allBuilds` is not capped at 100 like `builds`, and takes a range of indexes
+        let tree = format!(
+            "allBuilds[{fields}]{{{from},{to}}}",
+            fields = BUILD_TREE,
+            from = builds.len(),
+            to = builds.len() + PER_PAGE
+        );
+        let response = client
+            .get(&url)
+            .query(&[("tree", tree)])
+            .basic_auth(jenkins_user_name, Some(jenkins_api_token.expose()))
+            .header(reqwest::header::ACCEPT, "application/json")
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(DeploymentsFetcherError::InvalidResponse(format!(
+                "status: {:?}",
+                status
+            )));
+        }
+        let page = response
+            .json::<JenkinsJobResponse>()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?
+            .all_builds;
+        // Builds come newest first, so the page reaching before the timeframe
+        // already holds the build that the first one is compared against
+        let is_last_page =
+            page.len() < PER_PAGE || page.last().is_none_or(|build| build.timestamp < since);
+        builds.extend(page);
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok(builds)
+}
+
+fn last_built_revision(build: &JenkinsBuildItem) -> Option<String> {
+    build
+        .actions
+        .iter()
+        .find_map(|action| action.last_built_revision.as_ref())
+        .map(|revision| revision.sha1.clone())
+}
+
+// Each successful build is a deployment, carrying the failed builds since the previous one
+fn pick_deployment_builds(builds: Vec<JenkinsBuildItem>) -> Vec<JenkinsDeploymentBuild> {
+    let mut deployments: Vec<JenkinsDeploymentBuild> = vec![];
+    let mut failed_builds: Vec<u64> = vec![];
+    for build in builds.into_iter().sorted_by_key(|build| build.number) {
+        let result = build.result.as_deref().unwrap_or_default();
+        if FAILED_RESULTS.contains(&result) {
+            failed_builds.push(build.number);
+            continue;
+        }
+        if result != SUCCESS_RESULT {
+            continue;
+        }
+        let Some(sha) = last_built_revision(&build) else {
+            continue;
+        };
+        match deployments.last_mut() {
+            // A redeploy of the same commit does not ship any new change
+            Some(previous) if previous.sha == sha => {
+                previous.failed_builds.append(&mut failed_builds)
+            }
+            _ => deployments.push(JenkinsDeploymentBuild {
+                build,
+                sha,
+                failed_builds: std::mem::take(&mut failed_builds),
+            }),
+        }
+    }
+
+    deployments
+}
+
+fn builds_without_commit(
+    builds: &[JenkinsBuildItem],
+    params: &DeploymentsFetcherParams,
+) -> Vec<DataQualityWarning> {
+    builds
+        .iter()
+        .filter(|build| {
+            build.result.as_deref() == Some(SUCCESS_RESULT)
+                && last_built_revision(build).is_none()
+                && params.timeframe.is_include(&build.timestamp)
+        })
+        .map(|build| {
+            DataQualityWarning::new(
+                build.url.clone(),
+                "Skipped: the build has no built revision",
+            )
+        })
+        .collect()
+}
+
+async fn attach_commit(
+    github_credentials: GitHubCredentials,
+    github_api_base_url: Option<String>,
+    github_owner_repo: ValidatedGitHubOwnerRepo,
+    build: JenkinsDeploymentBuild,
+) -> Result<JenkinsBuildOrRepositoryInfo, DeploymentsFetcherError> {
+    let commit = get_commit(
+        github_owner_repo,
+        github_credentials,
+        github_api_base_url,
+        &build.sha,
+    )
+    .await?;
+
+    Ok(JenkinsBuildOrRepositoryInfo::JenkinsBuild(JenkinsBuild {
+        build,
+        commit,
+    }))
+}
+
+fn convert_to_items(
+    build_nodes: NonEmptyVec<JenkinsBuildOrRepositoryInfo>,
+) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
+    let mut sorted: NonEmptyVec<JenkinsBuildOrRepositoryInfo> = build_nodes;
+    sorted.sort_by_key(|a| match a {
+        JenkinsBuildOrRepositoryInfo::JenkinsBuild(build) => build.build.build.timestamp,
+        JenkinsBuildOrRepositoryInfo::RepositoryInfo(info) => info.created_at,
+    });
+    let (first_item, rest) = sorted.get();
+
+    let rest = rest
+        .into_iter()
+        .flat_map(|x| match x {
+            JenkinsBuildOrRepositoryInfo::JenkinsBuild(build) => Some(build),
+            JenkinsBuildOrRepositoryInfo::RepositoryInfo(_info) => None,
+        })
+        .collect::<Vec<JenkinsBuild>>();
+
+    let first_commit: BaseCommitShaOrRepositoryInfo = match first_item {
+        JenkinsBuildOrRepositoryInfo::JenkinsBuild(build) => {
+            BaseCommitShaOrRepositoryInfo::BaseCommitSha(build.commit.sha)
+        }
+        JenkinsBuildOrRepositoryInfo::RepositoryInfo(info) => {
+            BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(info.created_at)
+        }
+    };
+
+    let deployment_items = rest
+        .iter()
+        .scan(
+            first_commit,
+            |previous: &mut BaseCommitShaOrRepositoryInfo, build: &JenkinsBuild| {
+                // The build deploys when it finishes
+                let deployed_at = build.build.build.timestamp
+                    + chrono::Duration::milliseconds(build.build.build.duration);
+                let committed_at = build.commit.commit.author.as_ref().and_then(|x| x.date);
+                let creator_login = build
+                    .commit
+                    .author
+                    .as_ref()
+                    .map(|x| x.login.clone())
+                    .unwrap_or_default();
+                let commit_item = Commit {
+                    sha: build.commit.sha.clone(),
+                    message: build.commit.commit.message.clone(),
+                    resource_path: build.commit.html_url.clone(),
+                    committed_at: committed_at.unwrap_or(deployed_at),
+                    creator_login: creator_login.clone(),
+                };
+                let deployment_item = DeploymentLog {
+                    info: DeploymentInfo::JenkinsBuild {
+                        number: build.build.build.number,
+                        url: build.build.build.url.clone(),
+                        failed_builds: build.build.failed_builds.clone(),
+                    },
+                    head_commit: commit_item,
+                    base: previous.clone(),
+                    creator_login,
+                    deployed_at,
+                };
+                *previous = BaseCommitShaOrRepositoryInfo::BaseCommitSha(build.commit.sha.clone());
+                Some(deployment_item)
+            },
+        )
+        .collect::<Vec<DeploymentLog>>();
+
+    Ok(deployment_items)
+}
+
+pub struct DeploymentsFetcherWithJenkinsBuild {
+    pub jenkins_base_url: ValidatedJenkinsBaseUrl,
+    pub jenkins_job_name: ValidatedJenkinsJobName,
+    pub jenkins_user_name: ValidatedJenkinsUserName,
+    pub jenkins_api_token: ValidatedJenkinsApiToken,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+}
+#[async_trait]
+impl DeploymentsFetcher for DeploymentsFetcherWithJenkinsBuild {
+    async fn fetch(
+        &self,
+        params: DeploymentsFetcherParams,
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
+        let builds = fetch_builds(
+            &self.jenkins_base_url,
+            &self.jenkins_job_name,
+            &self.jenkins_user_name,
+            &self.jenkins_api_token,
+            &params,
+        )
+        .await?;
+        log::debug!("jenkins builds: {:?}", builds.len());
+        let mut warnings = builds_without_commit(&builds, &params);
+        let deployment_builds = pick_deployment_builds(builds);
+        let (mut builds, unresolved) = attach_commits(
+            deployment_builds
+                .into_iter()
+                .map(|build| {
+                    (
+                        build.build.url.clone(),
+                        attach_commit(
+                            self.github_credentials.clone(),
+                            self.github_api_base_url.clone(),
+                            self.github_owner_repo.clone(),
+                            build,
+                        ),
+                    )
+                })
+                .collect(),
+            "Skipped: the built revision is not a commit on GitHub",
+        )
+        .await?;
+        warnings.extend(unresolved);
+        let repo_created_at = get_created_at(
+            &self.github_credentials,
+            self.github_api_base_url.as_deref(),
+            &self.github_owner_repo,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::GetRepositoryCreatedAtError)?;
+        log::debug!("repo_created_at: {:#?}", repo_created_at);
+        builds.push(JenkinsBuildOrRepositoryInfo::RepositoryInfo(
+            GitHubRepositoryInfo {
+                created_at: repo_created_at,
+            },
+        ));
+        let non_empty_nodes = NonEmptyVec::new(builds)
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::DeploymentsFetcherResultIsEmptyList)?;
+
+        convert_to_items(non_empty_nodes).map(|logs| {
+            FetchedDeployments::from(logs)
+                .with_warnings(warnings)
+                .excluding(&params.author_exclusion)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{fetch_builds, pick_deployment_builds};
+    use crate::{
+        common_types::{
+            author_exclusion::AuthorExclusion, date_time_range::DateTimeRange,
+            jenkins_api_token::ValidatedJenkinsApiToken, jenkins_base_url::ValidatedJenkinsBaseUrl,
+            jenkins_job_name::ValidatedJenkinsJobName, jenkins_user_name::ValidatedJenkinsUserName,
+        },
+        dependencies::deployments_fetcher::interface::DeploymentsFetcherParams,
+    };
+
+    fn build(number: u64, result: Option<&str>, sha: &str, timestamp: i64) -> serde_json::Value {
+        serde_json::json!({
+            "_class": "hudson.model.FreeStyleBuild",
+            "number": number,
+            "url": format!("https://jenkins.example.com/job/deploy/{}/", number),
+            "result": result,
+            "timestamp": timestamp,
+            "duration": 60000,
+            "actions": [
+                { "_class": "hudson.model.CauseAction" },
+                { "_class": "hudson.plugins.git.util.BuildData",
+                  "lastBuiltRevision": { "SHA1": sha } },
+                {}
+            ]
+        })
+    }
+
+    #[tokio::test]
+    async fn fetch_successful_builds_with_failed_ones_in_between() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/job/services/job/deploy/api/json"))
+            .and(query_param(
+                "tree",
+                "allBuilds[number,url,result,timestamp,duration,actions[lastBuiltRevision[SHA1]]]{0,100}",
+            ))
+            // "jenkins-user:jenkins-api-token"
+            .and(header(
+                "authorization",
+                "Basic amVua2lucy11c2VyOmplbmtpbnMtYXBpLXRva2Vu",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "_class": "hudson.model.FreeStyleProject",
+                "allBuilds": [
+                    build(7, None, "eee", 1678010400000_i64),
+                    build(6, Some("SUCCESS"), "ddd", 1677924000000_i64),
+                    build(5, Some("UNSTABLE"), "ddd", 1677920400000_i64),
+                    build(4, Some("FAILURE"), "ccc", 1677916800000_i64),
+                    build(3, Some("ABORTED"), "ccc", 1677913200000_i64),
+                    build(2, Some("SUCCESS"), "bbb", 1677837600000_i64),
+                    build(1, Some("SUCCESS"), "aaa", 1676887200000_i64)
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let timeframe = DateTimeRange::new(
+            Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap(),
+        )
+        .unwrap();
+        let builds = fetch_builds(
+            &ValidatedJenkinsBaseUrl::new(Some(server.uri())).unwrap(),
+            &ValidatedJenkinsJobName::new(Some("services/deploy".to_string())).unwrap(),
+            &ValidatedJenkinsUserName::new(Some("jenkins-user".to_string())).unwrap(),
+            &ValidatedJenkinsApiToken::new(Some("jenkins-api-token".to_string())).unwrap(),
+            &DeploymentsFetcherParams {
+                timeframe,
+                author_exclusion: AuthorExclusion::default(),
+            },
+        )
+        .await
+        .unwrap();
+        let picked = pick_deployment_builds(builds)
+            .into_iter()
+            .map(|build| (build.build.number, build.sha, build.failed_builds))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            picked,
+            vec![
+                (1, "aaa".to_string(), vec![]),
+                (2, "bbb".to_string(), vec![]),
+                (6, "ddd".to_string(), vec![4, 5])
+            ]
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use octocrab::models::repos::RepoCommit;
+
+use super::{jenkins_build_api_response::JenkinsBuildItem, shared::GitHubRepositoryInfo};
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // most are JenkinsBuild
+pub(super) enum JenkinsBuildOrRepositoryInfo {
+    JenkinsBuild(JenkinsBuild),
+    RepositoryInfo(GitHubRepositoryInfo),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct JenkinsDeploymentBuild {
+    pub build: JenkinsBuildItem,
+    pub sha: String,
+    pub failed_builds: Vec<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct JenkinsBuild {
+    pub build: JenkinsDeploymentBuild,
+    pub commit: RepoCommit,
+}
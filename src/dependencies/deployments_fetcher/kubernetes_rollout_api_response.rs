@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+//------------------------
+// Kubernetes apps/v1 Deployment API
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct KubernetesDeploymentItem {
+    pub(super) metadata: KubernetesObjectMeta,
+    pub(super) spec: KubernetesDeploymentSpec,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub(super) struct KubernetesObjectMeta {
+    pub(super) name: String,
+    pub(super) uid: String,
+    pub(super) creation_timestamp: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    pub(super) annotations: HashMap<String, String>,
+    #[serde(default)]
+    pub(super) owner_references: Vec<KubernetesOwnerReference>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct KubernetesOwnerReference {
+    pub(super) kind: String,
+    pub(super) name: String,
+    pub(super) uid: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub(super) struct KubernetesDeploymentSpec {
+    pub(super) selector: KubernetesLabelSelector,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub(super) struct KubernetesLabelSelector {
+    #[serde(default)]
+    pub(super) match_labels: HashMap<String, String>,
+}
+
+//------------------------
+// Kubernetes apps/v1 ReplicaSet API
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct KubernetesReplicaSetList {
+    pub(super) items: Vec<KubernetesReplicaSetItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct KubernetesReplicaSetItem {
+    pub(super) metadata: KubernetesObjectMeta,
+    pub(super) spec: KubernetesReplicaSetSpec,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct KubernetesReplicaSetSpec {
+    pub(super) template: KubernetesPodTemplate,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct KubernetesPodTemplate {
+    #[serde(default)]
+    pub(super) metadata: KubernetesPodTemplateMeta,
+    pub(super) spec: KubernetesPodSpec,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct KubernetesPodTemplateMeta {
+    #[serde(default)]
+    pub(super) annotations: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct KubernetesPodSpec {
+    pub(super) containers: Vec<KubernetesContainer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct KubernetesContainer {
+    pub(super) name: String,
+    pub(super) image: String,
+}
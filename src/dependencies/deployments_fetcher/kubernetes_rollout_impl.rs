@@ -0,0 +1,561 @@
+use async_trait::async_trait;
+use itertools::Itertools;
+
+use super::{
+    interface::{
+        BaseCommitShaOrRepositoryInfo, DataQualityWarning, DeploymentInfo, DeploymentLog,
+        DeploymentsFetcher, DeploymentsFetcherError, DeploymentsFetcherParams, FetchedDeployments,
+    },
+    kubernetes_rollout_api_response::{
+        KubernetesDeploymentItem, KubernetesReplicaSetItem, KubernetesReplicaSetList,
+    },
+    kubernetes_rollout_kubeconfig::{
+        create_kubernetes_client, load_kubeconfig, resolve_connection, KubernetesConnection,
+    },
+    kubernetes_rollout_types::{ReplicaSet, ReplicaSetOrRepositoryInfo, ReplicaSetRevision},
+    shared::{attach_commits, get_commit, get_created_at, GitHubRepositoryInfo},
+};
+use crate::{
+    common_types::{
+        commit::Commit, github_credentials::GitHubCredentials,
+        github_owner_repo::ValidatedGitHubOwnerRepo,
+        kubernetes_deployment_name::ValidatedKubernetesDeploymentName,
+        kubernetes_namespace::ValidatedKubernetesNamespace,
+    },
+    shared::{
+        non_empty_vec::NonEmptyVec,
+        rate_limit::{send_with_retry, DEFAULT_RETRY_POLICY},
+    },
+};
+
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+async fn get_json<T: serde::de::DeserializeOwned>(
+    connection: &KubernetesConnection,
+    path: &str,
+    query: &[(&str, String)],
+) -> Result<T, DeploymentsFetcherError> {
+    let client = create_kubernetes_client(connection)?;
+    let mut request = client
+        .get(format!("{}{}", connection.server, path))
+        .query(query)
+        .header(reqwest::header::ACCEPT, "application/json");
+    if let Some(token) = &connection.token {
+        request = request.bearer_auth(token);
+    }
+    let response = send_with_retry(&DEFAULT_RETRY_POLICY, || {
+        request
+            .try_clone()
+            .expect("A request without a body can be cloned")
+            .send()
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!(e))
+    .map_err(DeploymentsFetcherError::FetchError)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(DeploymentsFetcherError::InvalidResponse(format!(
+            "path: {}, status: {:?}",
+            path, status
+        )));
+    }
+
+    response
+        .json::<T>()
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::FetchError)
+}
+
+async fn fetch_replica_sets(
+    connection: &KubernetesConnection,
+    namespace: &ValidatedKubernetesNamespace,
+    deployment_name: &ValidatedKubernetesDeploymentName,
+) -> Result<Vec<KubernetesReplicaSetItem>, DeploymentsFetcherError> {
+    let deployment: KubernetesDeploymentItem = get_json(
+        connection,
+        &format!(
+            "/apis/apps/v1/namespaces/{namespace}/deployments/{name}",
+            namespace = namespace,
+            name = deployment_name
+        ),
+        &[],
+    )
+    .await?;
+    let label_selector = deployment
+        .spec
+        .selector
+        .match_labels
+        .iter()
+        .sorted()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .join(",");
+    let replica_sets: KubernetesReplicaSetList = get_json(
+        connection,
+        &format!(
+            "/apis/apps/v1/namespaces/{namespace}/replicasets",
+            namespace = namespace
+        ),
+        &[("labelSelector", label_selector)],
+    )
+    .await?;
+
+    // Selectors may overlap between Deployments, so trust only the owner reference
+    let owned = replica_sets
+        .items
+        .into_iter()
+        .filter(|replica_set| {
+            replica_set
+                .metadata
+                .owner_references
+                .iter()
+                .any(|owner| owner.uid == deployment.metadata.uid)
+        })
+        .collect();
+
+    Ok(owned)
+}
+
+fn get_image_tag(image: &str) -> Option<String> {
+    let image = image.split('@').next().unwrap_or(image);
+    let name = image.rsplit('/').next().unwrap_or(image);
+    name.split_once(':')
+        .map(|(_, tag)| tag.to_string())
+        .filter(|tag| !tag.is_empty() && tag != "latest")
+}
+
+fn get_commit_sha(
+    replica_set: &KubernetesReplicaSetItem,
+    commit_annotation: &Option<String>,
+) -> Option<String> {
+    if let Some(annotation) = commit_annotation {
+        return replica_set
+            .spec
+            .template
+            .metadata
+            .annotations
+            .get(annotation)
+            .or_else(|| replica_set.metadata.annotations.get(annotation))
+            .cloned();
+    }
+    replica_set
+        .spec
+        .template
+        .spec
+        .containers
+        .first()
+        .and_then(|container| get_image_tag(&container.image))
+}
+
+fn pick_revisions(
+    replica_sets: Vec<KubernetesReplicaSetItem>,
+    commit_annotation: &Option<String>,
+) -> Vec<ReplicaSetRevision> {
+    let mut revisions = replica_sets
+        .into_iter()
+        .filter_map(|replica_set| {
+            let revision = replica_set
+                .metadata
+                .annotations
+                .get(REVISION_ANNOTATION)
+                .and_then(|revision| revision.parse::<u64>().ok());
+            let sha = get_commit_sha(&replica_set, commit_annotation);
+            if revision.is_none() || sha.is_none() {
+                log::debug!(
+                    "skip replica set without revision or commit: {:?}",
+                    replica_set.metadata.name
+                );
+            }
+            Some(ReplicaSetRevision {
+                revision: revision?,
+                sha: sha?,
+                deployed_at: replica_set.metadata.creation_timestamp,
+                rolled_back: false,
+                replica_set,
+            })
+        })
+        // A rollback moves the old ReplicaSet to a new revision but keeps its creation time,
+        // so only the revision tells the rollout order
+        .sorted_by_key(|it| it.revision)
+        .collect::<Vec<_>>();
+    // The rollout time of a rollback is not recorded; it happened after the previous rollout
+    let mut previous_deployed_at = None;
+    for revision in revisions.iter_mut() {
+        if let Some(previous_deployed_at) = previous_deployed_at {
+            if revision.deployed_at < previous_deployed_at {
+                revision.deployed_at = previous_deployed_at;
+                revision.rolled_back = true;
+            }
+        }
+        previous_deployed_at = Some(revision.deployed_at);
+    }
+
+    revisions
+        .into_iter()
+        .dedup_by(|a, b| a.sha == b.sha)
+        .collect()
+}
+
+fn rolled_back_revisions(
+    revisions: &[ReplicaSetRevision],
+    params: &DeploymentsFetcherParams,
+) -> Vec<DataQualityWarning> {
+    revisions
+        .iter()
+        .filter(|revision| {
+            revision.rolled_back && params.timeframe.is_include(&revision.deployed_at)
+        })
+        .map(|revision| {
+            DataQualityWarning::new(
+                revision.replica_set.metadata.name.clone(),
+                "Degraded: a rollback is dated at the rollout before it",
+            )
+        })
+        .collect()
+}
+
+fn replica_sets_without_commit(
+    replica_sets: &[KubernetesReplicaSetItem],
+    commit_annotation: &Option<String>,
+    params: &DeploymentsFetcherParams,
+) -> Vec<DataQualityWarning> {
+    replica_sets
+        .iter()
+        .filter(|replica_set| {
+            params
+                .timeframe
+                .is_include(&replica_set.metadata.creation_timestamp)
+        })
+        .filter(|replica_set| {
+            !replica_set
+                .metadata
+                .annotations
+                .contains_key(REVISION_ANNOTATION)
+                || get_commit_sha(replica_set, commit_annotation).is_none()
+        })
+        .map(|replica_set| {
+            DataQualityWarning::new(
+                replica_set.metadata.name.clone(),
+                "Skipped: the replica set has no revision or commit",
+            )
+        })
+        .collect()
+}
+
+async fn attach_commit(
+    github_credentials: GitHubCredentials,
+    github_api_base_url: Option<String>,
+    github_owner_repo: ValidatedGitHubOwnerRepo,
+    revision: ReplicaSetRevision,
+) -> Result<ReplicaSetOrRepositoryInfo, DeploymentsFetcherError> {
+    let commit = get_commit(
+        github_owner_repo,
+        github_credentials,
+        github_api_base_url,
+        &revision.sha,
+    )
+    .await?;
+
+    Ok(ReplicaSetOrRepositoryInfo::ReplicaSet(ReplicaSet {
+        revision,
+        commit,
+    }))
+}
+
+fn convert_to_items(
+    replica_set_nodes: NonEmptyVec<ReplicaSetOrRepositoryInfo>,
+) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
+    let mut sorted: NonEmptyVec<ReplicaSetOrRepositoryInfo> = replica_set_nodes;
+    sorted.sort_by_key(|a| match a {
+        ReplicaSetOrRepositoryInfo::ReplicaSet(replica_set) => (
+            replica_set.revision.deployed_at,
+            replica_set.revision.revision,
+        ),
+        ReplicaSetOrRepositoryInfo::RepositoryInfo(info) => (info.created_at, 0),
+    });
+    let (first_item, rest) = sorted.get();
+
+    let rest = rest
+        .into_iter()
+        .flat_map(|x| match x {
+            ReplicaSetOrRepositoryInfo::ReplicaSet(replica_set) => Some(replica_set),
+            ReplicaSetOrRepositoryInfo::RepositoryInfo(_info) => None,
+        })
+        .collect::<Vec<ReplicaSet>>();
+
+    let first_commit: BaseCommitShaOrRepositoryInfo = match first_item {
+        ReplicaSetOrRepositoryInfo::ReplicaSet(replica_set) => {
+            BaseCommitShaOrRepositoryInfo::BaseCommitSha(replica_set.commit.sha)
+        }
+        ReplicaSetOrRepositoryInfo::RepositoryInfo(info) => {
+            BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(info.created_at)
+        }
+    };
+
+    let deployment_items = rest
+        .iter()
+        .scan(
+            first_commit,
+            |previous: &mut BaseCommitShaOrRepositoryInfo, replica_set: &ReplicaSet| {
+                let deployed_at = replica_set.revision.deployed_at;
+                let committed_at = replica_set
+                    .commit
+                    .commit
+                    .author
+                    .as_ref()
+                    .and_then(|x| x.date);
+                let creator_login = replica_set
+                    .commit
+                    .author
+                    .as_ref()
+                    .map(|x| x.login.clone())
+                    .unwrap_or_default();
+                let commit_item = Commit {
+                    sha: replica_set.commit.sha.clone(),
+                    message: replica_set.commit.commit.message.clone(),
+                    resource_path: replica_set.commit.html_url.clone(),
+                    committed_at: committed_at.unwrap_or(deployed_at),
+                    creator_login: creator_login.clone(),
+                };
+                let deployment_item = DeploymentLog {
+                    info: DeploymentInfo::KubernetesReplicaSet {
+                        name: replica_set.revision.replica_set.metadata.name.clone(),
+                        revision: replica_set.revision.revision,
+                    },
+                    head_commit: commit_item,
+                    base: previous.clone(),
+                    creator_login,
+                    deployed_at,
+                };
+                *previous =
+                    BaseCommitShaOrRepositoryInfo::BaseCommitSha(replica_set.commit.sha.clone());
+                Some(deployment_item)
+            },
+        )
+        .collect::<Vec<DeploymentLog>>();
+
+    Ok(deployment_items)
+}
+
+pub struct DeploymentsFetcherWithKubernetesRollout {
+    pub kubeconfig_path: Option<String>,
+    pub kubernetes_context: Option<String>,
+    pub kubernetes_namespace: ValidatedKubernetesNamespace,
+    pub kubernetes_deployment_name: ValidatedKubernetesDeploymentName,
+    pub kubernetes_commit_annotation: Option<String>,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+}
+#[async_trait]
+impl DeploymentsFetcher for DeploymentsFetcherWithKubernetesRollout {
+    async fn fetch(
+        &self,
+        params: DeploymentsFetcherParams,
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
+        let kubeconfig = load_kubeconfig(self.kubeconfig_path.clone())?;
+        let connection = resolve_connection(kubeconfig, self.kubernetes_context.clone())?;
+        let replica_sets = fetch_replica_sets(
+            &connection,
+            &self.kubernetes_namespace,
+            &self.kubernetes_deployment_name,
+        )
+        .await?;
+        log::debug!("replica sets: {:?}", replica_sets.len());
+        let mut warnings =
+            replica_sets_without_commit(&replica_sets, &self.kubernetes_commit_annotation, &params);
+        let revisions = pick_revisions(replica_sets, &self.kubernetes_commit_annotation);
+        warnings.extend(rolled_back_revisions(&revisions, &params));
+        let (mut replica_sets, unresolved) = attach_commits(
+            revisions
+                .into_iter()
+                .map(|revision| {
+                    (
+                        revision.replica_set.metadata.name.clone(),
+                        attach_commit(
+                            self.github_credentials.clone(),
+                            self.github_api_base_url.clone(),
+                            self.github_owner_repo.clone(),
+                            revision,
+                        ),
+                    )
+                })
+                .collect(),
+            match self.kubernetes_commit_annotation {
+                Some(_) => "Skipped: the commit annotation is not a commit",
+                None => "Skipped: image tag is not a commit",
+            },
+        )
+        .await?;
+        warnings.extend(unresolved);
+        let repo_created_at = get_created_at(
+            &self.github_credentials,
+            self.github_api_base_url.as_deref(),
+            &self.github_owner_repo,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::GetRepositoryCreatedAtError)?;
+        log::debug!("repo_created_at: {:#?}", repo_created_at);
+        replica_sets.push(ReplicaSetOrRepositoryInfo::RepositoryInfo(
+            GitHubRepositoryInfo {
+                created_at: repo_created_at,
+            },
+        ));
+        let non_empty_nodes = NonEmptyVec::new(replica_sets)
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::DeploymentsFetcherResultIsEmptyList)?;
+
+        convert_to_items(non_empty_nodes).map(|logs| {
+            FetchedDeployments::from(logs)
+                .with_warnings(warnings)
+                .excluding(&params.author_exclusion)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{fetch_replica_sets, pick_revisions};
+    use crate::{
+        common_types::{
+            kubernetes_deployment_name::ValidatedKubernetesDeploymentName,
+            kubernetes_namespace::ValidatedKubernetesNamespace,
+        },
+        dependencies::deployments_fetcher::kubernetes_rollout_kubeconfig::{
+            resolve_connection, Kubeconfig,
+        },
+    };
+
+    fn replica_set(
+        name: &str,
+        owner_uid: &str,
+        revision: &str,
+        image: &str,
+        created_at: &str,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "metadata": {
+                "name": name,
+                "uid": format!("{}-uid", name),
+                "creationTimestamp": created_at,
+                "annotations": { "deployment.kubernetes.io/revision": revision },
+                "ownerReferences": [{ "kind": "Deployment", "name": "web", "uid": owner_uid }]
+            },
+            "spec": {
+                "template": {
+                    "spec": { "containers": [{ "name": "web", "image": image }] }
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn fetch_revisions_from_replica_sets() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/apis/apps/v1/namespaces/production/deployments/web"))
+            .and(header("authorization", "Bearer kube-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "metadata": {
+                    "name": "web",
+                    "uid": "web-uid",
+                    "creationTimestamp": "2023-01-01T00:00:00Z"
+                },
+                "spec": { "selector": { "matchLabels": { "app": "web" } } }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/apis/apps/v1/namespaces/production/replicasets"))
+            .and(query_param("labelSelector", "app=web"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [
+                    replica_set("web-2", "web-uid", "2", "ghcr.io/org/web:bbb", "2023-03-02T10:00:00Z"),
+                    replica_set("web-1", "web-uid", "1", "ghcr.io/org/web:aaa", "2023-03-01T10:00:00Z"),
+                    replica_set("other-1", "other-uid", "1", "ghcr.io/org/web:zzz", "2023-03-01T12:00:00Z"),
+                    replica_set("web-3", "web-uid", "3", "localhost:5000/web@sha256:ccc", "2023-03-03T10:00:00Z"),
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let kubeconfig: Kubeconfig = serde_yaml::from_str(&format!(
+            "
+current-context: test
+clusters:
+  - name: test
+    cluster:
+      server: {server}
+contexts:
+  - name: test
+    context:
+      cluster: test
+      user: test
+users:
+  - name: test
+    user:
+      token: kube-token
+",
+            server = server.uri()
+        ))
+        .unwrap();
+        let connection = resolve_connection(kubeconfig, None).unwrap();
+        let replica_sets = fetch_replica_sets(
+            &connection,
+            &ValidatedKubernetesNamespace::new(Some("production".to_string())).unwrap(),
+            &ValidatedKubernetesDeploymentName::new(Some("web".to_string())).unwrap(),
+        )
+        .await
+        .unwrap();
+        let revisions = pick_revisions(replica_sets, &None)
+            .into_iter()
+            .map(|it| (it.revision, it.sha))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            revisions,
+            vec![(1, "aaa".to_string()), (2, "bbb".to_string())]
+        );
+    }
+
+    #[test]
+    fn order_a_rolled_back_revision_after_the_one_it_replaced() {
+        // web-1 ran revision 1, then was scaled back up as revision 3 by a rollback
+        let replica_sets = vec![
+            replica_set(
+                "web-1",
+                "web-uid",
+                "3",
+                "ghcr.io/org/web:aaa",
+                "2023-03-01T10:00:00Z",
+            ),
+            replica_set(
+                "web-2",
+                "web-uid",
+                "2",
+                "ghcr.io/org/web:bbb",
+                "2023-03-02T10:00:00Z",
+            ),
+        ]
+        .into_iter()
+        .map(|it| serde_json::from_value(it).unwrap())
+        .collect();
+
+        let revisions = pick_revisions(replica_sets, &None);
+
+        assert_eq!(
+            revisions
+                .iter()
+                .map(|it| (it.revision, it.sha.as_str(), it.rolled_back))
+                .collect::<Vec<_>>(),
+            vec![(2, "bbb", false), (3, "aaa", true)]
+        );
+        assert_eq!(revisions[1].deployed_at, revisions[0].deployed_at);
+    }
+}
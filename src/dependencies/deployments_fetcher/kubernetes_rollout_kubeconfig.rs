@@ -0,0 +1,287 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use std::{env, fmt, fs, path::PathBuf};
+
+use super::interface::DeploymentsFetcherError;
+use crate::shared::redaction::{register_secret, MASK};
+
+//------------------------
+// kubeconfig file
+//------------------------
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct Kubeconfig {
+    pub(super) current_context: Option<String>,
+    #[serde(default)]
+    pub(super) clusters: Vec<KubeconfigNamedCluster>,
+    #[serde(default)]
+    pub(super) contexts: Vec<KubeconfigNamedContext>,
+    #[serde(default)]
+    pub(super) users: Vec<KubeconfigNamedUser>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct KubeconfigNamedCluster {
+    pub(super) name: String,
+    pub(super) cluster: KubeconfigCluster,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct KubeconfigCluster {
+    pub(super) server: String,
+    pub(super) certificate_authority: Option<String>,
+    pub(super) certificate_authority_data: Option<String>,
+    #[serde(default)]
+    pub(super) insecure_skip_tls_verify: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct KubeconfigNamedContext {
+    pub(super) name: String,
+    pub(super) context: KubeconfigContext,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct KubeconfigContext {
+    pub(super) cluster: String,
+    pub(super) user: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct KubeconfigNamedUser {
+    pub(super) name: String,
+    pub(super) user: KubeconfigUser,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct KubeconfigUser {
+    pub(super) token: Option<String>,
+    pub(super) token_file: Option<String>,
+    pub(super) client_certificate: Option<String>,
+    pub(super) client_certificate_data: Option<String>,
+    pub(super) client_key: Option<String>,
+    pub(super) client_key_data: Option<String>,
+}
+
+impl fmt::Debug for KubeconfigUser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("KubeconfigUser")
+            .field("token", &self.token.as_ref().map(|_| MASK))
+            .field("token_file", &self.token_file)
+            .field("client_certificate", &self.client_certificate)
+            .field("client_certificate_data", &self.client_certificate_data)
+            .field("client_key", &self.client_key)
+            .field(
+                "client_key_data",
+                &self.client_key_data.as_ref().map(|_| MASK),
+            )
+            .finish()
+    }
+}
+
+//------------------------
+// resolved connection
+//------------------------
+#[derive(Clone)]
+pub(super) struct KubernetesConnection {
+    pub(super) server: String,
+    pub(super) token: Option<String>,
+    pub(super) ca_pem: Option<Vec<u8>>,
+    pub(super) client_pem: Option<(Vec<u8>, Vec<u8>)>,
+    pub(super) insecure_skip_tls_verify: bool,
+}
+
+impl fmt::Debug for KubernetesConnection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("KubernetesConnection")
+            .field("server", &self.server)
+            .field("token", &self.token.as_ref().map(|_| MASK))
+            .field("ca_pem", &self.ca_pem.as_ref().map(|pem| pem.len()))
+            .field("client_pem", &self.client_pem.as_ref().map(|_| MASK))
+            .field("insecure_skip_tls_verify", &self.insecure_skip_tls_verify)
+            .finish()
+    }
+}
+
+fn register_connection(connection: KubernetesConnection) -> KubernetesConnection {
+    if let Some(token) = &connection.token {
+        register_secret(token);
+    }
+    if let Some((_, key)) = &connection.client_pem {
+        register_secret(&String::from_utf8_lossy(key));
+    }
+    connection
+}
+
+fn default_kubeconfig_path() -> Option<PathBuf> {
+    if let Ok(paths) = env::var("KUBECONFIG") {
+        if let Some(path) = env::split_paths(&paths).next() {
+            return Some(path);
+        }
+    }
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(".kube").join("config"))
+}
+
+fn read_data_or_file(
+    data: &Option<String>,
+    file: &Option<String>,
+) -> Result<Option<Vec<u8>>, DeploymentsFetcherError> {
+    if let Some(data) = data {
+        let decoded = STANDARD
+            .decode(data.trim())
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::CreateAPIClientError)?;
+        return Ok(Some(decoded));
+    }
+    if let Some(file) = file {
+        let content = fs::read(file)
+            .map_err(|e| anyhow::anyhow!("Cannot read {}: {}", file, e))
+            .map_err(DeploymentsFetcherError::CreateAPIClientError)?;
+        return Ok(Some(content));
+    }
+    Ok(None)
+}
+
+pub(super) fn load_kubeconfig(path: Option<String>) -> Result<Kubeconfig, DeploymentsFetcherError> {
+    let path = path
+        .map(PathBuf::from)
+        .or_else(default_kubeconfig_path)
+        .ok_or(DeploymentsFetcherError::CreateAPIClientError(
+            anyhow::anyhow!("Cannot find kubeconfig"),
+        ))?;
+    let content = fs::read_to_string(&path)
+        .map_err(|e| anyhow::anyhow!("Cannot read {:?}: {}", path, e))
+        .map_err(DeploymentsFetcherError::CreateAPIClientError)?;
+
+    serde_yaml::from_str::<Kubeconfig>(&content)
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::CreateAPIClientError)
+}
+
+pub(super) fn resolve_connection(
+    kubeconfig: Kubeconfig,
+    context_name: Option<String>,
+) -> Result<KubernetesConnection, DeploymentsFetcherError> {
+    let context_name = context_name.or(kubeconfig.current_context).ok_or(
+        DeploymentsFetcherError::CreateAPIClientError(anyhow::anyhow!(
+            "kubeconfig has no current-context"
+        )),
+    )?;
+    let context = kubeconfig
+        .contexts
+        .iter()
+        .find(|it| it.name == context_name)
+        .map(|it| it.context.clone())
+        .ok_or(DeploymentsFetcherError::CreateAPIClientError(
+            anyhow::anyhow!("Context is not found in kubeconfig: {}", context_name),
+        ))?;
+    let cluster = kubeconfig
+        .clusters
+        .iter()
+        .find(|it| it.name == context.cluster)
+        .map(|it| it.cluster.clone())
+        .ok_or(DeploymentsFetcherError::CreateAPIClientError(
+            anyhow::anyhow!("Cluster is not found in kubeconfig: {}", context.cluster),
+        ))?;
+    let user = kubeconfig
+        .users
+        .iter()
+        .find(|it| it.name == context.user)
+        .map(|it| it.user.clone())
+        .unwrap_or_default();
+
+    let token = match (user.token, user.token_file) {
+        (Some(token), _) => Some(token),
+        (None, Some(token_file)) => Some(
+            fs::read_to_string(&token_file)
+                .map_err(|e| anyhow::anyhow!("Cannot read {}: {}", token_file, e))
+                .map_err(DeploymentsFetcherError::CreateAPIClientError)?
+                .trim()
+                .to_string(),
+        ),
+        (None, None) => None,
+    };
+    let ca_pem = read_data_or_file(
+        &cluster.certificate_authority_data,
+        &cluster.certificate_authority,
+    )?;
+    let client_certificate =
+        read_data_or_file(&user.client_certificate_data, &user.client_certificate)?;
+    let client_key = read_data_or_file(&user.client_key_data, &user.client_key)?;
+    let client_pem = match (client_certificate, client_key) {
+        (Some(certificate), Some(key)) => Some((certificate, key)),
+        _ => None,
+    };
+
+    Ok(register_connection(KubernetesConnection {
+        server: cluster.server.trim_end_matches('/').to_string(),
+        token,
+        ca_pem,
+        client_pem,
+        insecure_skip_tls_verify: cluster.insecure_skip_tls_verify,
+    }))
+}
+
+pub(super) fn create_kubernetes_client(
+    connection: &KubernetesConnection,
+) -> Result<reqwest::Client, DeploymentsFetcherError> {
+    let mut builder =
+        reqwest::Client::builder().danger_accept_invalid_certs(connection.insecure_skip_tls_verify);
+    if let Some(ca_pem) = &connection.ca_pem {
+        let certificate = reqwest::Certificate::from_pem(ca_pem)
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::CreateAPIClientError)?;
+        builder = builder.add_root_certificate(certificate);
+    }
+    if let Some((certificate, key)) = &connection.client_pem {
+        // native-tls only accepts PKCS#8 keys; convert RSA keys with `openssl pkcs8 -topk8`
+        let identity = reqwest::Identity::from_pkcs8_pem(certificate, key)
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::CreateAPIClientError)?;
+        builder = builder.identity(identity);
+    }
+
+    builder
+        .build()
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::CreateAPIClientError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_connection, Kubeconfig};
+    use crate::shared::redaction::redact;
+
+    #[test]
+    fn hide_the_token_and_client_key() {
+        let kubeconfig: Kubeconfig = serde_yaml::from_str(
+            r#"
+current-context: prod
+clusters:
+  - name: prod
+    cluster:
+      server: https://k8s.example.com/
+contexts:
+  - name: prod
+    context:
+      cluster: prod
+      user: deployer
+users:
+  - name: deployer
+    user:
+      token: k8s-bearer-token-0123456789
+"#,
+        )
+        .unwrap();
+
+        let connection = resolve_connection(kubeconfig, None).unwrap();
+
+        assert!(!format!("{:?}", connection).contains("k8s-bearer-token-0123456789"));
+        assert_eq!(
+            redact("request failed for k8s-bearer-token-0123456789"),
+            "request failed for ********"
+        );
+    }
+}
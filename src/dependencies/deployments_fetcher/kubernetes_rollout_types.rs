@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use octocrab::models::repos::RepoCommit;
+
+use super::{
+    kubernetes_rollout_api_response::KubernetesReplicaSetItem, shared::GitHubRepositoryInfo,
+};
+
+#[derive(Debug, Clone)]
+pub(super) struct ReplicaSetRevision {
+    pub replica_set: KubernetesReplicaSetItem,
+    pub revision: u64,
+    pub sha: String,
+    pub deployed_at: DateTime<Utc>,
+    /// A rollback reuses an older ReplicaSet, whose creation is not the rollout time
+    pub rolled_back: bool,
+}
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // most are ReplicaSet
+pub(super) enum ReplicaSetOrRepositoryInfo {
+    ReplicaSet(ReplicaSet),
+    RepositoryInfo(GitHubRepositoryInfo),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct ReplicaSet {
+    pub revision: ReplicaSetRevision,
+    pub commit: RepoCommit,
+}
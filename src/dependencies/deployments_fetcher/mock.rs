@@ -2,6 +2,7 @@ use async_trait::async_trait;
 
 use super::interface::{
     DeploymentLog, DeploymentsFetcher, DeploymentsFetcherError, DeploymentsFetcherParams,
+    FetchedDeployments,
 };
 
 pub struct DeploymentsFetcherWithMock {
@@ -11,8 +12,9 @@ pub struct DeploymentsFetcherWithMock {
 impl DeploymentsFetcher for DeploymentsFetcherWithMock {
     async fn fetch(
         &self,
-        _params: DeploymentsFetcherParams,
-    ) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
-        Ok(self.deployment_logs.clone())
+        params: DeploymentsFetcherParams,
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
+        Ok(FetchedDeployments::from(self.deployment_logs.clone())
+            .excluding(&params.author_exclusion))
     }
 }
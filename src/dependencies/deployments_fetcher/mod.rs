@@ -1,16 +1,53 @@
+mod argo_cd_application_api_response;
+mod argo_cd_application_impl;
+mod argo_cd_application_types;
+mod aws_code_deploy_api_response;
+mod aws_code_deploy_impl;
+mod aws_code_deploy_signer;
+mod aws_code_deploy_types;
 mod github_deployment_graphql;
 mod github_deployment_impl;
 mod github_deployment_types;
-mod github_merged_pull_graphql;
+pub(crate) mod github_merged_pull_graphql;
 mod github_merged_pull_impl;
 mod github_merged_pull_types;
+mod github_pagination;
+mod heroku_pipeline_api_response;
+mod heroku_pipeline_impl;
 mod heroku_release_api_response;
 mod heroku_release_impl;
 mod heroku_release_types;
 pub mod interface;
+mod jenkins_build_api_response;
+mod jenkins_build_impl;
+mod jenkins_build_types;
+mod kubernetes_rollout_api_response;
+mod kubernetes_rollout_impl;
+mod kubernetes_rollout_kubeconfig;
+mod kubernetes_rollout_types;
 pub mod mock;
+mod netlify_deploy_api_response;
+mod netlify_deploy_impl;
+mod netlify_deploy_types;
+mod render_deploy_api_response;
+mod render_deploy_impl;
+mod render_deploy_types;
 pub(super) mod shared;
+mod vercel_deployment_api_response;
+mod vercel_deployment_impl;
+mod vercel_deployment_types;
 
+pub mod argo_cd_application {
+    // pub use super::argo_cd_application_api_response::*;
+    pub use super::argo_cd_application_impl::*;
+    // pub use super::argo_cd_application_types::*;
+}
+pub mod aws_code_deploy {
+    // pub use super::aws_code_deploy_api_response::*;
+    pub use super::aws_code_deploy_impl::*;
+    // pub use super::aws_code_deploy_signer::*;
+    // pub use super::aws_code_deploy_types::*;
+}
 pub mod github_deployment {
     // pub use super::github_deployment_graphql::*;
     pub use super::github_deployment_impl::*;
@@ -21,8 +58,38 @@ pub mod github_merged_pull {
     pub use super::github_merged_pull_impl::*;
     // pub use super::github_merged_pull_types::*;
 }
+pub mod heroku_pipeline {
+    // pub use super::heroku_pipeline_api_response::*;
+    pub use super::heroku_pipeline_impl::*;
+}
 pub mod heroku_release {
     // pub use super::heroku_release_api_response::*;
     pub use super::heroku_release_impl::*;
     // pub use super::heroku_release_types::*;
 }
+pub mod jenkins_build {
+    // pub use super::jenkins_build_api_response::*;
+    pub use super::jenkins_build_impl::*;
+    // pub use super::jenkins_build_types::*;
+}
+pub mod kubernetes_rollout {
+    // pub use super::kubernetes_rollout_api_response::*;
+    pub use super::kubernetes_rollout_impl::*;
+    // pub use super::kubernetes_rollout_kubeconfig::*;
+    // pub use super::kubernetes_rollout_types::*;
+}
+pub mod netlify_deploy {
+    // pub use super::netlify_deploy_api_response::*;
+    pub use super::netlify_deploy_impl::*;
+    // pub use super::netlify_deploy_types::*;
+}
+pub mod render_deploy {
+    // pub use super::render_deploy_api_response::*;
+    pub use super::render_deploy_impl::*;
+    // pub use super::render_deploy_types::*;
+}
+pub mod vercel_deployment {
+    // pub use super::vercel_deployment_api_response::*;
+    pub use super::vercel_deployment_impl::*;
+    // pub use super::vercel_deployment_types::*;
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+//------------------------
+// Netlify Site Deploys API
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct NetlifyDeployItem {
+    pub(super) id: String,
+    pub(super) state: String,
+    pub(super) context: Option<String>,
+    pub(super) commit_ref: Option<String>,
+    pub(super) created_at: chrono::DateTime<chrono::Utc>,
+    pub(super) published_at: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -0,0 +1,349 @@
+use async_trait::async_trait;
+use itertools::Itertools;
+
+use super::{
+    interface::{
+        BaseCommitShaOrRepositoryInfo, DataQualityWarning, DeploymentInfo, DeploymentLog,
+        DeploymentsFetcher, DeploymentsFetcherError, DeploymentsFetcherParams, FetchedDeployments,
+    },
+    netlify_deploy_api_response::NetlifyDeployItem,
+    netlify_deploy_types::{NetlifyDeploy, NetlifyDeployOrRepositoryInfo},
+    shared::{
+        attach_commits, create_http_client, get_commit, get_created_at, GitHubRepositoryInfo,
+    },
+};
+use crate::{
+    common_types::{
+        commit::Commit, github_credentials::GitHubCredentials,
+        github_owner_repo::ValidatedGitHubOwnerRepo, netlify_auth_token::ValidatedNetlifyAuthToken,
+        netlify_site_id::ValidatedNetlifySiteId,
+    },
+    shared::non_empty_vec::NonEmptyVec,
+};
+
+const NETLIFY_API_BASE_URL: &str = "https://api.netlify.com";
+const PRODUCTION_CONTEXT: &str = "production";
+const READY_STATE: &str = "ready";
+const PER_PAGE: usize = 100;
+
+async fn fetch_deploys(
+    base_url: &str,
+    netlify_auth_token: &ValidatedNetlifyAuthToken,
+    netlify_site_id: &ValidatedNetlifySiteId,
+    params: &DeploymentsFetcherParams,
+) -> Result<Vec<NetlifyDeployItem>, DeploymentsFetcherError> {
+    let client = create_http_client();
+    let url = format!(
+        "{base_url}/api/v1/sites/{site_id}/deploys",
+        base_url = base_url,
+        site_id = netlify_site_id
+    );
+    let since = params.timeframe.get_since();
+    let mut deploys: Vec<NetlifyDeployItem> = vec![];
+    let mut page_number = 1;
+    loop {
+        let response = client
+            .get(&url)
+            .query(&[
+                ("page", page_number.to_string()),
+                ("per_page", PER_PAGE.to_string()),
+            ])
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!("Bearer {token}", token = netlify_auth_token.expose()),
+            )
+            .header(reqwest::header::ACCEPT, "application/json")
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(DeploymentsFetcherError::InvalidResponse(format!(
+                "status: {:?}",
+                status
+            )));
+        }
+        let page = response
+            .json::<Vec<NetlifyDeployItem>>()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?;
+        // Deploys come newest first, so the page reaching before the timeframe
+        // already holds the deploy that the first one is compared against
+        let is_last_page =
+            page.len() < PER_PAGE || page.last().is_none_or(|deploy| deploy.created_at < since);
+        deploys.extend(page);
+        if is_last_page {
+            break;
+        }
+        page_number += 1;
+    }
+
+    Ok(deploys)
+}
+
+fn pick_production_deploys(deploys: Vec<NetlifyDeployItem>) -> Vec<(NetlifyDeployItem, String)> {
+    deploys
+        .into_iter()
+        .filter(|deploy| {
+            deploy.context.as_deref() == Some(PRODUCTION_CONTEXT) && deploy.state == READY_STATE
+        })
+        .filter_map(|deploy| {
+            let sha = deploy.commit_ref.clone()?;
+            Some((deploy, sha))
+        })
+        .sorted_by_key(|(deploy, _)| deploy.created_at)
+        // A redeploy of the same commit does not ship any new change
+        .dedup_by(|(_, a), (_, b)| a == b)
+        .collect()
+}
+
+fn deploys_without_commit(
+    deploys: &[NetlifyDeployItem],
+    params: &DeploymentsFetcherParams,
+) -> Vec<DataQualityWarning> {
+    deploys
+        .iter()
+        .filter(|deploy| {
+            deploy.context.as_deref() == Some(PRODUCTION_CONTEXT)
+                && deploy.state == READY_STATE
+                && deploy.commit_ref.is_none()
+                && params.timeframe.is_include(&deploy.created_at)
+        })
+        .map(|deploy| {
+            DataQualityWarning::new(deploy.id.clone(), "Skipped: the deploy has no commit")
+        })
+        .collect()
+}
+
+async fn attach_commit(
+    github_credentials: GitHubCredentials,
+    github_api_base_url: Option<String>,
+    github_owner_repo: ValidatedGitHubOwnerRepo,
+    deploy: NetlifyDeployItem,
+    sha: String,
+) -> Result<NetlifyDeployOrRepositoryInfo, DeploymentsFetcherError> {
+    let commit = get_commit(
+        github_owner_repo,
+        github_credentials,
+        github_api_base_url,
+        &sha,
+    )
+    .await?;
+
+    Ok(NetlifyDeployOrRepositoryInfo::NetlifyDeploy(
+        NetlifyDeploy { deploy, commit },
+    ))
+}
+
+fn convert_to_items(
+    deploy_nodes: NonEmptyVec<NetlifyDeployOrRepositoryInfo>,
+) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
+    let mut sorted: NonEmptyVec<NetlifyDeployOrRepositoryInfo> = deploy_nodes;
+    sorted.sort_by_key(|a| match a {
+        NetlifyDeployOrRepositoryInfo::NetlifyDeploy(deploy) => deploy.deploy.created_at,
+        NetlifyDeployOrRepositoryInfo::RepositoryInfo(info) => info.created_at,
+    });
+    let (first_item, rest) = sorted.get();
+
+    let rest = rest
+        .into_iter()
+        .flat_map(|x| match x {
+            NetlifyDeployOrRepositoryInfo::NetlifyDeploy(deploy) => Some(deploy),
+            NetlifyDeployOrRepositoryInfo::RepositoryInfo(_info) => None,
+        })
+        .collect::<Vec<NetlifyDeploy>>();
+
+    let first_commit: BaseCommitShaOrRepositoryInfo = match first_item {
+        NetlifyDeployOrRepositoryInfo::NetlifyDeploy(deploy) => {
+            BaseCommitShaOrRepositoryInfo::BaseCommitSha(deploy.commit.sha)
+        }
+        NetlifyDeployOrRepositoryInfo::RepositoryInfo(info) => {
+            BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(info.created_at)
+        }
+    };
+
+    let deployment_items = rest
+        .iter()
+        .scan(
+            first_commit,
+            |previous: &mut BaseCommitShaOrRepositoryInfo, deploy: &NetlifyDeploy| {
+                let deployed_at = deploy
+                    .deploy
+                    .published_at
+                    .unwrap_or(deploy.deploy.created_at);
+                let committed_at = deploy.commit.commit.author.as_ref().and_then(|x| x.date);
+                let creator_login = deploy
+                    .commit
+                    .author
+                    .as_ref()
+                    .map(|x| x.login.clone())
+                    .unwrap_or_default();
+                let commit_item = Commit {
+                    sha: deploy.commit.sha.clone(),
+                    message: deploy.commit.commit.message.clone(),
+                    resource_path: deploy.commit.html_url.clone(),
+                    committed_at: committed_at.unwrap_or(deployed_at),
+                    creator_login: creator_login.clone(),
+                };
+                let deployment_item = DeploymentLog {
+                    info: DeploymentInfo::NetlifyDeploy {
+                        id: deploy.deploy.id.clone(),
+                    },
+                    head_commit: commit_item,
+                    base: previous.clone(),
+                    creator_login,
+                    deployed_at,
+                };
+                *previous = BaseCommitShaOrRepositoryInfo::BaseCommitSha(deploy.commit.sha.clone());
+                Some(deployment_item)
+            },
+        )
+        .collect::<Vec<DeploymentLog>>();
+
+    Ok(deployment_items)
+}
+
+pub struct DeploymentsFetcherWithNetlifyDeploy {
+    pub netlify_api_base_url: Option<String>,
+    pub netlify_auth_token: ValidatedNetlifyAuthToken,
+    pub netlify_site_id: ValidatedNetlifySiteId,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+}
+#[async_trait]
+impl DeploymentsFetcher for DeploymentsFetcherWithNetlifyDeploy {
+    async fn fetch(
+        &self,
+        params: DeploymentsFetcherParams,
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
+        let base_url = self
+            .netlify_api_base_url
+            .clone()
+            .unwrap_or(NETLIFY_API_BASE_URL.to_string());
+        let deploys = fetch_deploys(
+            base_url.trim_end_matches('/'),
+            &self.netlify_auth_token,
+            &self.netlify_site_id,
+            &params,
+        )
+        .await?;
+        log::debug!("netlify deploys: {:?}", deploys.len());
+        let mut warnings = deploys_without_commit(&deploys, &params);
+        let production_deploys = pick_production_deploys(deploys);
+        let (mut deploys, unresolved) = attach_commits(
+            production_deploys
+                .into_iter()
+                .map(|(deploy, sha)| {
+                    (
+                        deploy.id.clone(),
+                        attach_commit(
+                            self.github_credentials.clone(),
+                            self.github_api_base_url.clone(),
+                            self.github_owner_repo.clone(),
+                            deploy,
+                            sha,
+                        ),
+                    )
+                })
+                .collect(),
+            "Skipped: the deploy commit is not a commit on GitHub",
+        )
+        .await?;
+        warnings.extend(unresolved);
+        let repo_created_at = get_created_at(
+            &self.github_credentials,
+            self.github_api_base_url.as_deref(),
+            &self.github_owner_repo,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::GetRepositoryCreatedAtError)?;
+        log::debug!("repo_created_at: {:#?}", repo_created_at);
+        deploys.push(NetlifyDeployOrRepositoryInfo::RepositoryInfo(
+            GitHubRepositoryInfo {
+                created_at: repo_created_at,
+            },
+        ));
+        let non_empty_nodes = NonEmptyVec::new(deploys)
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::DeploymentsFetcherResultIsEmptyList)?;
+
+        convert_to_items(non_empty_nodes).map(|logs| {
+            FetchedDeployments::from(logs)
+                .with_warnings(warnings)
+                .excluding(&params.author_exclusion)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{fetch_deploys, pick_production_deploys};
+    use crate::{
+        common_types::{
+            author_exclusion::AuthorExclusion, date_time_range::DateTimeRange,
+            netlify_auth_token::ValidatedNetlifyAuthToken, netlify_site_id::ValidatedNetlifySiteId,
+        },
+        dependencies::deployments_fetcher::interface::DeploymentsFetcherParams,
+    };
+
+    #[tokio::test]
+    async fn fetch_ready_production_deploys() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/sites/my-site/deploys"))
+            .and(query_param("page", "1"))
+            .and(header("authorization", "Bearer netlify-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "d4", "state": "ready", "context": "production", "commit_ref": "ccc",
+                  "created_at": "2023-03-04T10:00:00Z", "published_at": "2023-03-04T10:01:00Z" },
+                { "id": "d3", "state": "ready", "context": "deploy-preview", "commit_ref": "bbb",
+                  "created_at": "2023-03-03T10:00:00Z", "published_at": null },
+                { "id": "d2", "state": "error", "context": "production", "commit_ref": "bbb",
+                  "created_at": "2023-03-02T10:00:00Z", "published_at": null },
+                { "id": "d1", "state": "ready", "context": "production", "commit_ref": "aaa",
+                  "created_at": "2023-02-20T10:00:00Z", "published_at": "2023-02-20T10:01:00Z" }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let timeframe = DateTimeRange::new(
+            Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap(),
+        )
+        .unwrap();
+        let deploys = fetch_deploys(
+            &server.uri(),
+            &ValidatedNetlifyAuthToken::new(Some("netlify-token".to_string())).unwrap(),
+            &ValidatedNetlifySiteId::new(Some("my-site".to_string())).unwrap(),
+            &DeploymentsFetcherParams {
+                timeframe,
+                author_exclusion: AuthorExclusion::default(),
+            },
+        )
+        .await
+        .unwrap();
+        let picked = pick_production_deploys(deploys)
+            .into_iter()
+            .map(|(deploy, sha)| (deploy.id, sha))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            picked,
+            vec![
+                ("d1".to_string(), "aaa".to_string()),
+                ("d4".to_string(), "ccc".to_string())
+            ]
+        );
+    }
+}
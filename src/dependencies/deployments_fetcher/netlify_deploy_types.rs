@@ -0,0 +1,16 @@
+use octocrab::models::repos::RepoCommit;
+
+use super::{netlify_deploy_api_response::NetlifyDeployItem, shared::GitHubRepositoryInfo};
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // most are NetlifyDeploy
+pub(super) enum NetlifyDeployOrRepositoryInfo {
+    NetlifyDeploy(NetlifyDeploy),
+    RepositoryInfo(GitHubRepositoryInfo),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct NetlifyDeploy {
+    pub deploy: NetlifyDeployItem,
+    pub commit: RepoCommit,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+//------------------------
+// Render Deploys API
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct RenderDeployListItem {
+    pub(super) deploy: RenderDeployItem,
+    pub(super) cursor: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub(super) struct RenderDeployItem {
+    pub(super) id: String,
+    pub(super) status: String,
+    pub(super) commit: Option<RenderDeployCommit>,
+    pub(super) created_at: chrono::DateTime<chrono::Utc>,
+    pub(super) finished_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct RenderDeployCommit {
+    pub(super) id: String,
+}
@@ -0,0 +1,355 @@
+use async_trait::async_trait;
+use itertools::Itertools;
+
+use super::{
+    interface::{
+        BaseCommitShaOrRepositoryInfo, DataQualityWarning, DeploymentInfo, DeploymentLog,
+        DeploymentsFetcher, DeploymentsFetcherError, DeploymentsFetcherParams, FetchedDeployments,
+    },
+    render_deploy_api_response::{RenderDeployItem, RenderDeployListItem},
+    render_deploy_types::{RenderDeploy, RenderDeployOrRepositoryInfo},
+    shared::{
+        attach_commits, create_http_client, get_commit, get_created_at, GitHubRepositoryInfo,
+    },
+};
+use crate::{
+    common_types::{
+        commit::Commit, github_credentials::GitHubCredentials,
+        github_owner_repo::ValidatedGitHubOwnerRepo, render_api_key::ValidatedRenderApiKey,
+        render_service_id::ValidatedRenderServiceId,
+    },
+    shared::non_empty_vec::NonEmptyVec,
+};
+
+const RENDER_API_BASE_URL: &str = "https://api.render.com";
+// "deactivated" deploys were live until a newer deploy replaced them
+const SUCCEEDED_STATUSES: [&str; 2] = ["live", "deactivated"];
+const LIMIT: usize = 100;
+
+async fn fetch_deploys(
+    base_url: &str,
+    render_api_key: &ValidatedRenderApiKey,
+    render_service_id: &ValidatedRenderServiceId,
+    params: &DeploymentsFetcherParams,
+) -> Result<Vec<RenderDeployItem>, DeploymentsFetcherError> {
+    let client = create_http_client();
+    let url = format!(
+        "{base_url}/v1/services/{service_id}/deploys",
+        base_url = base_url,
+        service_id = render_service_id
+    );
+    let since = params.timeframe.get_since();
+    let mut deploys: Vec<RenderDeployItem> = vec![];
+    let mut cursor: Option<String> = None;
+    loop {
+        let mut query = vec![("limit", LIMIT.to_string())];
+        if let Some(cursor) = cursor {
+            query.push(("cursor", cursor));
+        }
+        let response = client
+            .get(&url)
+            .query(&query)
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!("Bearer {token}", token = render_api_key.expose()),
+            )
+            .header(reqwest::header::ACCEPT, "application/json")
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(DeploymentsFetcherError::InvalidResponse(format!(
+                "status: {:?}",
+                status
+            )));
+        }
+        let page = response
+            .json::<Vec<RenderDeployListItem>>()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?;
+        // Deploys come newest first, so the page reaching before the timeframe
+        // already holds the deploy that the first one is compared against
+        let is_last_page = page.len() < LIMIT
+            || page
+                .last()
+                .is_none_or(|item| item.deploy.created_at < since);
+        cursor = page.last().map(|item| item.cursor.clone());
+        deploys.extend(page.into_iter().map(|item| item.deploy));
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok(deploys)
+}
+
+// A Render service has no preview target, each environment is its own service
+fn pick_succeeded_deploys(deploys: Vec<RenderDeployItem>) -> Vec<(RenderDeployItem, String)> {
+    deploys
+        .into_iter()
+        .filter(|deploy| SUCCEEDED_STATUSES.contains(&deploy.status.as_str()))
+        .filter_map(|deploy| {
+            let sha = deploy.commit.as_ref()?.id.clone();
+            Some((deploy, sha))
+        })
+        .sorted_by_key(|(deploy, _)| deploy.created_at)
+        // A redeploy of the same commit does not ship any new change
+        .dedup_by(|(_, a), (_, b)| a == b)
+        .collect()
+}
+
+fn deploys_without_commit(
+    deploys: &[RenderDeployItem],
+    params: &DeploymentsFetcherParams,
+) -> Vec<DataQualityWarning> {
+    deploys
+        .iter()
+        .filter(|deploy| {
+            SUCCEEDED_STATUSES.contains(&deploy.status.as_str())
+                && deploy.commit.is_none()
+                && params.timeframe.is_include(&deploy.created_at)
+        })
+        .map(|deploy| {
+            DataQualityWarning::new(deploy.id.clone(), "Skipped: the deploy has no commit")
+        })
+        .collect()
+}
+
+async fn attach_commit(
+    github_credentials: GitHubCredentials,
+    github_api_base_url: Option<String>,
+    github_owner_repo: ValidatedGitHubOwnerRepo,
+    deploy: RenderDeployItem,
+    sha: String,
+) -> Result<RenderDeployOrRepositoryInfo, DeploymentsFetcherError> {
+    let commit = get_commit(
+        github_owner_repo,
+        github_credentials,
+        github_api_base_url,
+        &sha,
+    )
+    .await?;
+
+    Ok(RenderDeployOrRepositoryInfo::RenderDeploy(RenderDeploy {
+        deploy,
+        commit,
+    }))
+}
+
+fn convert_to_items(
+    deploy_nodes: NonEmptyVec<RenderDeployOrRepositoryInfo>,
+) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
+    let mut sorted: NonEmptyVec<RenderDeployOrRepositoryInfo> = deploy_nodes;
+    sorted.sort_by_key(|a| match a {
+        RenderDeployOrRepositoryInfo::RenderDeploy(deploy) => deploy.deploy.created_at,
+        RenderDeployOrRepositoryInfo::RepositoryInfo(info) => info.created_at,
+    });
+    let (first_item, rest) = sorted.get();
+
+    let rest = rest
+        .into_iter()
+        .flat_map(|x| match x {
+            RenderDeployOrRepositoryInfo::RenderDeploy(deploy) => Some(deploy),
+            RenderDeployOrRepositoryInfo::RepositoryInfo(_info) => None,
+        })
+        .collect::<Vec<RenderDeploy>>();
+
+    let first_commit: BaseCommitShaOrRepositoryInfo = match first_item {
+        RenderDeployOrRepositoryInfo::RenderDeploy(deploy) => {
+            BaseCommitShaOrRepositoryInfo::BaseCommitSha(deploy.commit.sha)
+        }
+        RenderDeployOrRepositoryInfo::RepositoryInfo(info) => {
+            BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(info.created_at)
+        }
+    };
+
+    let deployment_items = rest
+        .iter()
+        .scan(
+            first_commit,
+            |previous: &mut BaseCommitShaOrRepositoryInfo, deploy: &RenderDeploy| {
+                let deployed_at = deploy
+                    .deploy
+                    .finished_at
+                    .unwrap_or(deploy.deploy.created_at);
+                let committed_at = deploy.commit.commit.author.as_ref().and_then(|x| x.date);
+                let creator_login = deploy
+                    .commit
+                    .author
+                    .as_ref()
+                    .map(|x| x.login.clone())
+                    .unwrap_or_default();
+                let commit_item = Commit {
+                    sha: deploy.commit.sha.clone(),
+                    message: deploy.commit.commit.message.clone(),
+                    resource_path: deploy.commit.html_url.clone(),
+                    committed_at: committed_at.unwrap_or(deployed_at),
+                    creator_login: creator_login.clone(),
+                };
+                let deployment_item = DeploymentLog {
+                    info: DeploymentInfo::RenderDeploy {
+                        id: deploy.deploy.id.clone(),
+                    },
+                    head_commit: commit_item,
+                    base: previous.clone(),
+                    creator_login,
+                    deployed_at,
+                };
+                *previous = BaseCommitShaOrRepositoryInfo::BaseCommitSha(deploy.commit.sha.clone());
+                Some(deployment_item)
+            },
+        )
+        .collect::<Vec<DeploymentLog>>();
+
+    Ok(deployment_items)
+}
+
+pub struct DeploymentsFetcherWithRenderDeploy {
+    pub render_api_base_url: Option<String>,
+    pub render_api_key: ValidatedRenderApiKey,
+    pub render_service_id: ValidatedRenderServiceId,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+}
+#[async_trait]
+impl DeploymentsFetcher for DeploymentsFetcherWithRenderDeploy {
+    async fn fetch(
+        &self,
+        params: DeploymentsFetcherParams,
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
+        let base_url = self
+            .render_api_base_url
+            .clone()
+            .unwrap_or(RENDER_API_BASE_URL.to_string());
+        let deploys = fetch_deploys(
+            base_url.trim_end_matches('/'),
+            &self.render_api_key,
+            &self.render_service_id,
+            &params,
+        )
+        .await?;
+        log::debug!("render deploys: {:?}", deploys.len());
+        let mut warnings = deploys_without_commit(&deploys, &params);
+        let succeeded_deploys = pick_succeeded_deploys(deploys);
+        let (mut deploys, unresolved) = attach_commits(
+            succeeded_deploys
+                .into_iter()
+                .map(|(deploy, sha)| {
+                    (
+                        deploy.id.clone(),
+                        attach_commit(
+                            self.github_credentials.clone(),
+                            self.github_api_base_url.clone(),
+                            self.github_owner_repo.clone(),
+                            deploy,
+                            sha,
+                        ),
+                    )
+                })
+                .collect(),
+            "Skipped: the deploy commit is not a commit on GitHub",
+        )
+        .await?;
+        warnings.extend(unresolved);
+        let repo_created_at = get_created_at(
+            &self.github_credentials,
+            self.github_api_base_url.as_deref(),
+            &self.github_owner_repo,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::GetRepositoryCreatedAtError)?;
+        log::debug!("repo_created_at: {:#?}", repo_created_at);
+        deploys.push(RenderDeployOrRepositoryInfo::RepositoryInfo(
+            GitHubRepositoryInfo {
+                created_at: repo_created_at,
+            },
+        ));
+        let non_empty_nodes = NonEmptyVec::new(deploys)
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::DeploymentsFetcherResultIsEmptyList)?;
+
+        convert_to_items(non_empty_nodes).map(|logs| {
+            FetchedDeployments::from(logs)
+                .with_warnings(warnings)
+                .excluding(&params.author_exclusion)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{fetch_deploys, pick_succeeded_deploys};
+    use crate::{
+        common_types::{
+            author_exclusion::AuthorExclusion, date_time_range::DateTimeRange,
+            render_api_key::ValidatedRenderApiKey, render_service_id::ValidatedRenderServiceId,
+        },
+        dependencies::deployments_fetcher::interface::DeploymentsFetcherParams,
+    };
+
+    #[tokio::test]
+    async fn fetch_succeeded_deploys() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/services/srv-123/deploys"))
+            .and(query_param("limit", "100"))
+            .and(header("authorization", "Bearer render-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "cursor": "c4", "deploy": { "id": "dep-4", "status": "live",
+                  "commit": { "id": "ccc" }, "createdAt": "2023-03-04T10:00:00Z",
+                  "finishedAt": "2023-03-04T10:05:00Z" } },
+                { "cursor": "c3", "deploy": { "id": "dep-3", "status": "build_failed",
+                  "commit": { "id": "bbb" }, "createdAt": "2023-03-03T10:00:00Z",
+                  "finishedAt": null } },
+                { "cursor": "c2", "deploy": { "id": "dep-2", "status": "deactivated",
+                  "commit": { "id": "aaa" }, "createdAt": "2023-03-02T10:00:00Z",
+                  "finishedAt": "2023-03-02T10:05:00Z" } },
+                { "cursor": "c1", "deploy": { "id": "dep-1", "status": "deactivated",
+                  "commit": { "id": "aaa" }, "createdAt": "2023-02-20T10:00:00Z",
+                  "finishedAt": "2023-02-20T10:05:00Z" } }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let timeframe = DateTimeRange::new(
+            Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap(),
+        )
+        .unwrap();
+        let deploys = fetch_deploys(
+            &server.uri(),
+            &ValidatedRenderApiKey::new(Some("render-key".to_string())).unwrap(),
+            &ValidatedRenderServiceId::new(Some("srv-123".to_string())).unwrap(),
+            &DeploymentsFetcherParams {
+                timeframe,
+                author_exclusion: AuthorExclusion::default(),
+            },
+        )
+        .await
+        .unwrap();
+        let picked = pick_succeeded_deploys(deploys)
+            .into_iter()
+            .map(|(deploy, sha)| (deploy.id, sha))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            picked,
+            vec![
+                ("dep-1".to_string(), "aaa".to_string()),
+                ("dep-4".to_string(), "ccc".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use octocrab::models::repos::RepoCommit;
+
+use super::{render_deploy_api_response::RenderDeployItem, shared::GitHubRepositoryInfo};
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // most are RenderDeploy
+pub(super) enum RenderDeployOrRepositoryInfo {
+    RenderDeploy(RenderDeploy),
+    RepositoryInfo(GitHubRepositoryInfo),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct RenderDeploy {
+    pub deploy: RenderDeployItem,
+    pub commit: RepoCommit,
+}
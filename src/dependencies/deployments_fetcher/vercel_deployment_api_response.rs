@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+//------------------------
+// Vercel Deployments API
+//------------------------
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct VercelDeploymentList {
+    pub(super) deployments: Vec<VercelDeploymentItem>,
+    pub(super) pagination: VercelPagination,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct VercelDeploymentItem {
+    pub(super) uid: String,
+    pub(super) url: String,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub(super) created: chrono::DateTime<chrono::Utc>,
+    #[serde(default, with = "chrono::serde::ts_milliseconds_option")]
+    pub(super) ready: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(alias = "readyState")]
+    pub(super) state: Option<String>,
+    pub(super) target: Option<String>,
+    #[serde(default)]
+    pub(super) meta: VercelDeploymentMeta,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub(super) struct VercelDeploymentMeta {
+    pub(super) github_commit_sha: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct VercelPagination {
+    pub(super) next: Option<i64>,
+}
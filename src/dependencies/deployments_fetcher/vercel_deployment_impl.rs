@@ -0,0 +1,393 @@
+use async_trait::async_trait;
+use itertools::Itertools;
+
+use super::{
+    interface::{
+        BaseCommitShaOrRepositoryInfo, DataQualityWarning, DeploymentInfo, DeploymentLog,
+        DeploymentsFetcher, DeploymentsFetcherError, DeploymentsFetcherParams, FetchedDeployments,
+    },
+    shared::{
+        attach_commits, create_http_client, get_commit, get_created_at, GitHubRepositoryInfo,
+    },
+    vercel_deployment_api_response::{VercelDeploymentItem, VercelDeploymentList},
+    vercel_deployment_types::{VercelDeployment, VercelDeploymentOrRepositoryInfo},
+};
+use crate::{
+    common_types::{
+        commit::Commit, github_credentials::GitHubCredentials,
+        github_owner_repo::ValidatedGitHubOwnerRepo, vercel_auth_token::ValidatedVercelAuthToken,
+        vercel_project_id::ValidatedVercelProjectId,
+    },
+    shared::non_empty_vec::NonEmptyVec,
+};
+
+const VERCEL_API_BASE_URL: &str = "https://api.vercel.com";
+const PRODUCTION_TARGET: &str = "production";
+const READY_STATE: &str = "READY";
+
+async fn fetch_deployments(
+    base_url: &str,
+    vercel_auth_token: &ValidatedVercelAuthToken,
+    vercel_project_id: &ValidatedVercelProjectId,
+    vercel_team_id: &Option<String>,
+    params: &DeploymentsFetcherParams,
+) -> Result<Vec<VercelDeploymentItem>, DeploymentsFetcherError> {
+    let client = create_http_client();
+    let url = format!("{base_url}/v6/deployments", base_url = base_url);
+    let since = params.timeframe.get_since();
+    let mut deployments: Vec<VercelDeploymentItem> = vec![];
+    let mut until: Option<i64> = None;
+    loop {
+        let mut query = vec![
+            ("projectId", vercel_project_id.to_string()),
+            ("target", PRODUCTION_TARGET.to_string()),
+            ("state", READY_STATE.to_string()),
+            ("limit", "100".to_string()),
+        ];
+        if let Some(team_id) = vercel_team_id {
+            query.push(("teamId", team_id.clone()));
+        }
+        if let Some(until) = until {
+            query.push(("until", until.to_string()));
+        }
+        let response = client
+            .get(&url)
+            .query(&query)
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!("Bearer {token}", token = vercel_auth_token.expose()),
+            )
+            .header(reqwest::header::ACCEPT, "application/json")
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(DeploymentsFetcherError::InvalidResponse(format!(
+                "status: {:?}",
+                status
+            )));
+        }
+        let page = response
+            .json::<VercelDeploymentList>()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::FetchError)?;
+        // Deployments come newest first, so the page reaching before the timeframe
+        // already holds the deployment that the first one is compared against
+        let reached_since = page
+            .deployments
+            .last()
+            .is_none_or(|deployment| deployment.created < since);
+        deployments.extend(page.deployments);
+        match page.pagination.next {
+            Some(next) if !reached_since => until = Some(next),
+            _ => break,
+        }
+    }
+
+    Ok(deployments)
+}
+
+fn pick_production_deployments(
+    deployments: Vec<VercelDeploymentItem>,
+) -> Vec<(VercelDeploymentItem, String)> {
+    deployments
+        .into_iter()
+        .filter(|deployment| {
+            deployment.target.as_deref() == Some(PRODUCTION_TARGET)
+                && deployment.state.as_deref() == Some(READY_STATE)
+        })
+        .filter_map(|deployment| {
+            let sha = deployment.meta.github_commit_sha.clone()?;
+            Some((deployment, sha))
+        })
+        .sorted_by_key(|(deployment, _)| deployment.created)
+        // A redeploy of the same commit does not ship any new change
+        .dedup_by(|(_, a), (_, b)| a == b)
+        .collect()
+}
+
+fn deployments_without_commit(
+    deployments: &[VercelDeploymentItem],
+    params: &DeploymentsFetcherParams,
+) -> Vec<DataQualityWarning> {
+    deployments
+        .iter()
+        .filter(|deployment| {
+            deployment.target.as_deref() == Some(PRODUCTION_TARGET)
+                && deployment.state.as_deref() == Some(READY_STATE)
+                && deployment.meta.github_commit_sha.is_none()
+                && params.timeframe.is_include(&deployment.created)
+        })
+        .map(|deployment| {
+            DataQualityWarning::new(
+                deployment.uid.clone(),
+                "Skipped: the deployment has no GitHub commit",
+            )
+        })
+        .collect()
+}
+
+async fn attach_commit(
+    github_credentials: GitHubCredentials,
+    github_api_base_url: Option<String>,
+    github_owner_repo: ValidatedGitHubOwnerRepo,
+    deployment: VercelDeploymentItem,
+    sha: String,
+) -> Result<VercelDeploymentOrRepositoryInfo, DeploymentsFetcherError> {
+    let commit = get_commit(
+        github_owner_repo,
+        github_credentials,
+        github_api_base_url,
+        &sha,
+    )
+    .await?;
+
+    Ok(VercelDeploymentOrRepositoryInfo::VercelDeployment(
+        VercelDeployment { deployment, commit },
+    ))
+}
+
+fn convert_to_items(
+    deployment_nodes: NonEmptyVec<VercelDeploymentOrRepositoryInfo>,
+) -> Result<Vec<DeploymentLog>, DeploymentsFetcherError> {
+    let mut sorted: NonEmptyVec<VercelDeploymentOrRepositoryInfo> = deployment_nodes;
+    sorted.sort_by_key(|a| match a {
+        VercelDeploymentOrRepositoryInfo::VercelDeployment(deployment) => {
+            deployment.deployment.created
+        }
+        VercelDeploymentOrRepositoryInfo::RepositoryInfo(info) => info.created_at,
+    });
+    let (first_item, rest) = sorted.get();
+
+    let rest = rest
+        .into_iter()
+        .flat_map(|x| match x {
+            VercelDeploymentOrRepositoryInfo::VercelDeployment(deployment) => Some(deployment),
+            VercelDeploymentOrRepositoryInfo::RepositoryInfo(_info) => None,
+        })
+        .collect::<Vec<VercelDeployment>>();
+
+    let first_commit: BaseCommitShaOrRepositoryInfo = match first_item {
+        VercelDeploymentOrRepositoryInfo::VercelDeployment(deployment) => {
+            BaseCommitShaOrRepositoryInfo::BaseCommitSha(deployment.commit.sha)
+        }
+        VercelDeploymentOrRepositoryInfo::RepositoryInfo(info) => {
+            BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(info.created_at)
+        }
+    };
+
+    let deployment_items = rest
+        .iter()
+        .scan(
+            first_commit,
+            |previous: &mut BaseCommitShaOrRepositoryInfo, deployment: &VercelDeployment| {
+                let deployed_at = deployment
+                    .deployment
+                    .ready
+                    .unwrap_or(deployment.deployment.created);
+                let committed_at = deployment
+                    .commit
+                    .commit
+                    .author
+                    .as_ref()
+                    .and_then(|x| x.date);
+                let creator_login = deployment
+                    .commit
+                    .author
+                    .as_ref()
+                    .map(|x| x.login.clone())
+                    .unwrap_or_default();
+                let commit_item = Commit {
+                    sha: deployment.commit.sha.clone(),
+                    message: deployment.commit.commit.message.clone(),
+                    resource_path: deployment.commit.html_url.clone(),
+                    committed_at: committed_at.unwrap_or(deployed_at),
+                    creator_login: creator_login.clone(),
+                };
+                let deployment_item = DeploymentLog {
+                    info: DeploymentInfo::VercelDeployment {
+                        id: deployment.deployment.uid.clone(),
+                        url: deployment.deployment.url.clone(),
+                    },
+                    head_commit: commit_item,
+                    base: previous.clone(),
+                    creator_login,
+                    deployed_at,
+                };
+                *previous =
+                    BaseCommitShaOrRepositoryInfo::BaseCommitSha(deployment.commit.sha.clone());
+                Some(deployment_item)
+            },
+        )
+        .collect::<Vec<DeploymentLog>>();
+
+    Ok(deployment_items)
+}
+
+pub struct DeploymentsFetcherWithVercelDeployment {
+    pub vercel_api_base_url: Option<String>,
+    pub vercel_auth_token: ValidatedVercelAuthToken,
+    pub vercel_project_id: ValidatedVercelProjectId,
+    pub vercel_team_id: Option<String>,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+}
+#[async_trait]
+impl DeploymentsFetcher for DeploymentsFetcherWithVercelDeployment {
+    async fn fetch(
+        &self,
+        params: DeploymentsFetcherParams,
+    ) -> Result<FetchedDeployments, DeploymentsFetcherError> {
+        let base_url = self
+            .vercel_api_base_url
+            .clone()
+            .unwrap_or(VERCEL_API_BASE_URL.to_string());
+        let deployments = fetch_deployments(
+            base_url.trim_end_matches('/'),
+            &self.vercel_auth_token,
+            &self.vercel_project_id,
+            &self.vercel_team_id,
+            &params,
+        )
+        .await?;
+        log::debug!("vercel deployments: {:?}", deployments.len());
+        let mut warnings = deployments_without_commit(&deployments, &params);
+        let production_deployments = pick_production_deployments(deployments);
+        let (mut deployments, unresolved) = attach_commits(
+            production_deployments
+                .into_iter()
+                .map(|(deployment, sha)| {
+                    (
+                        deployment.uid.clone(),
+                        attach_commit(
+                            self.github_credentials.clone(),
+                            self.github_api_base_url.clone(),
+                            self.github_owner_repo.clone(),
+                            deployment,
+                            sha,
+                        ),
+                    )
+                })
+                .collect(),
+            "Skipped: the deployment commit is not a commit on GitHub",
+        )
+        .await?;
+        warnings.extend(unresolved);
+        let repo_created_at = get_created_at(
+            &self.github_credentials,
+            self.github_api_base_url.as_deref(),
+            &self.github_owner_repo,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(DeploymentsFetcherError::GetRepositoryCreatedAtError)?;
+        log::debug!("repo_created_at: {:#?}", repo_created_at);
+        deployments.push(VercelDeploymentOrRepositoryInfo::RepositoryInfo(
+            GitHubRepositoryInfo {
+                created_at: repo_created_at,
+            },
+        ));
+        let non_empty_nodes = NonEmptyVec::new(deployments)
+            .map_err(|e| anyhow::anyhow!(e))
+            .map_err(DeploymentsFetcherError::DeploymentsFetcherResultIsEmptyList)?;
+
+        convert_to_items(non_empty_nodes).map(|logs| {
+            FetchedDeployments::from(logs)
+                .with_warnings(warnings)
+                .excluding(&params.author_exclusion)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{fetch_deployments, pick_production_deployments};
+    use crate::{
+        common_types::{
+            author_exclusion::AuthorExclusion, date_time_range::DateTimeRange,
+            vercel_auth_token::ValidatedVercelAuthToken,
+            vercel_project_id::ValidatedVercelProjectId,
+        },
+        dependencies::deployments_fetcher::interface::DeploymentsFetcherParams,
+    };
+
+    #[tokio::test]
+    async fn fetch_production_deployments_across_pages() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v6/deployments"))
+            .and(query_param("projectId", "my-app"))
+            .and(query_param("until", "1677664800000"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "deployments": [
+                    { "uid": "dpl_2", "url": "my-app-2.vercel.app", "created": 1677664800000_i64,
+                      "state": "READY", "target": "production", "meta": { "githubCommitSha": "aaa" } },
+                    { "uid": "dpl_1", "url": "my-app-1.vercel.app", "created": 1677578400000_i64,
+                      "state": "READY", "target": "production", "meta": { "githubCommitSha": "000" } }
+                ],
+                "pagination": { "next": null }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v6/deployments"))
+            .and(query_param("projectId", "my-app"))
+            .and(header("authorization", "Bearer vercel-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "deployments": [
+                    { "uid": "dpl_5", "url": "my-app-5.vercel.app", "created": 1678010400000_i64,
+                      "ready": 1678010460000_i64, "state": "READY", "target": "production",
+                      "meta": { "githubCommitSha": "ccc" } },
+                    { "uid": "dpl_4", "url": "my-app-4.vercel.app", "created": 1677924000000_i64,
+                      "state": "READY", "target": null, "meta": { "githubCommitSha": "bbb" } },
+                    { "uid": "dpl_3", "url": "my-app-3.vercel.app", "created": 1677837600000_i64,
+                      "state": "READY", "target": "production", "meta": { "githubCommitSha": "aaa" } }
+                ],
+                "pagination": { "next": 1677664800000_i64 }
+            })))
+            .mount(&server)
+            .await;
+
+        let timeframe = DateTimeRange::new(
+            Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap(),
+        )
+        .unwrap();
+        let deployments = fetch_deployments(
+            &server.uri(),
+            &ValidatedVercelAuthToken::new(Some("vercel-token".to_string())).unwrap(),
+            &ValidatedVercelProjectId::new(Some("my-app".to_string())).unwrap(),
+            &None,
+            &DeploymentsFetcherParams {
+                timeframe,
+                author_exclusion: AuthorExclusion::default(),
+            },
+        )
+        .await
+        .unwrap();
+        let picked = pick_production_deployments(deployments)
+            .into_iter()
+            .map(|(deployment, sha)| (deployment.uid, sha))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            picked,
+            vec![
+                ("dpl_1".to_string(), "000".to_string()),
+                ("dpl_2".to_string(), "aaa".to_string()),
+                ("dpl_5".to_string(), "ccc".to_string())
+            ]
+        );
+    }
+}
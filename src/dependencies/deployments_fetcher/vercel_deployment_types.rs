@@ -0,0 +1,16 @@
+use octocrab::models::repos::RepoCommit;
+
+use super::{shared::GitHubRepositoryInfo, vercel_deployment_api_response::VercelDeploymentItem};
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // most are VercelDeployment
+pub(super) enum VercelDeploymentOrRepositoryInfo {
+    VercelDeployment(VercelDeployment),
+    RepositoryInfo(GitHubRepositoryInfo),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct VercelDeployment {
+    pub deployment: VercelDeploymentItem,
+    pub commit: RepoCommit,
+}
@@ -1,3 +1,5 @@
 pub mod deployments_fetcher;
 pub mod project_config_io;
+pub mod pull_requests_fetcher;
+pub mod secret_store;
 pub mod two_commits_comparer;
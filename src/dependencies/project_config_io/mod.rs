@@ -1,3 +1,4 @@
 pub mod reader;
+pub mod secrets;
 pub(super) mod settings_toml;
 pub mod writer;
@@ -6,22 +6,47 @@ use crate::{
         github_personal_token::ValidatedGitHubPersonalToken,
         heroku_auth_token::ValidatedHerokuAuthToken,
     },
+    dependencies::secret_store::interface::SecretStoreError,
     project_creating::dto::ProjectConfigDto,
 };
 
 pub struct GlobalConfig {
-    pub github_personal_token: ValidatedGitHubPersonalToken,
+    pub github_personal_token: Option<ValidatedGitHubPersonalToken>,
     pub heroku_auth_token: Option<ValidatedHerokuAuthToken>,
 }
 
+/// A project as listed by `project list`
+#[derive(Debug, Clone)]
+pub struct ProjectSummary {
+    pub project_name: String,
+    pub deployment_source: String,
+    pub github_owner: String,
+    pub github_repo: String,
+}
+
+/// A project as shown by `project show`, with its plaintext tokens masked and nothing resolved
+#[derive(Debug, Clone)]
+pub struct MaskedProjectConfig {
+    pub developer_count: u32,
+    pub working_days_per_week: f32,
+    pub github_owner: String,
+    pub github_repo: String,
+    /// Every stored setting by its name in the config file
+    pub settings: serde_json::Value,
+}
+
 #[derive(Debug, Error)]
 pub enum ProjectConfigIOReaderError {
     #[error("Cannot read the config file")]
     ConfigFileReadError(#[source] anyhow::Error),
-    #[error("Cannot find the project")]
+    #[error("Cannot find the project: {0}")]
     ProjectNotFound(String),
     #[error("Invalid data source")]
     DataSourceIsInvalid(String),
+    #[error("Cannot resolve the GitHub token")]
+    GitHubTokenCannotResolve(String),
+    #[error("Cannot read a secret")]
+    SecretCannotRead(#[from] SecretStoreError),
 }
 
 #[async_trait]
@@ -31,4 +56,11 @@ pub trait ProjectConfigIOReader {
         project_name: String,
     ) -> Result<ProjectConfigDto, ProjectConfigIOReaderError>;
     async fn read_globals(&self) -> Result<GlobalConfig, ProjectConfigIOReaderError>;
+    /// Every project of the config, in name order
+    async fn list(&self) -> Result<Vec<ProjectSummary>, ProjectConfigIOReaderError>;
+    /// The project as it is stored, with its plaintext tokens masked and nothing resolved
+    async fn read_masked(
+        &self,
+        project_name: String,
+    ) -> Result<MaskedProjectConfig, ProjectConfigIOReaderError>;
 }
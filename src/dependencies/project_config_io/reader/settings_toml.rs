@@ -1,13 +1,104 @@
 use anyhow::anyhow;
 use async_trait::async_trait;
+use std::{env, process::Command};
 
-use super::super::settings_toml::{Config, ProjectName};
-use super::interface::{GlobalConfig, ProjectConfigIOReader, ProjectConfigIOReaderError};
+use super::super::secrets::{mask_secrets, ConfigSecrets};
+use super::super::settings_toml::{Config, ProjectConfig, ProjectName};
+use super::interface::{
+    GlobalConfig, MaskedProjectConfig, ProjectConfigIOReader, ProjectConfigIOReaderError,
+    ProjectSummary,
+};
 use crate::apps::cli::config::CONFY_APP_NAME;
 use crate::common_types::deployment_source::DeploymentSource;
 use crate::common_types::github_personal_token::ValidatedGitHubPersonalToken;
 use crate::common_types::heroku_auth_token::ValidatedHerokuAuthToken;
-use crate::project_creating::dto::ProjectConfigDto;
+use crate::project_creating::dto::{GitHubAppDto, ProjectConfigDto};
+
+/// A project's own GitHub App or token wins over the global GitHub App
+fn resolve_github_app(project_config: &ProjectConfig, config: &Config) -> Option<GitHubAppDto> {
+    let has_own_token = project_config.github_personal_token.is_some()
+        || project_config.github_token_env.is_some()
+        || project_config.github_token_command.is_some();
+    let github_app = match (&project_config.github_app, has_own_token) {
+        (Some(github_app), _) => Some(github_app),
+        (None, true) => None,
+        (None, false) => config.github_app.as_ref(),
+    };
+    github_app.map(|github_app| GitHubAppDto {
+        app_id: github_app.app_id,
+        private_key_path: github_app.private_key_path.clone(),
+        installation_id: github_app.installation_id,
+    })
+}
+
+/// Picks a literal token, then the environment variable named by `token_env`
+/// (read through `get_env`), then the output of `token_command`;
+/// an empty string when none is set
+fn resolve_github_token(
+    token: Option<String>,
+    token_env: Option<String>,
+    token_command: Option<String>,
+    get_env: fn(&str) -> Result<String, env::VarError>,
+) -> Result<String, ProjectConfigIOReaderError> {
+    if let Some(token) = token.filter(|t| !t.is_empty()) {
+        return Ok(token);
+    }
+    if let Some(token_env) = token_env {
+        return get_env(&token_env).map_err(|e| {
+            ProjectConfigIOReaderError::GitHubTokenCannotResolve(format!("{}: {}", token_env, e))
+        });
+    }
+    if let Some(token_command) = token_command {
+        return run_token_command(&token_command);
+    }
+
+    Ok("".to_string())
+}
+
+fn run_token_command(token_command: &str) -> Result<String, ProjectConfigIOReaderError> {
+    let output = if cfg!(windows) {
+        Command::new("cmd").args(["/C", token_command]).output()
+    } else {
+        Command::new("sh").args(["-c", token_command]).output()
+    }
+    .map_err(|e| {
+        ProjectConfigIOReaderError::GitHubTokenCannotResolve(format!("{}: {}", token_command, e))
+    })?;
+    if !output.status.success() {
+        return Err(ProjectConfigIOReaderError::GitHubTokenCannotResolve(
+            format!(
+                "{}: {}",
+                token_command,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// A project's own token source wins over the global one
+fn resolve_project_github_token(
+    project_config: &ProjectConfig,
+    config: &Config,
+) -> Result<String, ProjectConfigIOReaderError> {
+    let token = resolve_github_token(
+        project_config.github_personal_token.clone(),
+        project_config.github_token_env.clone(),
+        project_config.github_token_command.clone(),
+        |name| env::var(name),
+    )?;
+    if !token.is_empty() {
+        return Ok(token);
+    }
+
+    resolve_github_token(
+        Some(config.github_personal_token.clone()),
+        config.github_token_env.clone(),
+        config.github_token_command.clone(),
+        |name| env::var(name),
+    )
+}
 
 pub struct ProjectConfigIOReaderWithSettingsToml;
 #[async_trait]
@@ -20,40 +111,50 @@ impl ProjectConfigIOReader for ProjectConfigIOReaderWithSettingsToml {
             .map_err(|e| anyhow!(e))
             .map_err(ProjectConfigIOReaderError::ConfigFileReadError)
             .and_then(|c| {
-                let project_config = c.projects.get(&project_name).ok_or(
-                    ProjectConfigIOReaderError::ProjectNotFound("Project not found".to_string()),
-                )?;
+                let c = ConfigSecrets::new(&c).reveal(c, Some(&project_name))?;
+                let project_config = c.projects.get(&project_name).ok_or_else(|| {
+                    ProjectConfigIOReaderError::ProjectNotFound(project_name.clone())
+                })?;
+                let github_app = resolve_github_app(project_config, &c);
+                // An App project never sends a token, so do not run a token command for it
+                let github_personal_token = match github_app {
+                    Some(_) => "".to_string(),
+                    None => resolve_project_github_token(project_config, &c)?,
+                };
                 let source = DeploymentSource::try_new(&project_config.clone().deployment_source)
                     .map_err(|e| {
                     ProjectConfigIOReaderError::DataSourceIsInvalid(e.to_string())
                 })?;
-                match source {
+                let dto: Result<ProjectConfigDto, ProjectConfigIOReaderError> = match source {
                     DeploymentSource::GitHubDeployment => Ok(ProjectConfigDto {
                         project_name,
                         developer_count: project_config.clone().developer_count,
                         working_days_per_week: project_config.clone().working_days_per_week,
-                        github_personal_token: project_config
-                            .clone()
-                            .github_personal_token
-                            .unwrap_or(c.github_personal_token.clone()),
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
                         github_owner: project_config.clone().github_owner,
                         github_repo: project_config.clone().github_repo,
                         github_deployment_environment: project_config
                             .github_deployment_environment
+                            .clone()
+                            .map(Vec::from),
+                        github_deployment_environment_merge: project_config
+                            .github_deployment_environment_merge
                             .clone(),
                         github_deployment_branch_name: None,
                         heroku_app_name: None,
                         heroku_auth_token: None,
                         deployment_source: DeploymentSource::GitHubDeployment.value(),
+                        ..Default::default()
                     }),
                     DeploymentSource::GitHubPullRequest => Ok(ProjectConfigDto {
                         project_name,
                         developer_count: project_config.clone().developer_count,
                         working_days_per_week: project_config.clone().working_days_per_week,
-                        github_personal_token: project_config
-                            .clone()
-                            .github_personal_token
-                            .unwrap_or(c.github_personal_token.clone()),
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
                         github_owner: project_config.clone().github_owner,
                         github_repo: project_config.clone().github_repo,
                         github_deployment_environment: None,
@@ -63,15 +164,15 @@ impl ProjectConfigIOReader for ProjectConfigIOReaderWithSettingsToml {
                         heroku_app_name: None,
                         heroku_auth_token: None,
                         deployment_source: DeploymentSource::GitHubPullRequest.value(),
+                        ..Default::default()
                     }),
                     DeploymentSource::HerokuRelease => Ok(ProjectConfigDto {
                         project_name,
                         developer_count: project_config.clone().developer_count,
                         working_days_per_week: project_config.clone().working_days_per_week,
-                        github_personal_token: project_config
-                            .clone()
-                            .github_personal_token
-                            .unwrap_or(c.github_personal_token.clone()),
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
                         github_deployment_environment: None,
                         github_deployment_branch_name: None,
                         heroku_app_name: project_config.clone().heroku_app_name,
@@ -82,8 +183,155 @@ impl ProjectConfigIOReader for ProjectConfigIOReaderWithSettingsToml {
                         github_owner: project_config.clone().github_owner,
                         github_repo: project_config.clone().github_repo,
                         deployment_source: DeploymentSource::HerokuRelease.value(),
+                        ..Default::default()
+                    }),
+                    DeploymentSource::HerokuPipeline => Ok(ProjectConfigDto {
+                        project_name,
+                        developer_count: project_config.clone().developer_count,
+                        working_days_per_week: project_config.clone().working_days_per_week,
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
+                        heroku_pipeline_name: project_config.clone().heroku_pipeline_name,
+                        heroku_auth_token: match project_config.clone().heroku_auth_token {
+                            Some(token) => Some(token),
+                            None => c.heroku_auth_token.clone(),
+                        },
+                        github_owner: project_config.clone().github_owner,
+                        github_repo: project_config.clone().github_repo,
+                        deployment_source: DeploymentSource::HerokuPipeline.value(),
+                        ..Default::default()
+                    }),
+                    DeploymentSource::ArgoCdApplication => Ok(ProjectConfigDto {
+                        project_name,
+                        developer_count: project_config.clone().developer_count,
+                        working_days_per_week: project_config.clone().working_days_per_week,
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
+                        github_owner: project_config.clone().github_owner,
+                        github_repo: project_config.clone().github_repo,
+                        argo_cd_base_url: project_config.clone().argo_cd_base_url,
+                        argo_cd_auth_token: project_config.clone().argo_cd_auth_token,
+                        argo_cd_application_name: project_config.clone().argo_cd_application_name,
+                        deployment_source: DeploymentSource::ArgoCdApplication.value(),
+                        ..Default::default()
+                    }),
+                    DeploymentSource::KubernetesRollout => Ok(ProjectConfigDto {
+                        project_name,
+                        developer_count: project_config.clone().developer_count,
+                        working_days_per_week: project_config.clone().working_days_per_week,
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
+                        github_owner: project_config.clone().github_owner,
+                        github_repo: project_config.clone().github_repo,
+                        kubeconfig_path: project_config.clone().kubeconfig_path,
+                        kubernetes_context: project_config.clone().kubernetes_context,
+                        kubernetes_namespace: project_config.clone().kubernetes_namespace,
+                        kubernetes_deployment_name: project_config
+                            .clone()
+                            .kubernetes_deployment_name,
+                        kubernetes_commit_annotation: project_config
+                            .clone()
+                            .kubernetes_commit_annotation,
+                        deployment_source: DeploymentSource::KubernetesRollout.value(),
+                        ..Default::default()
+                    }),
+                    DeploymentSource::VercelDeployment => Ok(ProjectConfigDto {
+                        project_name,
+                        developer_count: project_config.clone().developer_count,
+                        working_days_per_week: project_config.clone().working_days_per_week,
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
+                        github_owner: project_config.clone().github_owner,
+                        github_repo: project_config.clone().github_repo,
+                        vercel_api_base_url: project_config.clone().vercel_api_base_url,
+                        vercel_auth_token: project_config.clone().vercel_auth_token,
+                        vercel_project_id: project_config.clone().vercel_project_id,
+                        vercel_team_id: project_config.clone().vercel_team_id,
+                        deployment_source: DeploymentSource::VercelDeployment.value(),
+                        ..Default::default()
+                    }),
+                    DeploymentSource::NetlifyDeploy => Ok(ProjectConfigDto {
+                        project_name,
+                        developer_count: project_config.clone().developer_count,
+                        working_days_per_week: project_config.clone().working_days_per_week,
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
+                        github_owner: project_config.clone().github_owner,
+                        github_repo: project_config.clone().github_repo,
+                        netlify_api_base_url: project_config.clone().netlify_api_base_url,
+                        netlify_auth_token: project_config.clone().netlify_auth_token,
+                        netlify_site_id: project_config.clone().netlify_site_id,
+                        deployment_source: DeploymentSource::NetlifyDeploy.value(),
+                        ..Default::default()
+                    }),
+                    DeploymentSource::RenderDeploy => Ok(ProjectConfigDto {
+                        project_name,
+                        developer_count: project_config.clone().developer_count,
+                        working_days_per_week: project_config.clone().working_days_per_week,
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
+                        github_owner: project_config.clone().github_owner,
+                        github_repo: project_config.clone().github_repo,
+                        render_api_base_url: project_config.clone().render_api_base_url,
+                        render_api_key: project_config.clone().render_api_key,
+                        render_service_id: project_config.clone().render_service_id,
+                        deployment_source: DeploymentSource::RenderDeploy.value(),
+                        ..Default::default()
+                    }),
+                    DeploymentSource::AwsCodeDeploy => Ok(ProjectConfigDto {
+                        project_name,
+                        developer_count: project_config.clone().developer_count,
+                        working_days_per_week: project_config.clone().working_days_per_week,
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
+                        github_owner: project_config.clone().github_owner,
+                        github_repo: project_config.clone().github_repo,
+                        aws_endpoint_url: project_config.clone().aws_endpoint_url,
+                        aws_region: project_config.clone().aws_region,
+                        aws_code_deploy_application_name: project_config
+                            .clone()
+                            .aws_code_deploy_application_name,
+                        aws_code_deploy_deployment_group_name: project_config
+                            .clone()
+                            .aws_code_deploy_deployment_group_name,
+                        aws_ecs_commit_tag: project_config.clone().aws_ecs_commit_tag,
+                        deployment_source: DeploymentSource::AwsCodeDeploy.value(),
+                        ..Default::default()
+                    }),
+                    DeploymentSource::JenkinsBuild => Ok(ProjectConfigDto {
+                        project_name,
+                        developer_count: project_config.clone().developer_count,
+                        working_days_per_week: project_config.clone().working_days_per_week,
+                        github_personal_token: github_personal_token.clone(),
+                        github_app: github_app.clone(),
+                        github_api_base_url: project_config.clone().github_api_base_url,
+                        github_owner: project_config.clone().github_owner,
+                        github_repo: project_config.clone().github_repo,
+                        jenkins_base_url: project_config.clone().jenkins_base_url,
+                        jenkins_job_name: project_config.clone().jenkins_job_name,
+                        jenkins_user_name: project_config.clone().jenkins_user_name,
+                        jenkins_api_token: project_config.clone().jenkins_api_token,
+                        deployment_source: DeploymentSource::JenkinsBuild.value(),
+                        ..Default::default()
                     }),
-                }
+                };
+                dto.map(|dto| ProjectConfigDto {
+                    lead_time_start: project_config.lead_time_start.clone(),
+                    lead_time_fallback: project_config.lead_time_fallback.clone(),
+                    lead_time_fallback_cap_days: project_config.lead_time_fallback_cap_days,
+                    lead_time_per_pull_request: project_config.lead_time_per_pull_request,
+                    excluded_authors: project_config.excluded_authors.clone(),
+                    exclude_default_bots: project_config.exclude_default_bots,
+                    paths: project_config.paths.clone(),
+                    ..dto
+                })
             })
     }
 
@@ -92,6 +340,7 @@ impl ProjectConfigIOReader for ProjectConfigIOReaderWithSettingsToml {
             .map_err(|e| anyhow!(e))
             .map_err(ProjectConfigIOReaderError::ConfigFileReadError)
             .and_then(|c| {
+                let c = ConfigSecrets::new(&c).reveal(c, None)?;
                 let heroku_auth_token = match c.heroku_auth_token {
                     Some(token) => {
                         Some(ValidatedHerokuAuthToken::new(Some(token)).map_err(|e| {
@@ -100,13 +349,123 @@ impl ProjectConfigIOReader for ProjectConfigIOReaderWithSettingsToml {
                     }
                     None => None,
                 };
+                // A config that authenticates only as a GitHub App leaves the global token empty
+                let github_personal_token = resolve_github_token(
+                    Some(c.github_personal_token.clone()),
+                    c.github_token_env.clone(),
+                    c.github_token_command.clone(),
+                    |name| env::var(name),
+                )?;
+                let github_personal_token = if github_personal_token.is_empty() {
+                    None
+                } else {
+                    Some(
+                        ValidatedGitHubPersonalToken::new(Some(github_personal_token)).map_err(
+                            |e| ProjectConfigIOReaderError::DataSourceIsInvalid(e.to_string()),
+                        )?,
+                    )
+                };
                 Ok(GlobalConfig {
-                    github_personal_token: ValidatedGitHubPersonalToken::new(Some(
-                        c.github_personal_token,
-                    ))
-                    .map_err(|e| ProjectConfigIOReaderError::DataSourceIsInvalid(e.to_string()))?,
+                    github_personal_token,
                     heroku_auth_token,
                 })
             })
     }
+
+    async fn list(&self) -> Result<Vec<ProjectSummary>, ProjectConfigIOReaderError> {
+        let c = confy::load::<Config>(CONFY_APP_NAME, None)
+            .map_err(|e| anyhow!(e))
+            .map_err(ProjectConfigIOReaderError::ConfigFileReadError)?;
+        let mut projects: Vec<ProjectSummary> = c
+            .projects
+            .into_iter()
+            .map(|(project_name, project_config)| ProjectSummary {
+                project_name,
+                deployment_source: project_config.deployment_source,
+                github_owner: project_config.github_owner,
+                github_repo: project_config.github_repo,
+            })
+            .collect();
+        projects.sort_by(|a, b| a.project_name.cmp(&b.project_name));
+
+        Ok(projects)
+    }
+
+    async fn read_masked(
+        &self,
+        project_name: ProjectName,
+    ) -> Result<MaskedProjectConfig, ProjectConfigIOReaderError> {
+        let mut c = confy::load::<Config>(CONFY_APP_NAME, None)
+            .map_err(|e| anyhow!(e))
+            .map_err(ProjectConfigIOReaderError::ConfigFileReadError)?;
+        let project_config = c
+            .projects
+            .remove(&project_name)
+            .ok_or(ProjectConfigIOReaderError::ProjectNotFound(project_name))?;
+        let project_config = mask_secrets(project_config);
+        let settings = serde_json::to_value(&project_config)
+            .map_err(|e| anyhow!(e))
+            .map_err(ProjectConfigIOReaderError::ConfigFileReadError)?;
+
+        Ok(MaskedProjectConfig {
+            developer_count: project_config.developer_count,
+            working_days_per_week: project_config.working_days_per_week,
+            github_owner: project_config.github_owner,
+            github_repo: project_config.github_repo,
+            settings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::resolve_github_token;
+
+    fn get_env(name: &str) -> Result<String, env::VarError> {
+        match name {
+            "DEVPERF_TEST_GITHUB_TOKEN" => Ok("github_pat_from_environment_variable".to_string()),
+            _ => Err(env::VarError::NotPresent),
+        }
+    }
+
+    #[test]
+    fn resolve_github_token_from_each_source() {
+        let literal = resolve_github_token(
+            Some("ghp_literal".to_string()),
+            Some("DEVPERF_TEST_GITHUB_TOKEN".to_string()),
+            None,
+            get_env,
+        )
+        .unwrap();
+        let from_env = resolve_github_token(
+            Some("".to_string()),
+            Some("DEVPERF_TEST_GITHUB_TOKEN".to_string()),
+            Some("echo gho_from_command".to_string()),
+            get_env,
+        )
+        .unwrap();
+        let from_command = resolve_github_token(
+            None,
+            None,
+            Some("echo gho_from_command".to_string()),
+            get_env,
+        )
+        .unwrap();
+        let missing_env = resolve_github_token(
+            None,
+            Some("DEVPERF_TEST_UNSET_TOKEN".to_string()),
+            None,
+            get_env,
+        );
+        let failing_command = resolve_github_token(None, None, Some("exit 1".to_string()), get_env);
+
+        assert_eq!(literal, "ghp_literal");
+        assert_eq!(from_env, "github_pat_from_environment_variable");
+        assert_eq!(from_command, "gho_from_command");
+        assert!(missing_env.is_err());
+        assert!(failing_command.is_err());
+        assert_eq!(resolve_github_token(None, None, None, get_env).unwrap(), "");
+    }
 }
@@ -4,24 +4,104 @@ use serde::{Deserialize, Serialize};
 
 pub type ProjectName = String;
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GitHubAppConfig {
+    pub app_id: u64,
+    pub private_key_path: String,
+    pub installation_id: u64,
+}
+
+/// One environment name or glob, or a list of them
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GitHubDeploymentEnvironmentConfig {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl From<GitHubDeploymentEnvironmentConfig> for Vec<String> {
+    fn from(config: GitHubDeploymentEnvironmentConfig) -> Self {
+        match config {
+            GitHubDeploymentEnvironmentConfig::One(name) => vec![name],
+            GitHubDeploymentEnvironmentConfig::Many(names) => names,
+        }
+    }
+}
+
+impl From<Vec<String>> for GitHubDeploymentEnvironmentConfig {
+    fn from(mut names: Vec<String>) -> Self {
+        if names.len() == 1 {
+            GitHubDeploymentEnvironmentConfig::One(names.remove(0))
+        } else {
+            GitHubDeploymentEnvironmentConfig::Many(names)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectConfig {
     pub github_personal_token: Option<String>,
+    pub github_token_env: Option<String>,
+    pub github_token_command: Option<String>,
+    pub github_api_base_url: Option<String>,
     pub github_owner: String,
     pub github_repo: String,
-    pub github_deployment_environment: Option<String>,
+    pub github_deployment_environment: Option<GitHubDeploymentEnvironmentConfig>,
+    pub github_deployment_environment_merge: Option<String>,
     pub github_deployment_branch_name: Option<String>,
     pub heroku_app_name: Option<String>,
     pub heroku_auth_token: Option<String>,
+    pub heroku_pipeline_name: Option<String>,
+    pub argo_cd_base_url: Option<String>,
+    pub argo_cd_auth_token: Option<String>,
+    pub argo_cd_application_name: Option<String>,
+    pub kubeconfig_path: Option<String>,
+    pub kubernetes_context: Option<String>,
+    pub kubernetes_namespace: Option<String>,
+    pub kubernetes_deployment_name: Option<String>,
+    pub kubernetes_commit_annotation: Option<String>,
+    pub vercel_api_base_url: Option<String>,
+    pub vercel_auth_token: Option<String>,
+    pub vercel_project_id: Option<String>,
+    pub vercel_team_id: Option<String>,
+    pub netlify_api_base_url: Option<String>,
+    pub netlify_auth_token: Option<String>,
+    pub netlify_site_id: Option<String>,
+    pub render_api_base_url: Option<String>,
+    pub render_api_key: Option<String>,
+    pub render_service_id: Option<String>,
+    pub aws_endpoint_url: Option<String>,
+    pub aws_region: Option<String>,
+    pub aws_code_deploy_application_name: Option<String>,
+    pub aws_code_deploy_deployment_group_name: Option<String>,
+    pub aws_ecs_commit_tag: Option<String>,
+    pub jenkins_base_url: Option<String>,
+    pub jenkins_job_name: Option<String>,
+    pub jenkins_user_name: Option<String>,
+    pub jenkins_api_token: Option<String>,
     pub developer_count: u32,
     pub working_days_per_week: f32,
     pub deployment_source: String,
+    pub lead_time_start: Option<String>,
+    pub lead_time_fallback: Option<String>,
+    pub lead_time_fallback_cap_days: Option<u32>,
+    pub lead_time_per_pull_request: Option<bool>,
+    pub excluded_authors: Option<Vec<String>>,
+    pub exclude_default_bots: Option<bool>,
+    pub paths: Option<Vec<String>>,
+    // Tables go after plain values in TOML
+    pub github_app: Option<GitHubAppConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub github_personal_token: String,
+    pub github_token_env: Option<String>,
+    pub github_token_command: Option<String>,
     pub heroku_auth_token: Option<String>,
+    pub secret_store: Option<String>,
+    pub secret_store_age_file: Option<String>,
+    pub github_app: Option<GitHubAppConfig>,
     pub projects: HashMap<ProjectName, ProjectConfig>,
 }
 
@@ -30,7 +110,12 @@ impl ::std::default::Default for Config {
     fn default() -> Self {
         Self {
             github_personal_token: "".to_string(),
+            github_token_env: None,
+            github_token_command: None,
             heroku_auth_token: None,
+            secret_store: None,
+            secret_store_age_file: None,
+            github_app: None,
             projects: HashMap::new(),
         }
     }
@@ -1,17 +1,53 @@
 use async_trait::async_trait;
 use thiserror::Error;
 
-use crate::project_creating::dto::ProjectConfigDto;
+use crate::{
+    dependencies::secret_store::interface::SecretStoreError,
+    project_creating::dto::ProjectConfigDto,
+};
 
 pub type WriteConfigData = ProjectConfigDto;
 
+/// The values `project edit` changes, none for those kept as they are
+#[derive(Debug, Clone, Default)]
+pub struct ProjectConfigChanges {
+    pub developer_count: Option<u32>,
+    pub working_days_per_week: Option<f32>,
+    pub github_owner: Option<String>,
+    pub github_repo: Option<String>,
+    /// Any other setting, by its key in the config file; null clears it
+    pub settings: Vec<(String, serde_json::Value)>,
+}
+
 #[derive(Error, Debug)]
 pub enum ProjectConfigIOWriterError {
+    #[error("Cannot read the config file")]
+    CannotRead(#[source] anyhow::Error),
     #[error("Cannot write the config file")]
     CannotWritten(#[source] anyhow::Error),
+    #[error("Cannot find the project: {0}")]
+    ProjectNotFound(String),
+    #[error("The project already exists: {0}")]
+    ProjectAlreadyExists(String),
+    #[error("Invalid setting: {0}")]
+    InvalidSetting(String),
+    #[error("Cannot store a secret")]
+    SecretCannotWritten(#[from] SecretStoreError),
 }
 
 #[async_trait]
 pub trait ProjectConfigIOWriter {
     async fn write(&self, data: WriteConfigData) -> Result<(), ProjectConfigIOWriterError>;
+    async fn update(
+        &self,
+        project_name: String,
+        changes: ProjectConfigChanges,
+    ) -> Result<(), ProjectConfigIOWriterError>;
+    async fn remove(&self, project_name: String) -> Result<(), ProjectConfigIOWriterError>;
+    /// Moves the project and its stored secrets to the new name
+    async fn rename(
+        &self,
+        project_name: String,
+        new_project_name: String,
+    ) -> Result<(), ProjectConfigIOWriterError>;
 }
@@ -2,10 +2,83 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use std::collections::HashMap;
 
-use super::super::settings_toml::{Config, ProjectConfig};
-use super::interface::{ProjectConfigIOWriter, ProjectConfigIOWriterError, WriteConfigData};
+use super::super::secrets::ConfigSecrets;
+use super::super::settings_toml::{
+    Config, GitHubAppConfig, GitHubDeploymentEnvironmentConfig, ProjectConfig,
+};
+use super::interface::{
+    ProjectConfigChanges, ProjectConfigIOWriter, ProjectConfigIOWriterError, WriteConfigData,
+};
 use crate::apps::cli::config::CONFY_APP_NAME;
 
+fn load_config() -> Result<Config, ProjectConfigIOWriterError> {
+    confy::load::<Config>(CONFY_APP_NAME, None)
+        .map_err(|e| anyhow!(e))
+        .map_err(ProjectConfigIOWriterError::CannotRead)
+}
+
+fn store_config(config: Config) -> Result<(), ProjectConfigIOWriterError> {
+    confy::store(CONFY_APP_NAME, None, config)
+        .map_err(|e| anyhow!(e))
+        .map_err(ProjectConfigIOWriterError::CannotWritten)
+}
+
+fn apply_changes(
+    project_config: &mut ProjectConfig,
+    changes: ProjectConfigChanges,
+) -> Result<(), ProjectConfigIOWriterError> {
+    if let Some(developer_count) = changes.developer_count {
+        project_config.developer_count = developer_count;
+    }
+    if let Some(working_days_per_week) = changes.working_days_per_week {
+        project_config.working_days_per_week = working_days_per_week;
+    }
+    if let Some(github_owner) = changes.github_owner {
+        project_config.github_owner = github_owner;
+    }
+    if let Some(github_repo) = changes.github_repo {
+        project_config.github_repo = github_repo;
+    }
+    if changes.settings.is_empty() {
+        return Ok(());
+    }
+    // The settings are checked against the fields and their types of the config file
+    let mut value = serde_json::to_value(&*project_config)
+        .map_err(|e| ProjectConfigIOWriterError::InvalidSetting(e.to_string()))?;
+    for (key, setting) in changes.settings {
+        let field = value.get_mut(&key).ok_or_else(|| {
+            ProjectConfigIOWriterError::InvalidSetting(format!("unknown key `{}`", key))
+        })?;
+        *field = setting;
+    }
+    *project_config = serde_json::from_value(value)
+        .map_err(|e| ProjectConfigIOWriterError::InvalidSetting(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Moves a project to the new name without overwriting another project
+fn rename_project(
+    config: &mut Config,
+    project_name: &str,
+    new_project_name: &str,
+) -> Result<(), ProjectConfigIOWriterError> {
+    if config.projects.contains_key(new_project_name) {
+        return Err(ProjectConfigIOWriterError::ProjectAlreadyExists(
+            new_project_name.to_string(),
+        ));
+    }
+    let project_config = config
+        .projects
+        .remove(project_name)
+        .ok_or_else(|| ProjectConfigIOWriterError::ProjectNotFound(project_name.to_string()))?;
+    config
+        .projects
+        .insert(new_project_name.to_string(), project_config);
+
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct ProjectConfigIOWriterWithSettingsToml;
 #[async_trait]
@@ -16,20 +89,41 @@ impl ProjectConfigIOWriter for ProjectConfigIOWriterWithSettingsToml {
             Ok(c) => c,
             Err(_e) => Config {
                 github_personal_token: data.github_personal_token.clone(),
+                github_token_env: None,
+                github_token_command: None,
                 heroku_auth_token: data.heroku_auth_token.clone(),
+                secret_store: None,
+                secret_store_age_file: None,
+                github_app: None,
                 projects: HashMap::new(),
             },
         };
 
+        // Tokens are compared with the global ones, so those must be read out of the store
+        let secrets = ConfigSecrets::new(&config);
+        config = secrets.reveal(config, None)?;
+        let github_app = data.github_app.map(|github_app| GitHubAppConfig {
+            app_id: github_app.app_id,
+            private_key_path: github_app.private_key_path,
+            installation_id: github_app.installation_id,
+        });
         let project_config = ProjectConfig {
-            github_personal_token: if config.github_personal_token == data.github_personal_token {
+            github_personal_token: if config.github_personal_token == data.github_personal_token
+                || data.github_personal_token.is_empty()
+            {
                 None
             } else {
                 Some(data.github_personal_token)
             },
+            github_token_env: None,
+            github_token_command: None,
+            github_api_base_url: data.github_api_base_url,
             github_owner: data.github_owner,
             github_repo: data.github_repo,
-            github_deployment_environment: data.github_deployment_environment,
+            github_deployment_environment: data
+                .github_deployment_environment
+                .map(GitHubDeploymentEnvironmentConfig::from),
+            github_deployment_environment_merge: data.github_deployment_environment_merge,
             github_deployment_branch_name: data.github_deployment_branch_name,
             heroku_app_name: data.heroku_app_name,
             heroku_auth_token: if config.heroku_auth_token == data.heroku_auth_token {
@@ -37,9 +131,49 @@ impl ProjectConfigIOWriter for ProjectConfigIOWriterWithSettingsToml {
             } else {
                 data.heroku_auth_token
             },
+            heroku_pipeline_name: data.heroku_pipeline_name,
+            argo_cd_base_url: data.argo_cd_base_url,
+            argo_cd_auth_token: data.argo_cd_auth_token,
+            argo_cd_application_name: data.argo_cd_application_name,
+            kubeconfig_path: data.kubeconfig_path,
+            kubernetes_context: data.kubernetes_context,
+            kubernetes_namespace: data.kubernetes_namespace,
+            kubernetes_deployment_name: data.kubernetes_deployment_name,
+            kubernetes_commit_annotation: data.kubernetes_commit_annotation,
+            vercel_api_base_url: data.vercel_api_base_url,
+            vercel_auth_token: data.vercel_auth_token,
+            vercel_project_id: data.vercel_project_id,
+            vercel_team_id: data.vercel_team_id,
+            netlify_api_base_url: data.netlify_api_base_url,
+            netlify_auth_token: data.netlify_auth_token,
+            netlify_site_id: data.netlify_site_id,
+            render_api_base_url: data.render_api_base_url,
+            render_api_key: data.render_api_key,
+            render_service_id: data.render_service_id,
+            aws_endpoint_url: data.aws_endpoint_url,
+            aws_region: data.aws_region,
+            aws_code_deploy_application_name: data.aws_code_deploy_application_name,
+            aws_code_deploy_deployment_group_name: data.aws_code_deploy_deployment_group_name,
+            aws_ecs_commit_tag: data.aws_ecs_commit_tag,
+            jenkins_base_url: data.jenkins_base_url,
+            jenkins_job_name: data.jenkins_job_name,
+            jenkins_user_name: data.jenkins_user_name,
+            jenkins_api_token: data.jenkins_api_token,
             developer_count: data.developer_count,
             working_days_per_week: data.working_days_per_week,
             deployment_source: data.deployment_source,
+            lead_time_start: data.lead_time_start,
+            lead_time_fallback: data.lead_time_fallback,
+            lead_time_fallback_cap_days: data.lead_time_fallback_cap_days,
+            lead_time_per_pull_request: data.lead_time_per_pull_request,
+            excluded_authors: data.excluded_authors,
+            exclude_default_bots: data.exclude_default_bots,
+            paths: data.paths,
+            github_app: if config.github_app == github_app {
+                None
+            } else {
+                github_app
+            },
         };
 
         *config
@@ -47,8 +181,162 @@ impl ProjectConfigIOWriter for ProjectConfigIOWriterWithSettingsToml {
             .entry(data.project_name)
             .or_insert(project_config) = project_config.clone();
 
-        confy::store(CONFY_APP_NAME, None, config)
-            .map_err(|e| anyhow!(e))
-            .map_err(ProjectConfigIOWriterError::CannotWritten)
+        let (config, _) = secrets.conceal(config)?;
+        store_config(config)
+    }
+
+    async fn update(
+        &self,
+        project_name: String,
+        changes: ProjectConfigChanges,
+    ) -> Result<(), ProjectConfigIOWriterError> {
+        let config = load_config()?;
+        // A changed token is stored again, so the project is read out of the store first
+        let secrets = ConfigSecrets::new(&config);
+        let stale = config.projects.get(&project_name).cloned();
+        let mut config = secrets.reveal(config, Some(&project_name))?;
+        let project_config = config
+            .projects
+            .get_mut(&project_name)
+            .ok_or_else(|| ProjectConfigIOWriterError::ProjectNotFound(project_name.clone()))?;
+        apply_changes(project_config, changes)?;
+        let updated = project_config.clone();
+
+        let (config, _) = secrets.conceal(config)?;
+        store_config(config)?;
+        if let Some(stale) = stale {
+            secrets.forget_cleared(&project_name, stale, updated)?;
+        }
+
+        Ok(())
+    }
+
+    async fn remove(&self, project_name: String) -> Result<(), ProjectConfigIOWriterError> {
+        let mut config = load_config()?;
+        let secrets = ConfigSecrets::new(&config);
+        let project_config = config
+            .projects
+            .remove(&project_name)
+            .ok_or_else(|| ProjectConfigIOWriterError::ProjectNotFound(project_name.clone()))?;
+        store_config(config)?;
+
+        Ok(secrets.forget(&project_name, project_config)?)
+    }
+
+    async fn rename(
+        &self,
+        project_name: String,
+        new_project_name: String,
+    ) -> Result<(), ProjectConfigIOWriterError> {
+        let config = load_config()?;
+        // Stored secrets are named after the project, so they are read and stored again
+        let secrets = ConfigSecrets::new(&config);
+        let stale = config.projects.get(&project_name).cloned();
+        let mut config = secrets.reveal(config, Some(&project_name))?;
+        rename_project(&mut config, &project_name, &new_project_name)?;
+
+        let (config, _) = secrets.conceal(config)?;
+        store_config(config)?;
+        if let Some(stale) = stale {
+            secrets.forget(&project_name, stale)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_changes, rename_project};
+    use crate::dependencies::project_config_io::{
+        settings_toml::{Config, ProjectConfig},
+        writer::interface::{ProjectConfigChanges, ProjectConfigIOWriterError},
+    };
+
+    fn project_config() -> ProjectConfig {
+        serde_json::from_value(serde_json::json!({
+            "github_owner": "owner",
+            "github_repo": "repo",
+            "developer_count": 3,
+            "working_days_per_week": 5.0,
+            "deployment_source": "github_deployment"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn rename_and_edit_a_project() {
+        let mut config = Config::default();
+        config.projects.insert("api".to_string(), project_config());
+        config.projects.insert("web".to_string(), project_config());
+
+        assert!(matches!(
+            rename_project(&mut config, "api", "web"),
+            Err(ProjectConfigIOWriterError::ProjectAlreadyExists(_))
+        ));
+        assert!(matches!(
+            rename_project(&mut config, "worker", "jobs"),
+            Err(ProjectConfigIOWriterError::ProjectNotFound(_))
+        ));
+        rename_project(&mut config, "api", "backend").unwrap();
+        assert!(!config.projects.contains_key("api"));
+
+        let project_config = config.projects.get_mut("backend").unwrap();
+        apply_changes(
+            project_config,
+            ProjectConfigChanges {
+                developer_count: Some(5),
+                settings: vec![
+                    (
+                        "deployment_source".to_string(),
+                        serde_json::json!("render_deploy"),
+                    ),
+                    ("render_api_key".to_string(), serde_json::json!("key")),
+                    ("paths".to_string(), serde_json::json!(["services/api/**"])),
+                    (
+                        "lead_time_per_pull_request".to_string(),
+                        serde_json::json!(true),
+                    ),
+                ],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(project_config.developer_count, 5);
+        assert_eq!(project_config.working_days_per_week, 5.0);
+        assert_eq!(project_config.github_repo, "repo");
+        assert_eq!(project_config.deployment_source, "render_deploy");
+        assert_eq!(project_config.render_api_key, Some("key".to_string()));
+        assert_eq!(
+            project_config.paths,
+            Some(vec!["services/api/**".to_string()])
+        );
+        assert_eq!(project_config.lead_time_per_pull_request, Some(true));
+
+        apply_changes(
+            project_config,
+            ProjectConfigChanges {
+                settings: vec![("render_api_key".to_string(), serde_json::Value::Null)],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(project_config.render_api_key, None);
+        for setting in [
+            ("no_such_setting", serde_json::json!("value")),
+            ("developer_count", serde_json::json!("five")),
+        ] {
+            assert!(matches!(
+                apply_changes(
+                    project_config,
+                    ProjectConfigChanges {
+                        settings: vec![(setting.0.to_string(), setting.1)],
+                        ..Default::default()
+                    },
+                ),
+                Err(ProjectConfigIOWriterError::InvalidSetting(_))
+            ));
+        }
+        assert_eq!(project_config.developer_count, 5);
     }
 }
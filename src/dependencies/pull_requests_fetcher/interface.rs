@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::common_types::date_time_range::DateTimeRange;
+
+#[derive(Debug, Error)]
+pub enum PullRequestsFetcherError {
+    #[error("Api client error")]
+    CannotBuildAPIClient(#[source] anyhow::Error),
+    #[error("Cannot fetch pull requests")]
+    FetchError(#[source] anyhow::Error),
+}
+
+#[derive(Debug, Clone)]
+pub struct PullRequestsFetcherParams {
+    pub commit_shas: Vec<String>,
+    /// Also look up when the head branch of each pull request was pushed first
+    pub with_first_push: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequest {
+    pub number: u64,
+    pub title: String,
+    pub head_ref_name: String,
+    pub created_at: DateTime<Utc>,
+    pub merged_at: Option<DateTime<Utc>>,
+    pub merge_commit_sha: Option<String>,
+    pub first_commit_authored_at: Option<DateTime<Utc>>,
+    pub first_reviewed_at: Option<DateTime<Utc>>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub first_review_requested_at: Option<DateTime<Utc>>,
+    pub first_pushed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PullActivityParams {
+    pub timeframe: DateTimeRange,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PullActivity {
+    pub number: u64,
+    pub title: String,
+    pub author_login: String,
+    pub created_at: DateTime<Utc>,
+    pub merged_at: Option<DateTime<Utc>>,
+    pub first_reviewed_at: Option<DateTime<Utc>>,
+    /// Submitted reviews that requested changes
+    pub changes_requested: u32,
+    pub additions: u64,
+    pub deletions: u64,
+    pub changed_files: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FetchedPulls {
+    /// Pull requests merged in the timeframe
+    pub merged: Vec<PullActivity>,
+    /// Pull requests open now
+    pub open: Vec<PullActivity>,
+}
+
+#[async_trait]
+pub trait PullRequestsFetcher {
+    /// Fetches the merged pull requests that contain any of the commits, with their review timeline
+    async fn fetch(
+        &self,
+        params: PullRequestsFetcherParams,
+    ) -> Result<Vec<PullRequest>, PullRequestsFetcherError>;
+
+    /// Fetches the pull requests merged in the timeframe and the ones open now, with their review activity and size
+    async fn fetch_activity(
+        &self,
+        params: PullActivityParams,
+    ) -> Result<FetchedPulls, PullRequestsFetcherError>;
+}
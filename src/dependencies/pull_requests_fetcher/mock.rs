@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+
+use super::interface::{
+    FetchedPulls, PullActivity, PullActivityParams, PullRequest, PullRequestsFetcher,
+    PullRequestsFetcherError, PullRequestsFetcherParams,
+};
+
+#[derive(Default)]
+pub struct PullRequestsFetcherWithMock {
+    pub pull_requests: Vec<PullRequest>,
+    pub merged: Vec<PullActivity>,
+    pub open: Vec<PullActivity>,
+}
+#[async_trait]
+impl PullRequestsFetcher for PullRequestsFetcherWithMock {
+    async fn fetch(
+        &self,
+        _params: PullRequestsFetcherParams,
+    ) -> Result<Vec<PullRequest>, PullRequestsFetcherError> {
+        Ok(self.pull_requests.clone())
+    }
+
+    async fn fetch_activity(
+        &self,
+        _params: PullActivityParams,
+    ) -> Result<FetchedPulls, PullRequestsFetcherError> {
+        Ok(FetchedPulls {
+            merged: self.merged.clone(),
+            open: self.open.clone(),
+        })
+    }
+}
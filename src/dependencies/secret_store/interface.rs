@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+// A config value of `secret:<name>` is kept in the secret store under `<name>`
+const SECRET_REFERENCE_PREFIX: &str = "secret:";
+
+pub fn to_secret_reference(name: &str) -> String {
+    format!("{}{}", SECRET_REFERENCE_PREFIX, name)
+}
+
+pub fn parse_secret_reference(value: &str) -> Option<&str> {
+    value.strip_prefix(SECRET_REFERENCE_PREFIX)
+}
+
+#[derive(Debug, Error)]
+pub enum SecretStoreError {
+    #[error("Unknown secret store: {0}")]
+    UnknownStore(String),
+    #[error("Secret not found: {0}")]
+    NotFound(String),
+    #[error("Cannot access the OS keyring")]
+    KeyringError(#[source] anyhow::Error),
+    #[error("Cannot read the encrypted secret file")]
+    CannotRead(#[source] anyhow::Error),
+    #[error("Cannot write the encrypted secret file")]
+    CannotWrite(#[source] anyhow::Error),
+}
+
+pub trait SecretStore {
+    fn get(&self, name: &str) -> Result<String, SecretStoreError>;
+    fn set_all(&self, secrets: &[(String, String)]) -> Result<(), SecretStoreError>;
+    /// Deletes the named secrets; a name that is not stored is skipped
+    fn delete_all(&self, names: &[String]) -> Result<(), SecretStoreError>;
+}
@@ -0,0 +1,44 @@
+use keyring::Entry;
+
+use super::interface::{SecretStore, SecretStoreError};
+use crate::apps::cli::config::CONFY_APP_NAME;
+
+/// Keeps each secret as an entry of the OS keyring (Secret Service on Linux)
+pub struct SecretStoreWithKeyring;
+
+fn entry(name: &str) -> Result<Entry, SecretStoreError> {
+    Entry::new(CONFY_APP_NAME, name)
+        .map_err(|e| anyhow::anyhow!(e))
+        .map_err(SecretStoreError::KeyringError)
+}
+
+impl SecretStore for SecretStoreWithKeyring {
+    fn get(&self, name: &str) -> Result<String, SecretStoreError> {
+        entry(name)?.get_password().map_err(|e| match e {
+            keyring::Error::NoEntry => SecretStoreError::NotFound(name.to_string()),
+            e => SecretStoreError::KeyringError(anyhow::anyhow!(e)),
+        })
+    }
+
+    fn set_all(&self, secrets: &[(String, String)]) -> Result<(), SecretStoreError> {
+        for (name, value) in secrets {
+            entry(name)?
+                .set_password(value)
+                .map_err(|e| anyhow::anyhow!(e))
+                .map_err(SecretStoreError::KeyringError)?;
+        }
+
+        Ok(())
+    }
+
+    fn delete_all(&self, names: &[String]) -> Result<(), SecretStoreError> {
+        for name in names {
+            match entry(name)?.delete_password() {
+                Ok(()) | Err(keyring::Error::NoEntry) => {}
+                Err(e) => return Err(SecretStoreError::KeyringError(anyhow::anyhow!(e))),
+            }
+        }
+
+        Ok(())
+    }
+}
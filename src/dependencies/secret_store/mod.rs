@@ -0,0 +1,3 @@
+pub mod age_file;
+pub mod interface;
+pub mod keyring;
@@ -1,4 +1,8 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::common_types::commit::Commit;
@@ -44,6 +48,8 @@ impl ValidatedCommitShaPair {
 pub enum TwoCommitsComparerError {
     #[error("Api client error")]
     CannotBuildAPIClient(#[source] anyhow::Error),
+    #[error("Cannot fetch from API")]
+    CannotFetch(#[source] anyhow::Error),
     #[error("API response is not normal")]
     InvalidAPIResponse(String),
     #[error("Cannot parse response json")]
@@ -52,10 +58,32 @@ pub enum TwoCommitsComparerError {
     CannotGotFromJson(String),
 }
 
+/// The lines changed between the two commits
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiffSize {
+    pub additions: u64,
+    pub deletions: u64,
+    pub changed_files: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ComparedCommits {
+    pub commits: Vec<Commit>,
+    /// The range holds more commits than could be retrieved, or not every commit's files were listed
+    pub truncated: bool,
+    /// The committer date of each commit by its sha, which differs from the author date after a rebase
+    pub committer_dates: HashMap<String, DateTime<Utc>>,
+    /// Empty when the changed files could not all be listed
+    pub diff_size: Option<DiffSize>,
+    /// The files each commit changed by its sha, when they were asked for
+    pub commit_files: HashMap<String, Vec<String>>,
+}
+
 #[async_trait]
 pub trait TwoCommitsComparer {
     async fn compare(
         &self,
         commit_sha_pair: ValidatedCommitShaPair,
-    ) -> Result<Vec<Commit>, TwoCommitsComparerError>;
+        with_commit_files: bool,
+    ) -> Result<ComparedCommits, TwoCommitsComparerError>;
 }
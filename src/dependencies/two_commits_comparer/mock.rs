@@ -1,18 +1,46 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 
 use crate::common_types::commit::Commit;
 
-use super::interface::{TwoCommitsComparer, TwoCommitsComparerError, ValidatedCommitShaPair};
+use super::interface::{
+    ComparedCommits, TwoCommitsComparer, TwoCommitsComparerError, ValidatedCommitShaPair,
+};
 
 pub struct TwoCommitsComparerWithMock {
     pub commits: Vec<Commit>,
 }
+impl TwoCommitsComparerWithMock {
+    /// Every commit changes a file named after its sha
+    fn commit_files(&self) -> HashMap<String, Vec<String>> {
+        self.commits
+            .iter()
+            .map(|commit| (commit.sha.clone(), vec![commit.sha.clone()]))
+            .collect()
+    }
+}
 #[async_trait]
 impl TwoCommitsComparer for TwoCommitsComparerWithMock {
     async fn compare(
         &self,
         _commit_sha_pair: ValidatedCommitShaPair,
-    ) -> Result<Vec<Commit>, TwoCommitsComparerError> {
-        Ok(self.commits.clone())
+        with_commit_files: bool,
+    ) -> Result<ComparedCommits, TwoCommitsComparerError> {
+        Ok(ComparedCommits {
+            commits: self.commits.clone(),
+            truncated: false,
+            committer_dates: self
+                .commits
+                .iter()
+                .map(|c| (c.sha.clone(), c.committed_at))
+                .collect(),
+            diff_size: None,
+            commit_files: if with_commit_files {
+                self.commit_files()
+            } else {
+                HashMap::new()
+            },
+        })
     }
 }
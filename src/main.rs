@@ -1,6 +1,6 @@
 #![allow(non_upper_case_globals)]
 
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use clap::Parser;
 
 mod apps;
@@ -10,15 +10,17 @@ mod metrics_retrieving;
 #[cfg(test)]
 mod profiling;
 mod project_creating;
+mod pulls_retrieving;
 mod shared;
 #[cfg(test)]
 mod tests;
 
-use apps::cli::config::{get_config_path, ConfigAction};
+use apps::cli::config::{get_config_path, migrate_secrets, ConfigAction};
 use apps::cli::four_keys::get_four_keys;
+use apps::cli::pulls::get_pulls;
 use apps::cli::sub_commands::Action;
 use apps::cli::{initializer, project};
-use shared::{datetime_utc, setup_logger};
+use shared::{datetime_utc, redaction, setup_logger};
 
 #[derive(Parser)]
 struct Args {
@@ -30,10 +32,36 @@ struct Args {
 }
 
 #[tokio::main]
-async fn main() -> anyhow::Result<()> {
+async fn main() {
     let args = Args::parse();
     setup_logger::init(args.verbose);
 
+    // Errors can carry request URLs and headers, so they go through the same redaction as logs
+    if let Err(e) = run(args).await {
+        eprintln!("Error: {}", redaction::redact(&format!("{:?}", e)));
+        std::process::exit(1);
+    }
+}
+
+/// The timeframe of a metrics command, the last 90 days by default
+fn parse_timeframe(
+    since: Option<String>,
+    until: Option<String>,
+) -> anyhow::Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let datetime_since = if let Some(since) = since {
+        datetime_utc::parse(&since)
+    } else {
+        Ok(Utc::now() - Duration::days(90))
+    }?;
+    let datetime_until = if let Some(until) = until {
+        datetime_utc::parse(&until)
+    } else {
+        Ok(Utc::now())
+    }?;
+    Ok((datetime_since, datetime_until))
+}
+
+async fn run(args: Args) -> anyhow::Result<()> {
     match args.action {
         Action::Init {} => {
             initializer::init::perform().await?;
@@ -44,28 +72,61 @@ async fn main() -> anyhow::Result<()> {
 
                 println!("{:?}", config_path);
             }
+            ConfigAction::MigrateSecrets { store, age_file } => {
+                migrate_secrets(store, age_file)?;
+            }
         },
         Action::FourKeys {
             project,
             since,
             until,
+            strict,
+        } => {
+            let (datetime_since, datetime_until) = parse_timeframe(since, until)?;
+            get_four_keys(&project, datetime_since, datetime_until, strict).await?;
+        }
+        Action::Pulls {
+            project,
+            since,
+            until,
         } => {
-            let datetime_since = if let Some(since) = since {
-                datetime_utc::parse(&since)
-            } else {
-                Ok(Utc::now() - Duration::days(90))
-            }?;
-            let datetime_until = if let Some(until) = until {
-                datetime_utc::parse(&until)
-            } else {
-                Ok(Utc::now())
-            }?;
-            get_four_keys(&project, datetime_since, datetime_until).await?;
+            let (datetime_since, datetime_until) = parse_timeframe(since, until)?;
+            get_pulls(&project, datetime_since, datetime_until).await?;
         }
         Action::Project { sub_action } => match sub_action {
             project::ProjectAction::Add {} => {
                 project::add().await?;
             }
+            project::ProjectAction::List {} => {
+                project::list().await?;
+            }
+            project::ProjectAction::Show { name } => {
+                project::show(name).await?;
+            }
+            project::ProjectAction::Edit {
+                name,
+                developer_count,
+                working_days_per_week,
+                owner_repo,
+                settings,
+                unset,
+            } => {
+                project::edit(
+                    name,
+                    developer_count,
+                    working_days_per_week,
+                    owner_repo,
+                    settings,
+                    unset,
+                )
+                .await?;
+            }
+            project::ProjectAction::Remove { name, yes } => {
+                project::remove(name, yes).await?;
+            }
+            project::ProjectAction::Rename { name, new_name } => {
+                project::rename(name, new_name).await?;
+            }
         },
     }
     Ok(())
@@ -2,7 +2,11 @@ use thiserror::Error;
 
 use crate::{
     common_types::{
+        author_exclusion::{AuthorExclusion, ValidateAuthorExclusionError},
         developer_count::{ValidateDeveloperCountError, ValidatedDeveloperCount},
+        lead_time_fallback::{LeadTimeFallback, ValidateLeadTimeFallbackError},
+        lead_time_start::{LeadTimeStart, ValidateLeadTimeStartError},
+        path_filter::{PathFilter, ValidatePathFilterError},
         working_days_per_week::{ValidateWorkingDaysPerWeekError, ValidatedWorkingDaysPerWeek},
     },
     project_creating::dto::ProjectConfigDto,
@@ -16,6 +20,14 @@ pub enum RetrieveFourKeysExecutionContextDtoError {
     InvalidDeveloperCount(#[from] ValidateDeveloperCountError),
     #[error("Developer count is invalid")]
     InvalidWorkingDaysPerWeek(#[from] ValidateWorkingDaysPerWeekError),
+    #[error("Lead time start is invalid")]
+    LeadTimeStart(#[from] ValidateLeadTimeStartError),
+    #[error("Lead time fallback is invalid")]
+    LeadTimeFallback(#[from] ValidateLeadTimeFallbackError),
+    #[error("Excluded authors are invalid")]
+    AuthorExclusion(#[from] ValidateAuthorExclusionError),
+    #[error("Paths are invalid")]
+    PathFilter(#[from] ValidatePathFilterError),
 }
 
 pub struct RetrieveFourKeysExecutionContextDto;
@@ -27,10 +39,21 @@ impl RetrieveFourKeysExecutionContextDto {
         let developer_count = ValidatedDeveloperCount::new(dto.developer_count.to_string())?;
         let working_days_per_week =
             ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
+        let lead_time_start = LeadTimeStart::new(dto.lead_time_start)?;
+        let lead_time_fallback =
+            LeadTimeFallback::new(dto.lead_time_fallback, dto.lead_time_fallback_cap_days)?;
+        let author_exclusion =
+            AuthorExclusion::new(dto.excluded_authors, dto.exclude_default_bots)?;
+        let path_filter = PathFilter::new(dto.paths)?;
         Ok(RetrieveFourKeysExecutionContextProject {
             name: dto.project_name,
             developer_count: developer_count.to_u32(),
             working_days_per_week: working_days_per_week.to_f32(),
+            lead_time_start,
+            lead_time_fallback,
+            lead_time_per_pull_request: dto.lead_time_per_pull_request.unwrap_or(false),
+            author_exclusion,
+            path_filter,
         })
     }
 }
@@ -1,5 +1,7 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
 use futures::future::join_all;
+use itertools::Itertools;
 
 use super::{
     retrieve_four_keys::{
@@ -7,27 +9,40 @@ use super::{
         DeploymentFrequencyPerformanceSurvey2022,
     },
     retrieve_four_keys_internal_types::{
-        CalculateDeploymentFrequency, CalculateDeploymentFrequencyPerDay, CalculateLeadTime,
-        CalculateLeadTimeMedian, CreateEvents, DeploymentLogWithFirstOperation,
+        CalculateBatchSize, CalculateBatchSizeSummary, CalculateDeploymentFrequency,
+        CalculateDeploymentFrequencyPerDay, CalculateEnvironmentSummaries, CalculateLeadTime,
+        CalculateLeadTimeMedian, CalculateLeadTimeStages, CalculateLeadTimeStagesMedian,
+        CalculatePullRequestLeadTimes, CreateEvents, DeploymentLogWithFirstOperation,
         GetDeploymentPerformance2022, GetDeploymentPerformanceLabel, PickFirstCommit,
-        RetrieveFourKeysStep,
+        PickFirstCommittedAt, PickMergedPullRequests, PickPullRequestStart, RetrieveFourKeysStep,
+        ScopeComparedCommits,
     },
     retrieve_four_keys_public_types::{
-        DailyDeploymentsSummary, Deployment, DeploymentLeadTimeForChanges, DeploymentPerformance,
-        FourKeysResult, RepositoryInfo, RetrieveFourKeys, RetrieveFourKeysEvent,
-        RetrieveFourKeysEventError, RetrieveFourKeysExecutionContext,
+        BatchSizeMedian, BatchSizeSummary, DailyDeploymentsSummary, Deployment,
+        DeploymentBatchSize, DeploymentLeadTimeForChanges, DeploymentLeadTimeStages,
+        DeploymentPerformance, EnvironmentSummary, ExcludedCounts, FourKeysResult,
+        LeadTimeStagesMedian, PullRequestLeadTime, RepositoryInfo, RetrieveFourKeys,
+        RetrieveFourKeysEvent, RetrieveFourKeysEventError, RetrieveFourKeysExecutionContext,
+        WeeklyBatchSize,
     },
 };
 use crate::{
     common_types::{
-        commit::Commit, daily_items::DailyItems, monthly_items::MonthlyItems,
-        weekly_items::WeeklyItems,
+        author_exclusion::AuthorExclusion, commit::Commit, daily_items::DailyItems,
+        lead_time_fallback::LeadTimeFallback, lead_time_start::LeadTimeStart,
+        monthly_items::MonthlyItems, path_filter::PathFilter, weekly_items::WeeklyItems,
     },
     dependencies::{
         deployments_fetcher::interface::{
-            BaseCommitShaOrRepositoryInfo, DeploymentsFetcher, DeploymentsFetcherParams,
+            BaseCommitShaOrRepositoryInfo, DataQualityWarning, DeploymentInfo, DeploymentsFetcher,
+            DeploymentsFetcherParams,
+        },
+        pull_requests_fetcher::interface::{
+            PullRequest, PullRequestsFetcher, PullRequestsFetcherParams,
+        },
+        two_commits_comparer::interface::{
+            ComparedCommits, TwoCommitsComparer, ValidatedCommitShaPair,
         },
-        two_commits_comparer::interface::{TwoCommitsComparer, ValidatedCommitShaPair},
     },
     metrics_retrieving::retrieve_four_keys_public_types::FirstCommitOrRepositoryInfo,
     shared::median::median,
@@ -36,35 +51,201 @@ use crate::{
 // ---------------------------
 // PickFirstCommit
 // ---------------------------
-const pick_first_commit: PickFirstCommit = |commits: &Vec<Commit>| -> Option<Commit> {
-    let mut sorted_commits = commits.clone();
-    sorted_commits.sort_by_key(|it| it.committed_at);
-    sorted_commits.first().cloned()
-};
+const pick_first_commit: PickFirstCommit =
+    |commits: &Vec<Commit>, author_exclusion: &AuthorExclusion| -> Option<Commit> {
+        // A bot's commit, such as a dependency update, does not start the lead time
+        commits
+            .iter()
+            .filter(|it| !author_exclusion.is_excluded(&it.creator_login))
+            .min_by_key(|it| it.committed_at)
+            .cloned()
+    };
+
+// ---------------------------
+// PickFirstCommittedAt
+// ---------------------------
+pub(super) const pick_first_committed_at: PickFirstCommittedAt =
+    |compared: &ComparedCommits, author_exclusion: &AuthorExclusion| -> Option<DateTime<Utc>> {
+        compared
+            .commits
+            .iter()
+            .filter(|it| !author_exclusion.is_excluded(&it.creator_login))
+            .flat_map(|it| compared.committer_dates.get(&it.sha))
+            .min()
+            .cloned()
+    };
+
+// ---------------------------
+// PickPullRequestStart
+// ---------------------------
+// A pull request without a review request, a known first push or a known first commit starts
+// when it was opened
+fn pull_request_started_at(pull: &PullRequest, lead_time_start: LeadTimeStart) -> DateTime<Utc> {
+    match lead_time_start {
+        LeadTimeStart::PullRequestCreated => pull.created_at,
+        LeadTimeStart::PullRequestReviewRequested => {
+            pull.first_review_requested_at.unwrap_or(pull.created_at)
+        }
+        LeadTimeStart::BranchFirstPushed => pull.first_pushed_at.unwrap_or(pull.created_at),
+        LeadTimeStart::FirstCommitAuthored | LeadTimeStart::FirstCommitCommitted => {
+            pull.first_commit_authored_at.unwrap_or(pull.created_at)
+        }
+    }
+}
+
+const pick_pull_request_start: PickPullRequestStart =
+    |pulls: &[PullRequest], lead_time_start: LeadTimeStart| -> Option<DateTime<Utc>> {
+        pulls
+            .iter()
+            .map(|pull| pull_request_started_at(pull, lead_time_start))
+            .min()
+    };
+
+// ---------------------------
+// PickMergedPullRequests
+// ---------------------------
+const pick_merged_pull_requests: PickMergedPullRequests =
+    |pulls: &[PullRequest], commits: &[Commit]| -> Vec<PullRequest> {
+        // Only the pull requests merged by this deployment, not earlier ones sharing a commit
+        pulls
+            .iter()
+            .filter(|pull| {
+                pull.merge_commit_sha
+                    .as_ref()
+                    .is_some_and(|sha| commits.iter().any(|commit| &commit.sha == sha))
+            })
+            .cloned()
+            .collect()
+    };
+
+// ---------------------------
+// CalculatePullRequestLeadTimes
+// ---------------------------
+const calculate_pull_request_lead_times: CalculatePullRequestLeadTimes =
+    |pulls: &[PullRequest],
+     commits: &[Commit],
+     deployed_at: DateTime<Utc>,
+     lead_time_start: LeadTimeStart|
+     -> Vec<PullRequestLeadTime> {
+        pick_merged_pull_requests(pulls, commits)
+            .iter()
+            .map(|pull| {
+                let started_at = pull_request_started_at(pull, lead_time_start);
+                PullRequestLeadTime {
+                    number: pull.number,
+                    title: pull.title.clone(),
+                    started_at,
+                    lead_time_for_changes_seconds: (deployed_at - started_at).num_seconds(),
+                }
+            })
+            .collect()
+    };
+
+// ---------------------------
+// ScopeComparedCommits
+// ---------------------------
+pub(super) const scope_compared_commits: ScopeComparedCommits =
+    |compared: ComparedCommits, path_filter: &PathFilter| -> Option<ComparedCommits> {
+        if !path_filter.is_scoped() {
+            return Some(compared);
+        }
+        let touches_paths = |commit: &Commit| {
+            compared
+                .commit_files
+                .get(&commit.sha)
+                .is_some_and(|files| files.iter().any(|file| path_filter.is_match(file)))
+        };
+        let commits = compared
+            .commits
+            .iter()
+            .filter(|commit| touches_paths(commit))
+            .cloned()
+            .collect::<Vec<_>>();
+        if commits.is_empty() {
+            return None;
+        }
+        // The diff of the whole range would count changes outside the paths
+        Some(ComparedCommits {
+            commits,
+            diff_size: None,
+            ..compared
+        })
+    };
+
+// ---------------------------
+// CalculateBatchSize
+// ---------------------------
+const calculate_batch_size: CalculateBatchSize =
+    |compared: &ComparedCommits| -> DeploymentBatchSize {
+        // Commits without a GitHub account behind them have no login to tell their authors apart
+        let author_count = compared
+            .commits
+            .iter()
+            .map(|commit| commit.creator_login.as_str())
+            .filter(|login| !login.is_empty())
+            .unique()
+            .count();
+        DeploymentBatchSize {
+            commit_count: compared.commits.len() as u32,
+            author_count: author_count as u32,
+            diff_size: compared.diff_size.clone(),
+        }
+    };
 
 // ---------------------------
 // CalculateEachLogLeadTimes
 // ---------------------------
+fn lead_time_started_at(
+    log_with_operation: &DeploymentLogWithFirstOperation,
+) -> Option<DateTime<Utc>> {
+    let operation = log_with_operation.first_operation.as_ref()?;
+    let first_committed_at = match operation {
+        FirstCommitOrRepositoryInfo::FirstCommit(commit) => commit.committed_at,
+        FirstCommitOrRepositoryInfo::RepositoryInfo(info) => info.created_at,
+    };
+    Some(
+        log_with_operation
+            .lead_time_started_at
+            .unwrap_or(first_committed_at),
+    )
+}
+
 pub(super) fn calculate_lead_time_for_changes_seconds(
     log_with_operation: DeploymentLogWithFirstOperation,
 ) -> Option<i64> {
-    if let Some(operation) = log_with_operation.first_operation {
-        let first_committed_at = match operation {
-            FirstCommitOrRepositoryInfo::FirstCommit(commit) => commit.committed_at,
-            FirstCommitOrRepositoryInfo::RepositoryInfo(info) => info.created_at,
-        };
+    lead_time_started_at(&log_with_operation).map(|started_at| {
         let deployed_at = log_with_operation.deployment_log.deployed_at;
-        let lead_time_for_changes_seconds = (deployed_at - first_committed_at).num_seconds();
-        Some(lead_time_for_changes_seconds)
-    } else {
-        None
-    }
+        (deployed_at - started_at).num_seconds()
+    })
 }
 
+const calculate_lead_time_stages: CalculateLeadTimeStages =
+    |pulls: &[PullRequest], deployed_at: DateTime<Utc>| -> Option<DeploymentLeadTimeStages> {
+        // The oldest pull request is the one that bounds the lead time
+        let pull = pulls.iter().min_by_key(|pull| pull.created_at)?;
+        let seconds = |from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>| {
+            from.zip(to)
+                .map(|(from, to)| (to - from).num_seconds().max(0))
+        };
+        Some(DeploymentLeadTimeStages {
+            pull_request_number: pull.number,
+            coding_seconds: seconds(pull.first_commit_authored_at, Some(pull.created_at)),
+            pickup_seconds: seconds(Some(pull.created_at), pull.first_reviewed_at),
+            review_seconds: seconds(pull.first_reviewed_at, pull.approved_at),
+            merge_seconds: seconds(pull.approved_at, pull.merged_at),
+            deploy_seconds: seconds(pull.merged_at, Some(deployed_at)),
+        })
+    };
+
 const calculate_lead_time: CalculateLeadTime =
     |log_with_operation: DeploymentLogWithFirstOperation| -> Deployment {
         let lead_time_for_changes_seconds =
             calculate_lead_time_for_changes_seconds(log_with_operation.clone());
+        let lead_time_started_at = lead_time_started_at(&log_with_operation);
+        let lead_time_stages = calculate_lead_time_stages(
+            &log_with_operation.pull_requests,
+            log_with_operation.deployment_log.deployed_at,
+        );
 
         let head_commit = Commit {
             sha: log_with_operation.deployment_log.head_commit.sha,
@@ -85,6 +266,11 @@ const calculate_lead_time: CalculateLeadTime =
             first_commit,
             deployed_at: log_with_operation.deployment_log.deployed_at,
             lead_time_for_changes_seconds,
+            lead_time_started_at,
+            lead_time_stages,
+            pull_request_lead_times: log_with_operation.pull_request_lead_times,
+            commits_truncated: log_with_operation.commits_truncated,
+            batch_size: log_with_operation.batch_size,
         }
     };
 
@@ -174,93 +360,399 @@ const get_deployment_performance_label: GetDeploymentPerformanceLabel =
         }
     };
 
+fn median_lead_time(durations: Vec<i64>) -> DeploymentLeadTimeForChanges {
+    let median_duration = median(durations);
+    let days = (median_duration / 86400.0) as i64;
+    let hours = (median_duration / 3600.0) as i64;
+    let minutes = (median_duration.round() as i64 % 3600) / 60;
+    let seconds = (median_duration.round() as i64) - (hours * 3600) - (minutes * 60);
+    DeploymentLeadTimeForChanges {
+        days,
+        hours,
+        minutes,
+        seconds,
+        total_seconds: median_duration,
+    }
+}
+
 const calculate_lead_time_median: CalculateLeadTimeMedian =
     |items: &Vec<Deployment>| -> DeploymentLeadTimeForChanges {
+        // A deployment counts once per pull request when they were expanded
         let durations = items
             .iter()
-            .flat_map(|item| item.lead_time_for_changes_seconds)
+            .flat_map(|item| {
+                if item.pull_request_lead_times.is_empty() {
+                    item.lead_time_for_changes_seconds.into_iter().collect()
+                } else {
+                    item.pull_request_lead_times
+                        .iter()
+                        .map(|pull| pull.lead_time_for_changes_seconds)
+                        .collect::<Vec<_>>()
+                }
+            })
             .collect::<Vec<i64>>();
         log::debug!("durations: {:?}", durations);
-        let median_duration = median(durations);
-        let days = (median_duration / 86400.0) as i64;
-        let hours = (median_duration / 3600.0) as i64;
-        let minutes = (median_duration.round() as i64 % 3600) / 60;
-        let seconds = (median_duration.round() as i64) - (hours * 3600) - (minutes * 60);
-        DeploymentLeadTimeForChanges {
-            days,
-            hours,
-            minutes,
-            seconds,
-            total_seconds: median_duration,
+        median_lead_time(durations)
+    };
+
+const calculate_lead_time_stages_median: CalculateLeadTimeStagesMedian =
+    |items: &Vec<Deployment>| -> LeadTimeStagesMedian {
+        let stage_median = |stage: fn(&DeploymentLeadTimeStages) -> Option<i64>| {
+            median_lead_time(
+                items
+                    .iter()
+                    .flat_map(|item| item.lead_time_stages.as_ref().and_then(stage))
+                    .collect(),
+            )
+        };
+        LeadTimeStagesMedian {
+            coding: stage_median(|stages| stages.coding_seconds),
+            pickup: stage_median(|stages| stages.pickup_seconds),
+            review: stage_median(|stages| stages.review_seconds),
+            merge: stage_median(|stages| stages.merge_seconds),
+            deploy: stage_median(|stages| stages.deploy_seconds),
+        }
+    };
+
+fn batch_size_median(batch_sizes: Vec<&DeploymentBatchSize>) -> BatchSizeMedian {
+    let changed_lines = batch_sizes
+        .iter()
+        .flat_map(|batch_size| batch_size.diff_size.as_ref())
+        .map(|diff_size| (diff_size.additions + diff_size.deletions) as i64)
+        .collect::<Vec<_>>();
+    BatchSizeMedian {
+        commit_count: median(
+            batch_sizes
+                .iter()
+                .map(|batch_size| batch_size.commit_count as i64)
+                .collect(),
+        ),
+        author_count: median(
+            batch_sizes
+                .iter()
+                .map(|batch_size| batch_size.author_count as i64)
+                .collect(),
+        ),
+        changed_lines: if changed_lines.is_empty() {
+            None
+        } else {
+            Some(median(changed_lines))
+        },
+    }
+}
+
+const calculate_batch_size_summary: CalculateBatchSizeSummary =
+    |items: &Vec<Deployment>, context: &Context| -> BatchSizeSummary {
+        let weekly = WeeklyItems::new(
+            items.clone(),
+            |it| it.deployed_at.date_naive(),
+            context.timeframe.clone(),
+        )
+        .iter()
+        .map(|(week, items)| WeeklyBatchSize {
+            week: *week,
+            deployments: items.len() as u32,
+            median: batch_size_median(items.iter().flat_map(|it| it.batch_size.as_ref()).collect()),
+        })
+        .sorted_by_key(|it| it.week)
+        .collect();
+        BatchSizeSummary {
+            median: batch_size_median(items.iter().flat_map(|it| it.batch_size.as_ref()).collect()),
+            weekly,
         }
     };
 
+const calculate_environment_summaries: CalculateEnvironmentSummaries =
+    |items: &Vec<Deployment>, context: &Context| -> Vec<EnvironmentSummary> {
+        items
+            .iter()
+            .filter_map(|item| match &item.info {
+                DeploymentInfo::GithubDeployment { environment, .. } => {
+                    Some((environment.clone(), item.clone()))
+                }
+                _ => None,
+            })
+            .into_group_map()
+            .into_iter()
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(environment, items)| EnvironmentSummary {
+                environment,
+                total_deployments: items.len() as u32,
+                deployment_frequency_per_day: calculate_deployment_frequency_per_day(
+                    &items, context,
+                ),
+                lead_time_for_changes: calculate_lead_time_median(&items),
+            })
+            .collect()
+    };
+
 // ---------------------------
 // Retrieve FourKeys event
 // ---------------------------
 struct RetrieveFourKeysStepImpl<
     FDeploymentsFetcher: DeploymentsFetcher,
     FTwoCommitsComparer: TwoCommitsComparer,
+    FPullRequestsFetcher: PullRequestsFetcher,
 > {
     pub deployments_fetcher: FDeploymentsFetcher,
     pub two_commits_comparer: FTwoCommitsComparer,
+    pub pull_requests_fetcher: FPullRequestsFetcher,
+}
+impl<
+        FDeploymentsFetcher: DeploymentsFetcher + Sync + Send,
+        FTwoCommitsComparer: TwoCommitsComparer + Sync + Send,
+        FPullRequestsFetcher: PullRequestsFetcher + Sync + Send,
+    > RetrieveFourKeysStepImpl<FDeploymentsFetcher, FTwoCommitsComparer, FPullRequestsFetcher>
+{
+    /// The pull requests of the compared commits, and the start of their lead time when it is
+    /// not the first commit's author date. The pull requests are only read when the lead time
+    /// starts at them or is reported per pull request, so the stages are empty otherwise.
+    async fn read_compared_commits(
+        &self,
+        head_sha: &str,
+        compared: &ComparedCommits,
+        lead_time_start: LeadTimeStart,
+        lead_time_per_pull_request: bool,
+        author_exclusion: &AuthorExclusion,
+    ) -> (
+        Vec<PullRequest>,
+        Option<DateTime<Utc>>,
+        Option<DataQualityWarning>,
+    ) {
+        let commit_started_at = match lead_time_start {
+            LeadTimeStart::FirstCommitCommitted => {
+                pick_first_committed_at(compared, author_exclusion)
+            }
+            _ => None,
+        };
+        if !lead_time_start.is_pull_request_based() && !lead_time_per_pull_request {
+            return (vec![], commit_started_at, None);
+        }
+        let pulls = self
+            .pull_requests_fetcher
+            .fetch(PullRequestsFetcherParams {
+                commit_shas: compared.commits.iter().map(|c| c.sha.clone()).collect(),
+                with_first_push: lead_time_start == LeadTimeStart::BranchFirstPushed,
+            })
+            .await;
+        let pulls = match pulls {
+            Ok(pulls) => pulls,
+            Err(e) => {
+                log::warn!("Cannot read the pull requests of {}: {:?}", head_sha, e);
+                let consequence = if lead_time_start.is_pull_request_based() {
+                    "the lead time starts at the first commit"
+                } else {
+                    "the lead time has no per pull request breakdown"
+                };
+                let warning = DataQualityWarning::new(
+                    head_sha,
+                    format!(
+                        "Degraded: the pull requests could not be read, {}: {}",
+                        consequence, e
+                    ),
+                );
+                return (vec![], commit_started_at, Some(warning));
+            }
+        };
+        if !lead_time_start.is_pull_request_based() {
+            return (pulls, commit_started_at, None);
+        }
+        match pick_pull_request_start(&pulls, lead_time_start) {
+            Some(started_at) => (pulls, Some(started_at), None),
+            None => (
+                pulls,
+                None,
+                Some(DataQualityWarning::new(
+                    head_sha,
+                    "Degraded: no pull request was found, the lead time starts at the first commit",
+                )),
+            ),
+        }
+    }
 }
 #[async_trait]
 impl<
         FDeploymentsFetcher: DeploymentsFetcher + Sync + Send,
         FTwoCommitsComparer: TwoCommitsComparer + Sync + Send,
-    > RetrieveFourKeysStep for RetrieveFourKeysStepImpl<FDeploymentsFetcher, FTwoCommitsComparer>
+        FPullRequestsFetcher: PullRequestsFetcher + Sync + Send,
+    > RetrieveFourKeysStep
+    for RetrieveFourKeysStepImpl<FDeploymentsFetcher, FTwoCommitsComparer, FPullRequestsFetcher>
 {
     async fn retrieve_four_keys(
         self,
         context: RetrieveFourKeysExecutionContext,
     ) -> Result<FourKeysResult, RetrieveFourKeysEventError> {
+        let lead_time_start = context.project.lead_time_start;
+        let lead_time_fallback = context.project.lead_time_fallback;
+        let lead_time_per_pull_request = context.project.lead_time_per_pull_request;
+        let author_exclusion = context.project.author_exclusion;
+        let path_filter = context.project.path_filter;
         let context = Context {
             timeframe: context.timeframe,
             developers: context.project.developer_count,
             working_days_per_week: context.project.working_days_per_week,
         };
-        let deployment_logs = self
+        let fetched = self
             .deployments_fetcher
             .fetch(DeploymentsFetcherParams {
                 timeframe: context.timeframe.clone(),
+                author_exclusion: author_exclusion.clone(),
             })
             .await?;
-        let deployment_logs = deployment_logs
+        let mut warnings = fetched.warnings;
+        let deployment_logs = fetched
+            .logs
             .into_iter()
             .filter(|log| context.timeframe.is_include(&log.deployed_at))
             .collect::<Vec<_>>();
-        let deployment_with_first_operations = join_all(deployment_logs.iter().map(|log| async {
-            let first_operation = match log.base.clone() {
-                BaseCommitShaOrRepositoryInfo::BaseCommitSha(sha) => {
-                    // HACK: use method chain...
-                    if let Ok(commit_sha_pair) =
-                        ValidatedCommitShaPair::new(sha.clone(), log.head_commit.sha.clone())
-                    {
-                        if let Ok(commits) =
-                            self.two_commits_comparer.compare(commit_sha_pair).await
+        let (deployment_with_first_operations, compare_warnings): (Vec<_>, Vec<_>) =
+            join_all(deployment_logs.iter().map(|log| async {
+                let mut commits_truncated = false;
+                let mut lead_time_started_at = None;
+                let mut pull_requests = vec![];
+                let mut pull_request_lead_times = vec![];
+                let mut batch_size = None;
+                let mut excluded_commit_count = 0;
+                let mut outside_paths = false;
+                let mut log_warnings = vec![];
+                let first_operation = match log.base.clone() {
+                    BaseCommitShaOrRepositoryInfo::BaseCommitSha(sha) => {
+                        // HACK: use method chain...
+                        if let Ok(commit_sha_pair) =
+                            ValidatedCommitShaPair::new(sha.clone(), log.head_commit.sha.clone())
                         {
-                            let first_commit = pick_first_commit(&commits);
-                            first_commit.map(FirstCommitOrRepositoryInfo::FirstCommit)
+                            match self
+                                .two_commits_comparer
+                                .compare(commit_sha_pair, path_filter.is_scoped())
+                                .await
+                                .map(|compared| scope_compared_commits(compared, &path_filter))
+                            {
+                                Ok(None) => {
+                                    outside_paths = true;
+                                    None
+                                }
+                                Ok(Some(compared)) => {
+                                    commits_truncated = compared.truncated;
+                                    batch_size = Some(calculate_batch_size(&compared));
+                                    let (pulls, started_at, start_warning) = self
+                                        .read_compared_commits(
+                                            &log.head_commit.sha,
+                                            &compared,
+                                            lead_time_start,
+                                            lead_time_per_pull_request,
+                                            &author_exclusion,
+                                        )
+                                        .await;
+                                    if lead_time_per_pull_request {
+                                        pull_request_lead_times = calculate_pull_request_lead_times(
+                                            &pulls,
+                                            &compared.commits,
+                                            log.deployed_at,
+                                            lead_time_start,
+                                        );
+                                    }
+                                    pull_requests =
+                                        pick_merged_pull_requests(&pulls, &compared.commits);
+                                    lead_time_started_at = started_at;
+                                    log_warnings.extend(start_warning);
+                                    excluded_commit_count = compared
+                                        .commits
+                                        .iter()
+                                        .filter(|commit| {
+                                            author_exclusion.is_excluded(&commit.creator_login)
+                                        })
+                                        .count()
+                                        as u32;
+                                    let first_commit =
+                                        pick_first_commit(&compared.commits, &author_exclusion);
+                                    first_commit.map(FirstCommitOrRepositoryInfo::FirstCommit)
+                                }
+                                Err(e) => {
+                                    log::warn!(
+                                        "Skip the lead time of {}: {:?}",
+                                        log.head_commit.sha,
+                                        e
+                                    );
+                                    log_warnings.push(DataQualityWarning::new(
+                                        log.head_commit.sha.clone(),
+                                        format!(
+                                            "Degraded: no lead time, the comparison failed: {}",
+                                            e
+                                        ),
+                                    ));
+                                    None
+                                }
+                            }
                         } else {
                             None
                         }
-                    } else {
-                        None
                     }
-                }
-                BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(created_at) => Some(
-                    FirstCommitOrRepositoryInfo::RepositoryInfo(RepositoryInfo { created_at }),
-                ),
-            };
-            DeploymentLogWithFirstOperation {
-                deployment_log: log.clone(),
-                first_operation,
-            }
-        }))
-        .await;
+                    BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(created_at) => {
+                        match lead_time_fallback {
+                            LeadTimeFallback::Exclude => {
+                                log_warnings.push(DataQualityWarning::new(
+                                    log.head_commit.sha.clone(),
+                                    "Degraded: no lead time, no earlier deployment to compare with",
+                                ));
+                                None
+                            }
+                            LeadTimeFallback::RepositoryCreated => {
+                                Some(FirstCommitOrRepositoryInfo::RepositoryInfo(
+                                    RepositoryInfo { created_at },
+                                ))
+                            }
+                            LeadTimeFallback::RepositoryCreatedCapped(days) => {
+                                let cap = log.deployed_at - Duration::days(days.into());
+                                lead_time_started_at = Some(created_at.max(cap));
+                                Some(FirstCommitOrRepositoryInfo::RepositoryInfo(
+                                    RepositoryInfo { created_at },
+                                ))
+                            }
+                        }
+                    }
+                };
+                let deployment = DeploymentLogWithFirstOperation {
+                    deployment_log: log.clone(),
+                    first_operation,
+                    commits_truncated,
+                    lead_time_started_at,
+                    pull_requests,
+                    pull_request_lead_times,
+                    batch_size,
+                    excluded_commit_count,
+                    outside_paths,
+                };
+                (deployment, log_warnings)
+            }))
+            .await
+            .into_iter()
+            .unzip();
+        warnings.extend(compare_warnings.into_iter().flatten());
+        warnings.extend(
+            deployment_with_first_operations
+                .iter()
+                .filter(|it| it.commits_truncated)
+                .map(|it| {
+                    DataQualityWarning::new(
+                        it.deployment_log.head_commit.sha.clone(),
+                        "Degraded: not every commit could be read, the lead time may be understated",
+                    )
+                }),
+        );
+        let excluded = ExcludedCounts {
+            deployments: fetched.excluded_count,
+            commits: deployment_with_first_operations
+                .iter()
+                .map(|it| it.excluded_commit_count)
+                .sum(),
+            deployments_outside_paths: deployment_with_first_operations
+                .iter()
+                .filter(|it| it.outside_paths)
+                .count() as u32,
+        };
         let deployments: Vec<Deployment> = deployment_with_first_operations
             .into_iter()
+            .filter(|it| !it.outside_paths)
             .map(calculate_lead_time)
             .collect();
         let mut sorted_deployments = deployments;
@@ -278,10 +770,14 @@ impl<
         };
 
         let lead_time_for_changes = calculate_lead_time_median(&sorted_deployments);
+        let lead_time_stages = calculate_lead_time_stages_median(&sorted_deployments);
+        let batch_size = calculate_batch_size_summary(&sorted_deployments, &context);
+        let environments = calculate_environment_summaries(&sorted_deployments, &context);
 
         let performance = DeploymentPerformance {
             deployment_frequency,
             lead_time_for_changes,
+            lead_time_stages,
         };
 
         let daily_deployment_summaries: Vec<DailyDeploymentsSummary> = DailyItems::new(
@@ -303,6 +799,10 @@ impl<
             deployments: sorted_daily_deployment_summaries,
             context,
             performance,
+            batch_size,
+            environments,
+            excluded,
+            warnings,
         };
 
         Ok(deployment_frequency)
@@ -322,15 +822,19 @@ const create_events: CreateEvents = |project: FourKeysResult| -> Vec<RetrieveFou
 pub struct RetrieveFourKeysWorkflow<
     FDeploymentsFetcher: DeploymentsFetcher,
     FTwoCommitsComparer: TwoCommitsComparer,
+    FPullRequestsFetcher: PullRequestsFetcher,
 > {
     pub deployments_fetcher: FDeploymentsFetcher,
     pub two_commits_comparer: FTwoCommitsComparer,
+    pub pull_requests_fetcher: FPullRequestsFetcher,
 }
 #[async_trait]
 impl<
         FDeploymentsFetcher: DeploymentsFetcher + Sync + Send,
         FTwoCommitsComparer: TwoCommitsComparer + Sync + Send,
-    > RetrieveFourKeys for RetrieveFourKeysWorkflow<FDeploymentsFetcher, FTwoCommitsComparer>
+        FPullRequestsFetcher: PullRequestsFetcher + Sync + Send,
+    > RetrieveFourKeys
+    for RetrieveFourKeysWorkflow<FDeploymentsFetcher, FTwoCommitsComparer, FPullRequestsFetcher>
 {
     async fn retrieve_four_keys(
         self,
@@ -340,6 +844,7 @@ impl<
             RetrieveFourKeysStepImpl {
                 deployments_fetcher: self.deployments_fetcher,
                 two_commits_comparer: self.two_commits_comparer,
+                pull_requests_fetcher: self.pull_requests_fetcher,
             }
             .retrieve_four_keys(context)
             .await?,
@@ -19,6 +19,13 @@ mod tests {
             let item = DeploymentLogWithFirstOperation {
                 deployment_log: build_deployment_log("2023-01-01 00:00:00"),
                 first_operation: None,
+                commits_truncated: false,
+                lead_time_started_at: None,
+                pull_requests: vec![],
+                pull_request_lead_times: vec![],
+                batch_size: None,
+                excluded_commit_count: 0,
+                outside_paths: false,
             };
             assert_eq!(calculate_lead_time_for_changes_seconds(item), None);
         }
@@ -30,6 +37,13 @@ mod tests {
                 first_operation: Some(FirstCommitOrRepositoryInfo::FirstCommit(build_commit(
                     "2023-01-01 00:00:00",
                 ))),
+                commits_truncated: false,
+                lead_time_started_at: None,
+                pull_requests: vec![],
+                pull_request_lead_times: vec![],
+                batch_size: None,
+                excluded_commit_count: 0,
+                outside_paths: false,
             };
             assert_eq!(
                 calculate_lead_time_for_changes_seconds(item),
@@ -44,6 +58,13 @@ mod tests {
                 first_operation: Some(FirstCommitOrRepositoryInfo::RepositoryInfo(
                     build_repository_info("2023-01-01 00:00:00"),
                 )),
+                commits_truncated: false,
+                lead_time_started_at: None,
+                pull_requests: vec![],
+                pull_request_lead_times: vec![],
+                batch_size: None,
+                excluded_commit_count: 0,
+                outside_paths: false,
             };
             assert_eq!(
                 calculate_lead_time_for_changes_seconds(item),
@@ -51,4 +72,57 @@ mod tests {
             );
         }
     }
+
+    mod scope_compared_commits_tests {
+        use std::collections::HashMap;
+
+        use crate::{
+            common_types::{
+                author_exclusion::AuthorExclusion, commit::Commit, path_filter::PathFilter,
+            },
+            dependencies::two_commits_comparer::interface::ComparedCommits,
+            metrics_retrieving::retrieve_four_keys::{
+                pick_first_committed_at, scope_compared_commits,
+            },
+            shared::datetime_utc::parse,
+            tests::factories::commit::build_commit,
+        };
+
+        #[test]
+        fn first_committer_date_is_of_the_commits_touching_the_paths() {
+            let compared = ComparedCommits {
+                commits: vec![
+                    Commit {
+                        sha: "web".to_string(),
+                        ..build_commit("2023-01-02 10:00:00")
+                    },
+                    Commit {
+                        sha: "api".to_string(),
+                        ..build_commit("2023-01-05 10:00:00")
+                    },
+                ],
+                truncated: false,
+                committer_dates: HashMap::from([
+                    ("web".to_string(), parse("2023-01-03 10:00:00").unwrap()),
+                    ("api".to_string(), parse("2023-01-06 10:00:00").unwrap()),
+                ]),
+                diff_size: None,
+                commit_files: HashMap::from([
+                    ("web".to_string(), vec!["web/index.ts".to_string()]),
+                    ("api".to_string(), vec!["api/main.rs".to_string()]),
+                ]),
+            };
+
+            let scoped = scope_compared_commits(
+                compared,
+                &PathFilter::new(Some(vec!["api/*".to_string()])).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                pick_first_committed_at(&scoped, &AuthorExclusion::default()),
+                Some(parse("2023-01-06 10:00:00").unwrap())
+            );
+        }
+    }
 }
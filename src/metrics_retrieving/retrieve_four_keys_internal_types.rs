@@ -1,19 +1,70 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use super::retrieve_four_keys::{
-    Context, Deployment, DeploymentFrequency, DeploymentFrequencyLabel,
-    DeploymentFrequencyPerformanceSurvey2022, DeploymentLeadTimeForChanges,
-    FirstCommitOrRepositoryInfo, FourKeysResult, RetrieveFourKeysEvent, RetrieveFourKeysEventError,
-    RetrieveFourKeysExecutionContext,
+    BatchSizeSummary, Context, Deployment, DeploymentBatchSize, DeploymentFrequency,
+    DeploymentFrequencyLabel, DeploymentFrequencyPerformanceSurvey2022,
+    DeploymentLeadTimeForChanges, DeploymentLeadTimeStages, EnvironmentSummary,
+    FirstCommitOrRepositoryInfo, FourKeysResult, LeadTimeStagesMedian, PullRequestLeadTime,
+    RetrieveFourKeysEvent, RetrieveFourKeysEventError, RetrieveFourKeysExecutionContext,
 };
 use crate::{
-    common_types::commit::Commit, dependencies::deployments_fetcher::interface::DeploymentLog,
+    common_types::{
+        author_exclusion::AuthorExclusion, commit::Commit, lead_time_start::LeadTimeStart,
+        path_filter::PathFilter,
+    },
+    dependencies::{
+        deployments_fetcher::interface::DeploymentLog,
+        pull_requests_fetcher::interface::PullRequest,
+        two_commits_comparer::interface::ComparedCommits,
+    },
 };
 
 // ---------------------------
 // PickFirstCommit
 // ---------------------------
-pub(super) type PickFirstCommit = fn(commits: &Vec<Commit>) -> Option<Commit>;
+pub(super) type PickFirstCommit =
+    fn(commits: &Vec<Commit>, author_exclusion: &AuthorExclusion) -> Option<Commit>;
+
+// ---------------------------
+// PickFirstCommittedAt
+// ---------------------------
+pub(super) type PickFirstCommittedAt =
+    fn(compared: &ComparedCommits, author_exclusion: &AuthorExclusion) -> Option<DateTime<Utc>>;
+
+// ---------------------------
+// PickPullRequestStart
+// ---------------------------
+pub(super) type PickPullRequestStart =
+    fn(pulls: &[PullRequest], lead_time_start: LeadTimeStart) -> Option<DateTime<Utc>>;
+
+// ---------------------------
+// PickMergedPullRequests
+// ---------------------------
+pub(super) type PickMergedPullRequests =
+    fn(pulls: &[PullRequest], commits: &[Commit]) -> Vec<PullRequest>;
+
+// ---------------------------
+// CalculatePullRequestLeadTimes
+// ---------------------------
+pub(super) type CalculatePullRequestLeadTimes = fn(
+    pulls: &[PullRequest],
+    commits: &[Commit],
+    deployed_at: DateTime<Utc>,
+    lead_time_start: LeadTimeStart,
+) -> Vec<PullRequestLeadTime>;
+
+// ---------------------------
+// ScopeComparedCommits
+// ---------------------------
+/// Keeps the commits touching the project's paths; empty when none does
+pub(super) type ScopeComparedCommits =
+    fn(compared: ComparedCommits, path_filter: &PathFilter) -> Option<ComparedCommits>;
+
+// ---------------------------
+// CalculateBatchSize
+// ---------------------------
+pub(super) type CalculateBatchSize = fn(compared: &ComparedCommits) -> DeploymentBatchSize;
 
 // ---------------------------
 // CalculateEachLogLeadTimes
@@ -22,9 +73,23 @@ pub(super) type PickFirstCommit = fn(commits: &Vec<Commit>) -> Option<Commit>;
 pub(super) struct DeploymentLogWithFirstOperation {
     pub(super) deployment_log: DeploymentLog,
     pub(super) first_operation: Option<FirstCommitOrRepositoryInfo>,
+    pub(super) commits_truncated: bool,
+    /// Overrides the date of `first_operation` as the start of the lead time
+    pub(super) lead_time_started_at: Option<DateTime<Utc>>,
+    /// The pull requests merged by the deployed commits
+    pub(super) pull_requests: Vec<PullRequest>,
+    pub(super) pull_request_lead_times: Vec<PullRequestLeadTime>,
+    pub(super) batch_size: Option<DeploymentBatchSize>,
+    /// The compared commits by excluded authors
+    pub(super) excluded_commit_count: u32,
+    /// None of the compared commits touches the project's paths, so the deployment does not count
+    pub(super) outside_paths: bool,
 }
 pub(super) type CalculateLeadTime = fn(DeploymentLogWithFirstOperation) -> Deployment;
 
+pub(super) type CalculateLeadTimeStages =
+    fn(pulls: &[PullRequest], deployed_at: DateTime<Utc>) -> Option<DeploymentLeadTimeStages>;
+
 // ---------------------------
 // Aggregation
 // ---------------------------
@@ -43,6 +108,13 @@ pub(super) type GetDeploymentPerformanceLabel =
 
 pub(super) type CalculateLeadTimeMedian = fn(&Vec<Deployment>) -> DeploymentLeadTimeForChanges;
 
+pub(super) type CalculateLeadTimeStagesMedian = fn(&Vec<Deployment>) -> LeadTimeStagesMedian;
+
+pub(super) type CalculateBatchSizeSummary = fn(&Vec<Deployment>, &Context) -> BatchSizeSummary;
+
+pub(super) type CalculateEnvironmentSummaries =
+    fn(&Vec<Deployment>, &Context) -> Vec<EnvironmentSummary>;
+
 // ---------------------------
 // RetrieveFourKeys
 // ---------------------------
@@ -2,9 +2,14 @@
 mod tests {
     mod retrieve_four_keys_workflow_tests {
         use crate::{
-            common_types::date_time_range::DateTimeRange,
+            common_types::{
+                author_exclusion::AuthorExclusion, date_time_range::DateTimeRange,
+                lead_time_fallback::LeadTimeFallback, lead_time_start::LeadTimeStart,
+                path_filter::PathFilter,
+            },
             dependencies::{
                 deployments_fetcher::mock::DeploymentsFetcherWithMock,
+                pull_requests_fetcher::mock::PullRequestsFetcherWithMock,
                 two_commits_comparer::mock::TwoCommitsComparerWithMock,
             },
             metrics_retrieving::retrieve_four_keys::{
@@ -28,6 +33,11 @@ mod tests {
                     name: "project".to_string(),
                     developer_count: 2,
                     working_days_per_week: 2.5,
+                    lead_time_start: LeadTimeStart::default(),
+                    lead_time_fallback: LeadTimeFallback::default(),
+                    lead_time_per_pull_request: false,
+                    author_exclusion: AuthorExclusion::default(),
+                    path_filter: PathFilter::default(),
                 },
             };
             let deployments_fetcher = DeploymentsFetcherWithMock {
@@ -118,9 +128,11 @@ mod tests {
             let two_commits_comparer = TwoCommitsComparerWithMock {
                 commits: vec![build_commit("2023-01-02 10:00:00")],
             };
+            let pull_requests_fetcher = PullRequestsFetcherWithMock::default();
             let workflow = RetrieveFourKeysWorkflow {
                 deployments_fetcher,
                 two_commits_comparer,
+                pull_requests_fetcher,
             };
             let result = workflow.retrieve_four_keys(context).await;
             assert!(result.is_ok());
@@ -158,6 +170,11 @@ mod tests {
                     name: "project".to_string(),
                     developer_count: 2,
                     working_days_per_week: 2.5,
+                    lead_time_start: LeadTimeStart::default(),
+                    lead_time_fallback: LeadTimeFallback::default(),
+                    lead_time_per_pull_request: false,
+                    author_exclusion: AuthorExclusion::default(),
+                    path_filter: PathFilter::default(),
                 },
             };
             let deployments_fetcher = DeploymentsFetcherWithMock {
@@ -231,9 +248,11 @@ mod tests {
             let two_commits_comparer = TwoCommitsComparerWithMock {
                 commits: vec![build_commit("2023-01-02 10:00:00")],
             };
+            let pull_requests_fetcher = PullRequestsFetcherWithMock::default();
             let workflow = RetrieveFourKeysWorkflow {
                 deployments_fetcher,
                 two_commits_comparer,
+                pull_requests_fetcher,
             };
             let result = workflow.retrieve_four_keys(context).await;
             assert!(result.is_ok());
@@ -274,6 +293,11 @@ mod tests {
                     name: "project".to_string(),
                     developer_count: 2,
                     working_days_per_week: 2.5,
+                    lead_time_start: LeadTimeStart::default(),
+                    lead_time_fallback: LeadTimeFallback::default(),
+                    lead_time_per_pull_request: false,
+                    author_exclusion: AuthorExclusion::default(),
+                    path_filter: PathFilter::default(),
                 },
             };
             let deployments_fetcher = DeploymentsFetcherWithMock {
@@ -341,9 +365,11 @@ mod tests {
             let two_commits_comparer = TwoCommitsComparerWithMock {
                 commits: vec![build_commit("2023-01-02 10:00:00")],
             };
+            let pull_requests_fetcher = PullRequestsFetcherWithMock::default();
             let workflow = RetrieveFourKeysWorkflow {
                 deployments_fetcher,
                 two_commits_comparer,
+                pull_requests_fetcher,
             };
             let result = workflow.retrieve_four_keys(context).await;
             assert!(result.is_ok());
@@ -381,6 +407,11 @@ mod tests {
                     name: "project".to_string(),
                     developer_count: 2,
                     working_days_per_week: 2.5,
+                    lead_time_start: LeadTimeStart::default(),
+                    lead_time_fallback: LeadTimeFallback::default(),
+                    lead_time_per_pull_request: false,
+                    author_exclusion: AuthorExclusion::default(),
+                    path_filter: PathFilter::default(),
                 },
             };
             let deployments_fetcher = DeploymentsFetcherWithMock {
@@ -448,9 +479,11 @@ mod tests {
             let two_commits_comparer = TwoCommitsComparerWithMock {
                 commits: vec![build_commit("2023-01-02 10:00:00")],
             };
+            let pull_requests_fetcher = PullRequestsFetcherWithMock::default();
             let workflow = RetrieveFourKeysWorkflow {
                 deployments_fetcher,
                 two_commits_comparer,
+                pull_requests_fetcher,
             };
             let result = workflow.retrieve_four_keys(context).await;
             assert!(result.is_ok());
@@ -476,4 +509,537 @@ mod tests {
             }
         }
     }
+
+    mod lead_time_start_tests {
+        use crate::{
+            common_types::{
+                author_exclusion::AuthorExclusion, date_time_range::DateTimeRange,
+                lead_time_fallback::LeadTimeFallback, lead_time_start::LeadTimeStart,
+                path_filter::PathFilter,
+            },
+            dependencies::{
+                deployments_fetcher::{
+                    interface::{BaseCommitShaOrRepositoryInfo, DeploymentLog},
+                    mock::DeploymentsFetcherWithMock,
+                },
+                pull_requests_fetcher::{
+                    interface::PullRequest, mock::PullRequestsFetcherWithMock,
+                },
+                two_commits_comparer::mock::TwoCommitsComparerWithMock,
+            },
+            metrics_retrieving::retrieve_four_keys::{
+                Deployment, FourKeysResult, RetrieveFourKeys, RetrieveFourKeysEvent,
+                RetrieveFourKeysExecutionContext, RetrieveFourKeysExecutionContextProject,
+                RetrieveFourKeysWorkflow,
+            },
+            shared::datetime_utc::parse,
+            tests::factories::{commit::build_commit, deployment_log::build_deployment_log},
+        };
+
+        const HOUR: i64 = 60 * 60;
+        const DAY: i64 = 24 * HOUR;
+
+        fn build_pull_request(created_at: &str, review_requested_at: Option<&str>) -> PullRequest {
+            PullRequest {
+                number: 1,
+                title: "title".to_string(),
+                head_ref_name: "feature".to_string(),
+                created_at: parse(created_at).unwrap(),
+                merged_at: Some(parse("2023-01-09 10:00:00").unwrap()),
+                merge_commit_sha: Some("sha".to_string()),
+                first_commit_authored_at: Some(parse("2023-01-05 10:00:00").unwrap()),
+                first_reviewed_at: Some(parse("2023-01-08 12:00:00").unwrap()),
+                approved_at: Some(parse("2023-01-09 08:00:00").unwrap()),
+                first_review_requested_at: review_requested_at.map(|it| parse(it).unwrap()),
+                first_pushed_at: None,
+            }
+        }
+
+        async fn retrieve(
+            deployment_log: DeploymentLog,
+            pull_requests: Vec<PullRequest>,
+            lead_time_start: LeadTimeStart,
+            lead_time_fallback: LeadTimeFallback,
+            lead_time_per_pull_request: bool,
+        ) -> (Deployment, FourKeysResult) {
+            let context = RetrieveFourKeysExecutionContext {
+                timeframe: DateTimeRange::new(
+                    parse("2023-01-01 00:00:00").unwrap(),
+                    parse("2023-01-31 00:00:00").unwrap(),
+                )
+                .unwrap(),
+                project: RetrieveFourKeysExecutionContextProject {
+                    name: "project".to_string(),
+                    developer_count: 1,
+                    working_days_per_week: 5.0,
+                    lead_time_start,
+                    lead_time_fallback,
+                    lead_time_per_pull_request,
+                    author_exclusion: AuthorExclusion::default(),
+                    path_filter: PathFilter::default(),
+                },
+            };
+            let workflow = RetrieveFourKeysWorkflow {
+                deployments_fetcher: DeploymentsFetcherWithMock {
+                    deployment_logs: vec![deployment_log],
+                },
+                two_commits_comparer: TwoCommitsComparerWithMock {
+                    commits: vec![build_commit("2023-01-02 10:00:00")],
+                },
+                pull_requests_fetcher: PullRequestsFetcherWithMock {
+                    pull_requests,
+                    ..Default::default()
+                },
+            };
+            let events = workflow.retrieve_four_keys(context).await.unwrap();
+            let RetrieveFourKeysEvent::RetrieveFourKeys(result) =
+                events.into_iter().next().unwrap();
+            let deployment = result
+                .deployments
+                .iter()
+                .flat_map(|it| it.items.clone())
+                .next()
+                .unwrap();
+            (deployment, result)
+        }
+
+        #[tokio::test]
+        async fn starts_at_the_first_commit_by_default() {
+            let (deployment, _) = retrieve(
+                build_deployment_log("2023-01-10 10:00:00"),
+                vec![build_pull_request("2023-01-08 10:00:00", None)],
+                LeadTimeStart::default(),
+                LeadTimeFallback::default(),
+                false,
+            )
+            .await;
+            assert_eq!(deployment.lead_time_for_changes_seconds, Some(8 * DAY));
+        }
+
+        #[tokio::test]
+        async fn starts_at_the_pull_request_creation() {
+            let (deployment, result) = retrieve(
+                build_deployment_log("2023-01-10 10:00:00"),
+                vec![build_pull_request("2023-01-08 10:00:00", None)],
+                LeadTimeStart::PullRequestCreated,
+                LeadTimeFallback::default(),
+                false,
+            )
+            .await;
+            assert_eq!(deployment.lead_time_for_changes_seconds, Some(2 * DAY));
+            assert_eq!(
+                deployment.lead_time_started_at,
+                Some(parse("2023-01-08 10:00:00").unwrap())
+            );
+            assert!(result.warnings.is_empty());
+        }
+
+        #[tokio::test]
+        async fn starts_at_the_first_review_request() {
+            let (deployment, _) = retrieve(
+                build_deployment_log("2023-01-10 10:00:00"),
+                vec![build_pull_request(
+                    "2023-01-08 10:00:00",
+                    Some("2023-01-09 10:00:00"),
+                )],
+                LeadTimeStart::PullRequestReviewRequested,
+                LeadTimeFallback::default(),
+                false,
+            )
+            .await;
+            assert_eq!(deployment.lead_time_for_changes_seconds, Some(DAY));
+        }
+
+        #[tokio::test]
+        async fn without_pull_requests_starts_at_the_first_commit_with_a_warning() {
+            let (deployment, result) = retrieve(
+                build_deployment_log("2023-01-10 10:00:00"),
+                vec![],
+                LeadTimeStart::PullRequestCreated,
+                LeadTimeFallback::default(),
+                false,
+            )
+            .await;
+            assert_eq!(deployment.lead_time_for_changes_seconds, Some(8 * DAY));
+            assert_eq!(result.warnings.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn breaks_the_lead_time_into_stages() {
+            let (deployment, result) = retrieve(
+                build_deployment_log("2023-01-10 10:00:00"),
+                vec![build_pull_request("2023-01-08 10:00:00", None)],
+                LeadTimeStart::PullRequestCreated,
+                LeadTimeFallback::default(),
+                false,
+            )
+            .await;
+            let stages = deployment.lead_time_stages.unwrap();
+            assert_eq!(stages.coding_seconds, Some(3 * DAY));
+            assert_eq!(stages.pickup_seconds, Some(2 * HOUR));
+            assert_eq!(stages.review_seconds, Some(20 * HOUR));
+            assert_eq!(stages.merge_seconds, Some(2 * HOUR));
+            assert_eq!(stages.deploy_seconds, Some(DAY));
+
+            let medians = result.performance.lead_time_stages;
+            assert_eq!(medians.review.hours, 20);
+            assert_eq!(medians.deploy.days, 1);
+        }
+
+        #[tokio::test]
+        async fn starting_at_the_first_commit_does_not_read_the_pull_requests() {
+            let (deployment, result) = retrieve(
+                build_deployment_log("2023-01-10 10:00:00"),
+                vec![build_pull_request("2023-01-08 10:00:00", None)],
+                LeadTimeStart::default(),
+                LeadTimeFallback::default(),
+                false,
+            )
+            .await;
+            assert_eq!(deployment.lead_time_stages, None);
+            assert_eq!(deployment.lead_time_for_changes_seconds, Some(8 * DAY));
+            assert!(result.warnings.is_empty());
+        }
+
+        #[tokio::test]
+        async fn without_pull_requests_has_no_stages() {
+            let (deployment, result) = retrieve(
+                build_deployment_log("2023-01-10 10:00:00"),
+                vec![],
+                LeadTimeStart::default(),
+                LeadTimeFallback::default(),
+                false,
+            )
+            .await;
+            assert_eq!(deployment.lead_time_stages, None);
+            assert!(result.warnings.is_empty());
+        }
+
+        #[tokio::test]
+        async fn expands_the_deployment_into_its_merged_pull_requests() {
+            let earlier_pull = PullRequest {
+                number: 2,
+                merge_commit_sha: Some("earlier".to_string()),
+                first_commit_authored_at: Some(parse("2022-12-01 10:00:00").unwrap()),
+                ..build_pull_request("2023-01-08 10:00:00", None)
+            };
+            let (deployment, result) = retrieve(
+                build_deployment_log("2023-01-10 10:00:00"),
+                vec![
+                    build_pull_request("2023-01-08 10:00:00", None),
+                    earlier_pull,
+                ],
+                LeadTimeStart::default(),
+                LeadTimeFallback::default(),
+                true,
+            )
+            .await;
+            assert_eq!(deployment.pull_request_lead_times.len(), 1);
+            assert_eq!(deployment.pull_request_lead_times[0].number, 1);
+            assert_eq!(
+                deployment.pull_request_lead_times[0].lead_time_for_changes_seconds,
+                5 * DAY
+            );
+            assert_eq!(
+                result.performance.lead_time_for_changes.total_seconds,
+                (5 * DAY) as f64
+            );
+        }
+
+        #[tokio::test]
+        async fn breaks_down_only_a_pull_request_merged_by_the_deployment() {
+            let earlier_pull = PullRequest {
+                number: 2,
+                created_at: parse("2022-12-01 10:00:00").unwrap(),
+                merge_commit_sha: Some("earlier".to_string()),
+                ..build_pull_request("2023-01-08 10:00:00", None)
+            };
+            let (deployment, _) = retrieve(
+                build_deployment_log("2023-01-10 10:00:00"),
+                vec![
+                    earlier_pull,
+                    build_pull_request("2023-01-08 10:00:00", None),
+                ],
+                LeadTimeStart::PullRequestCreated,
+                LeadTimeFallback::default(),
+                false,
+            )
+            .await;
+            assert_eq!(deployment.lead_time_stages.unwrap().pull_request_number, 1);
+        }
+
+        #[tokio::test]
+        async fn repository_creation_fallback_can_be_capped_or_excluded() {
+            let first_deployment = DeploymentLog {
+                base: BaseCommitShaOrRepositoryInfo::RepositoryCreatedAt(
+                    parse("2020-01-01 00:00:00").unwrap(),
+                ),
+                ..build_deployment_log("2023-01-10 10:00:00")
+            };
+
+            let (uncapped, _) = retrieve(
+                first_deployment.clone(),
+                vec![],
+                LeadTimeStart::default(),
+                LeadTimeFallback::RepositoryCreated,
+                false,
+            )
+            .await;
+            let (capped, _) = retrieve(
+                first_deployment.clone(),
+                vec![],
+                LeadTimeStart::default(),
+                LeadTimeFallback::RepositoryCreatedCapped(7),
+                false,
+            )
+            .await;
+            let (excluded, excluded_result) = retrieve(
+                first_deployment,
+                vec![],
+                LeadTimeStart::default(),
+                LeadTimeFallback::Exclude,
+                false,
+            )
+            .await;
+
+            assert!(uncapped.lead_time_for_changes_seconds.unwrap() > 365 * DAY);
+            assert_eq!(capped.lead_time_for_changes_seconds, Some(7 * DAY));
+            assert_eq!(excluded.lead_time_for_changes_seconds, None);
+            assert_eq!(excluded_result.warnings.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn reports_the_batch_size_of_the_deployment() {
+            let (deployment, result) = retrieve(
+                build_deployment_log("2023-01-10 10:00:00"),
+                vec![],
+                LeadTimeStart::default(),
+                LeadTimeFallback::default(),
+                false,
+            )
+            .await;
+            let batch_size = deployment.batch_size.unwrap();
+            assert_eq!(batch_size.commit_count, 1);
+            assert_eq!(batch_size.author_count, 1);
+            assert_eq!(batch_size.diff_size, None);
+            assert_eq!(result.batch_size.median.commit_count, 1.0);
+            assert_eq!(result.batch_size.median.changed_lines, None);
+            let week = result
+                .batch_size
+                .weekly
+                .iter()
+                .find(|it| it.deployments > 0)
+                .unwrap();
+            assert_eq!(
+                week.week,
+                parse("2023-01-09 00:00:00").unwrap().date_naive()
+            );
+            assert_eq!(week.median.author_count, 1.0);
+        }
+    }
+
+    mod scope_tests {
+        use crate::{
+            common_types::{
+                author_exclusion::AuthorExclusion, commit::Commit, date_time_range::DateTimeRange,
+                lead_time_fallback::LeadTimeFallback, lead_time_start::LeadTimeStart,
+                path_filter::PathFilter,
+            },
+            dependencies::{
+                deployments_fetcher::{
+                    interface::{DeploymentInfo, DeploymentLog},
+                    mock::DeploymentsFetcherWithMock,
+                },
+                pull_requests_fetcher::mock::PullRequestsFetcherWithMock,
+                two_commits_comparer::mock::TwoCommitsComparerWithMock,
+            },
+            metrics_retrieving::retrieve_four_keys::{
+                Deployment, ExcludedCounts, FourKeysResult, RetrieveFourKeys,
+                RetrieveFourKeysEvent, RetrieveFourKeysExecutionContext,
+                RetrieveFourKeysExecutionContextProject, RetrieveFourKeysWorkflow,
+            },
+            shared::datetime_utc::parse,
+            tests::factories::{commit::build_commit, deployment_log::build_deployment_log},
+        };
+
+        const DAY: i64 = 24 * 60 * 60;
+
+        async fn retrieve(
+            deployment_logs: Vec<DeploymentLog>,
+            commits: Vec<Commit>,
+            path_filter: PathFilter,
+        ) -> (Vec<Deployment>, FourKeysResult) {
+            retrieve_starting_at(
+                deployment_logs,
+                commits,
+                path_filter,
+                LeadTimeStart::default(),
+            )
+            .await
+        }
+
+        async fn retrieve_starting_at(
+            deployment_logs: Vec<DeploymentLog>,
+            commits: Vec<Commit>,
+            path_filter: PathFilter,
+            lead_time_start: LeadTimeStart,
+        ) -> (Vec<Deployment>, FourKeysResult) {
+            let context = RetrieveFourKeysExecutionContext {
+                timeframe: DateTimeRange::new(
+                    parse("2023-01-01 00:00:00").unwrap(),
+                    parse("2023-01-31 00:00:00").unwrap(),
+                )
+                .unwrap(),
+                project: RetrieveFourKeysExecutionContextProject {
+                    name: "project".to_string(),
+                    developer_count: 1,
+                    working_days_per_week: 5.0,
+                    lead_time_start,
+                    lead_time_fallback: LeadTimeFallback::default(),
+                    lead_time_per_pull_request: false,
+                    author_exclusion: AuthorExclusion::default(),
+                    path_filter,
+                },
+            };
+            let workflow = RetrieveFourKeysWorkflow {
+                deployments_fetcher: DeploymentsFetcherWithMock { deployment_logs },
+                two_commits_comparer: TwoCommitsComparerWithMock { commits },
+                pull_requests_fetcher: PullRequestsFetcherWithMock::default(),
+            };
+            let events = workflow.retrieve_four_keys(context).await.unwrap();
+            let RetrieveFourKeysEvent::RetrieveFourKeys(result) =
+                events.into_iter().next().unwrap();
+            let deployments = result
+                .deployments
+                .iter()
+                .flat_map(|it| it.items.clone())
+                .collect::<Vec<_>>();
+            (deployments, result)
+        }
+
+        #[tokio::test]
+        async fn leaves_out_bot_deployments_and_commits() {
+            let (deployments, result) = retrieve(
+                vec![
+                    DeploymentLog {
+                        creator_login: "github-actions[bot]".to_string(),
+                        ..build_deployment_log("2023-01-08 10:00:00")
+                    },
+                    build_deployment_log("2023-01-10 10:00:00"),
+                ],
+                vec![
+                    Commit {
+                        creator_login: "renovate[bot]".to_string(),
+                        ..build_commit("2023-01-02 10:00:00")
+                    },
+                    build_commit("2023-01-05 10:00:00"),
+                ],
+                PathFilter::default(),
+            )
+            .await;
+
+            assert_eq!(deployments.len(), 1);
+            assert_eq!(deployments[0].lead_time_for_changes_seconds, Some(5 * DAY));
+            assert_eq!(
+                result.excluded,
+                ExcludedCounts {
+                    deployments: 1,
+                    commits: 1,
+                    deployments_outside_paths: 0,
+                }
+            );
+        }
+
+        #[tokio::test]
+        async fn starts_at_the_first_committer_date_of_a_counted_commit() {
+            let (deployments, _) = retrieve_starting_at(
+                vec![build_deployment_log("2023-01-10 10:00:00")],
+                vec![
+                    Commit {
+                        sha: "bot".to_string(),
+                        creator_login: "renovate[bot]".to_string(),
+                        ..build_commit("2023-01-02 10:00:00")
+                    },
+                    Commit {
+                        sha: "human".to_string(),
+                        ..build_commit("2023-01-05 10:00:00")
+                    },
+                ],
+                PathFilter::default(),
+                LeadTimeStart::FirstCommitCommitted,
+            )
+            .await;
+
+            assert_eq!(deployments[0].lead_time_for_changes_seconds, Some(5 * DAY));
+        }
+
+        #[tokio::test]
+        async fn counts_only_deployments_touching_the_paths() {
+            // The mock commits change a file named after their sha
+            let commits = vec![
+                Commit {
+                    sha: "web/index.ts".to_string(),
+                    ..build_commit("2023-01-02 10:00:00")
+                },
+                Commit {
+                    sha: "api/main.rs".to_string(),
+                    ..build_commit("2023-01-05 10:00:00")
+                },
+            ];
+
+            let (deployments, _) = retrieve(
+                vec![build_deployment_log("2023-01-10 10:00:00")],
+                commits.clone(),
+                PathFilter::new(Some(vec!["api/*".to_string()])).unwrap(),
+            )
+            .await;
+            assert_eq!(deployments.len(), 1);
+            assert_eq!(deployments[0].lead_time_for_changes_seconds, Some(5 * DAY));
+            assert_eq!(deployments[0].batch_size.as_ref().unwrap().commit_count, 1);
+
+            let (deployments, result) = retrieve(
+                vec![build_deployment_log("2023-01-10 10:00:00")],
+                commits,
+                PathFilter::new(Some(vec!["docs/*".to_string()])).unwrap(),
+            )
+            .await;
+            assert!(deployments.is_empty());
+            assert_eq!(result.excluded.deployments_outside_paths, 1);
+        }
+
+        #[tokio::test]
+        async fn breaks_down_the_deployments_by_environment() {
+            let deployed_to = |environment: &str, deployed_at: &str| DeploymentLog {
+                info: DeploymentInfo::GithubDeployment {
+                    id: "id".to_string(),
+                    environment: environment.to_string(),
+                },
+                ..build_deployment_log(deployed_at)
+            };
+
+            let (_, result) = retrieve(
+                vec![
+                    deployed_to("production-us", "2023-01-12 10:00:00"),
+                    deployed_to("production-eu", "2023-01-10 10:00:00"),
+                    deployed_to("production-us", "2023-01-20 10:00:00"),
+                ],
+                vec![build_commit("2023-01-05 10:00:00")],
+                PathFilter::default(),
+            )
+            .await;
+
+            assert_eq!(
+                result
+                    .environments
+                    .iter()
+                    .map(|it| (it.environment.as_str(), it.total_deployments))
+                    .collect::<Vec<_>>(),
+                vec![("production-eu", 1), ("production-us", 2)]
+            );
+            assert_eq!(
+                result.environments[0].lead_time_for_changes.total_seconds,
+                (5 * DAY) as f64
+            );
+        }
+    }
 }
@@ -4,10 +4,16 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::{
-    common_types::{commit::Commit, date_time_range::DateTimeRange},
+    common_types::{
+        author_exclusion::AuthorExclusion, commit::Commit, date_time_range::DateTimeRange,
+        lead_time_fallback::LeadTimeFallback, lead_time_start::LeadTimeStart,
+        path_filter::PathFilter,
+    },
     dependencies::{
-        deployments_fetcher::interface::{DeploymentInfo, DeploymentsFetcherError},
-        two_commits_comparer::interface::TwoCommitsComparerError,
+        deployments_fetcher::interface::{
+            DataQualityWarning, DeploymentInfo, DeploymentsFetcherError,
+        },
+        two_commits_comparer::interface::{DiffSize, TwoCommitsComparerError},
     },
 };
 
@@ -24,6 +30,11 @@ pub struct RetrieveFourKeysExecutionContextProject {
     pub name: String,
     pub developer_count: u32,
     pub working_days_per_week: f32,
+    pub lead_time_start: LeadTimeStart,
+    pub lead_time_fallback: LeadTimeFallback,
+    pub lead_time_per_pull_request: bool,
+    pub author_exclusion: AuthorExclusion,
+    pub path_filter: PathFilter,
 }
 
 #[derive(Clone)]
@@ -53,6 +64,51 @@ pub struct Deployment {
     pub first_commit: FirstCommitOrRepositoryInfo,
     pub deployed_at: chrono::DateTime<chrono::Utc>,
     pub lead_time_for_changes_seconds: Option<i64>,
+    /// The point the lead time is measured from, per the project's `lead_time_start`
+    pub lead_time_started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Where the lead time went, when the deployment holds a merged pull request
+    pub lead_time_stages: Option<DeploymentLeadTimeStages>,
+    /// The lead time of each pull request merged in the deployment, with `lead_time_per_pull_request`
+    pub pull_request_lead_times: Vec<PullRequestLeadTime>,
+    /// Not every commit of the deployment could be retrieved, so its lead time may be understated
+    pub commits_truncated: bool,
+    /// How much the deployment shipped, when it could be compared with the previous one
+    pub batch_size: Option<DeploymentBatchSize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeploymentBatchSize {
+    pub commit_count: u32,
+    /// The distinct GitHub users among the commit authors
+    pub author_count: u32,
+    pub diff_size: Option<DiffSize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PullRequestLeadTime {
+    pub number: u64,
+    pub title: String,
+    pub started_at: DateTime<Utc>,
+    pub lead_time_for_changes_seconds: i64,
+}
+
+/// The stages of the oldest pull request in a deployment; a stage is empty when either end is unknown
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeploymentLeadTimeStages {
+    pub pull_request_number: u64,
+    /// From the first commit to the pull request being opened
+    pub coding_seconds: Option<i64>,
+    /// From the pull request being opened to its first review
+    pub pickup_seconds: Option<i64>,
+    /// From the first review to the approval
+    pub review_seconds: Option<i64>,
+    /// From the approval to the merge
+    pub merge_seconds: Option<i64>,
+    /// From the merge to the deployment
+    pub deploy_seconds: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,6 +129,62 @@ pub struct DeploymentLeadTimeForChanges {
     pub total_seconds: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct LeadTimeStagesMedian {
+    pub coding: DeploymentLeadTimeForChanges,
+    pub pickup: DeploymentLeadTimeForChanges,
+    pub review: DeploymentLeadTimeForChanges,
+    pub merge: DeploymentLeadTimeForChanges,
+    pub deploy: DeploymentLeadTimeForChanges,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BatchSizeMedian {
+    pub commit_count: f64,
+    pub author_count: f64,
+    /// Additions and deletions, empty when no deployment has a diff size
+    pub changed_lines: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct WeeklyBatchSize {
+    pub week: NaiveDate,
+    pub deployments: u32,
+    pub median: BatchSizeMedian,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BatchSizeSummary {
+    pub median: BatchSizeMedian,
+    /// The weekly medians in date order, to show whether batches are getting smaller
+    pub weekly: Vec<WeeklyBatchSize>,
+}
+
+/// The deployments to one GitHub deployment environment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct EnvironmentSummary {
+    pub environment: String,
+    pub total_deployments: u32,
+    pub deployment_frequency_per_day: f32,
+    pub lead_time_for_changes: DeploymentLeadTimeForChanges,
+}
+
+/// What was left out because of its author or its paths
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ExcludedCounts {
+    pub deployments: u32,
+    /// Commits that were not picked as the first commit of a deployment
+    pub commits: u32,
+    /// Deployments whose commits touch none of the project's `paths`
+    pub deployments_outside_paths: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Context {
@@ -123,6 +235,7 @@ pub struct DeploymentFrequencyPerformance {
 pub struct DeploymentPerformance {
     pub deployment_frequency: DeploymentFrequencyPerformance,
     pub lead_time_for_changes: DeploymentLeadTimeForChanges,
+    pub lead_time_stages: LeadTimeStagesMedian,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -131,6 +244,12 @@ pub struct FourKeysResult {
     pub deployments: Vec<DailyDeploymentsSummary>,
     pub context: Context,
     pub performance: DeploymentPerformance,
+    pub batch_size: BatchSizeSummary,
+    /// One per GitHub deployment environment in name order, empty for other deployment sources
+    pub environments: Vec<EnvironmentSummary>,
+    pub excluded: ExcludedCounts,
+    /// Deployments that were skipped or whose lead time is degraded
+    pub warnings: Vec<DataQualityWarning>,
 }
 
 // Events
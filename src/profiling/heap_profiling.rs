@@ -1,9 +1,14 @@
 #[cfg(test)]
 mod profiling {
     use crate::{
-        common_types::date_time_range::DateTimeRange,
+        common_types::{
+            author_exclusion::AuthorExclusion, date_time_range::DateTimeRange,
+            lead_time_fallback::LeadTimeFallback, lead_time_start::LeadTimeStart,
+            path_filter::PathFilter,
+        },
         dependencies::{
             deployments_fetcher::mock::DeploymentsFetcherWithMock,
+            pull_requests_fetcher::mock::PullRequestsFetcherWithMock,
             two_commits_comparer::mock::TwoCommitsComparerWithMock,
         },
         metrics_retrieving::retrieve_four_keys::{
@@ -37,6 +42,11 @@ mod profiling {
                     name: "project".to_string(),
                     developer_count: 2,
                     working_days_per_week: 2.5,
+                    lead_time_start: LeadTimeStart::default(),
+                    lead_time_fallback: LeadTimeFallback::default(),
+                    lead_time_per_pull_request: false,
+                    author_exclusion: AuthorExclusion::default(),
+                    path_filter: PathFilter::default(),
                 },
             };
             let deployments_fetcher = DeploymentsFetcherWithMock {
@@ -78,9 +88,11 @@ mod profiling {
             let two_commits_comparer = TwoCommitsComparerWithMock {
                 commits: vec![build_commit("2023-01-02 10:00:00")],
             };
+            let pull_requests_fetcher = PullRequestsFetcherWithMock::default();
             let workflow = RetrieveFourKeysWorkflow {
                 deployments_fetcher,
                 two_commits_comparer,
+                pull_requests_fetcher,
             };
             workflow
                 .retrieve_four_keys(context)
@@ -4,8 +4,11 @@ use crate::dependencies::project_config_io::writer::interface::ProjectConfigIOWr
 
 use super::{
     create_project_internal_types::{
-        CreateEvents, CreateGithubDeploymentProject, CreateGithubPullRequestProject,
-        CreateHerokuProject, CreateProjectStep,
+        CreateArgoCdApplicationProject, CreateAwsCodeDeployProject, CreateEvents,
+        CreateGithubDeploymentProject, CreateGithubPullRequestProject, CreateHerokuPipelineProject,
+        CreateHerokuProject, CreateJenkinsBuildProject, CreateKubernetesRolloutProject,
+        CreateNetlifyDeployProject, CreateProjectStep, CreateRenderDeployProject,
+        CreateVercelDeploymentProject,
     },
     create_project_public_types::*,
     dto::ProjectConfigDto,
@@ -18,7 +21,8 @@ const create_github_deployment_project: CreateGithubDeploymentProject =
     |uncreated_project: UncreatedGitHubDeploymentProject| -> GitHubDeploymentProjectCreated {
         GitHubDeploymentProjectCreated {
             project_name: uncreated_project.project_name,
-            github_personal_token: uncreated_project.github_personal_token,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
             github_owner_repo: uncreated_project.github_owner_repo,
             github_deployment_environment: uncreated_project.github_deployment_environment,
             developer_count: uncreated_project.developer_count,
@@ -30,7 +34,8 @@ const create_github_pull_request_project: CreateGithubPullRequestProject =
     |uncreated_project: UncreatedGitHubPullRequestProject| -> GitHubPullRequestProjectCreated {
         GitHubPullRequestProjectCreated {
             project_name: uncreated_project.project_name,
-            github_personal_token: uncreated_project.github_personal_token,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
             github_owner_repo: uncreated_project.github_owner_repo,
             github_deploy_branch_name: uncreated_project.github_deploy_branch_name,
             developer_count: uncreated_project.developer_count,
@@ -42,7 +47,8 @@ const create_heroku_project: CreateHerokuProject =
     |uncreated_project: UncreatedHerokuReleaseProject| -> HerokuReleaseProjectCreated {
         HerokuReleaseProjectCreated {
             project_name: uncreated_project.project_name,
-            github_personal_token: uncreated_project.github_personal_token,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
             github_owner_repo: uncreated_project.github_owner_repo,
             heroku_app_name: uncreated_project.heroku_app_name,
             heroku_auth_token: uncreated_project.heroku_auth_token,
@@ -51,6 +57,132 @@ const create_heroku_project: CreateHerokuProject =
         }
     };
 
+const create_heroku_pipeline_project: CreateHerokuPipelineProject =
+    |uncreated_project: UncreatedHerokuPipelineProject| -> HerokuPipelineProjectCreated {
+        HerokuPipelineProjectCreated {
+            project_name: uncreated_project.project_name,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
+            github_owner_repo: uncreated_project.github_owner_repo,
+            heroku_pipeline_name: uncreated_project.heroku_pipeline_name,
+            heroku_auth_token: uncreated_project.heroku_auth_token,
+            developer_count: uncreated_project.developer_count,
+            working_days_per_week: uncreated_project.working_days_per_week,
+        }
+    };
+
+const create_argo_cd_application_project: CreateArgoCdApplicationProject =
+    |uncreated_project: UncreatedArgoCdApplicationProject| -> ArgoCdApplicationProjectCreated {
+        ArgoCdApplicationProjectCreated {
+            project_name: uncreated_project.project_name,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
+            github_owner_repo: uncreated_project.github_owner_repo,
+            argo_cd_base_url: uncreated_project.argo_cd_base_url,
+            argo_cd_auth_token: uncreated_project.argo_cd_auth_token,
+            argo_cd_application_name: uncreated_project.argo_cd_application_name,
+            developer_count: uncreated_project.developer_count,
+            working_days_per_week: uncreated_project.working_days_per_week,
+        }
+    };
+
+const create_kubernetes_rollout_project: CreateKubernetesRolloutProject =
+    |uncreated_project: UncreatedKubernetesRolloutProject| -> KubernetesRolloutProjectCreated {
+        KubernetesRolloutProjectCreated {
+            project_name: uncreated_project.project_name,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
+            github_owner_repo: uncreated_project.github_owner_repo,
+            kubeconfig_path: uncreated_project.kubeconfig_path,
+            kubernetes_context: uncreated_project.kubernetes_context,
+            kubernetes_namespace: uncreated_project.kubernetes_namespace,
+            kubernetes_deployment_name: uncreated_project.kubernetes_deployment_name,
+            kubernetes_commit_annotation: uncreated_project.kubernetes_commit_annotation,
+            developer_count: uncreated_project.developer_count,
+            working_days_per_week: uncreated_project.working_days_per_week,
+        }
+    };
+
+const create_vercel_deployment_project: CreateVercelDeploymentProject =
+    |uncreated_project: UncreatedVercelDeploymentProject| -> VercelDeploymentProjectCreated {
+        VercelDeploymentProjectCreated {
+            project_name: uncreated_project.project_name,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
+            github_owner_repo: uncreated_project.github_owner_repo,
+            vercel_api_base_url: uncreated_project.vercel_api_base_url,
+            vercel_auth_token: uncreated_project.vercel_auth_token,
+            vercel_project_id: uncreated_project.vercel_project_id,
+            vercel_team_id: uncreated_project.vercel_team_id,
+            developer_count: uncreated_project.developer_count,
+            working_days_per_week: uncreated_project.working_days_per_week,
+        }
+    };
+
+const create_netlify_deploy_project: CreateNetlifyDeployProject =
+    |uncreated_project: UncreatedNetlifyDeployProject| -> NetlifyDeployProjectCreated {
+        NetlifyDeployProjectCreated {
+            project_name: uncreated_project.project_name,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
+            github_owner_repo: uncreated_project.github_owner_repo,
+            netlify_api_base_url: uncreated_project.netlify_api_base_url,
+            netlify_auth_token: uncreated_project.netlify_auth_token,
+            netlify_site_id: uncreated_project.netlify_site_id,
+            developer_count: uncreated_project.developer_count,
+            working_days_per_week: uncreated_project.working_days_per_week,
+        }
+    };
+
+const create_render_deploy_project: CreateRenderDeployProject =
+    |uncreated_project: UncreatedRenderDeployProject| -> RenderDeployProjectCreated {
+        RenderDeployProjectCreated {
+            project_name: uncreated_project.project_name,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
+            github_owner_repo: uncreated_project.github_owner_repo,
+            render_api_base_url: uncreated_project.render_api_base_url,
+            render_api_key: uncreated_project.render_api_key,
+            render_service_id: uncreated_project.render_service_id,
+            developer_count: uncreated_project.developer_count,
+            working_days_per_week: uncreated_project.working_days_per_week,
+        }
+    };
+
+const create_aws_code_deploy_project: CreateAwsCodeDeployProject =
+    |uncreated_project: UncreatedAwsCodeDeployProject| -> AwsCodeDeployProjectCreated {
+        AwsCodeDeployProjectCreated {
+            project_name: uncreated_project.project_name,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
+            github_owner_repo: uncreated_project.github_owner_repo,
+            aws_endpoint_url: uncreated_project.aws_endpoint_url,
+            aws_region: uncreated_project.aws_region,
+            aws_code_deploy_application_name: uncreated_project.aws_code_deploy_application_name,
+            aws_code_deploy_deployment_group_name: uncreated_project
+                .aws_code_deploy_deployment_group_name,
+            aws_ecs_commit_tag: uncreated_project.aws_ecs_commit_tag,
+            developer_count: uncreated_project.developer_count,
+            working_days_per_week: uncreated_project.working_days_per_week,
+        }
+    };
+
+const create_jenkins_build_project: CreateJenkinsBuildProject =
+    |uncreated_project: UncreatedJenkinsBuildProject| -> JenkinsBuildProjectCreated {
+        JenkinsBuildProjectCreated {
+            project_name: uncreated_project.project_name,
+            github_credentials: uncreated_project.github_credentials,
+            github_api_base_url: uncreated_project.github_api_base_url,
+            github_owner_repo: uncreated_project.github_owner_repo,
+            jenkins_base_url: uncreated_project.jenkins_base_url,
+            jenkins_job_name: uncreated_project.jenkins_job_name,
+            jenkins_user_name: uncreated_project.jenkins_user_name,
+            jenkins_api_token: uncreated_project.jenkins_api_token,
+            developer_count: uncreated_project.developer_count,
+            working_days_per_week: uncreated_project.working_days_per_week,
+        }
+    };
+
 struct CreateProjectStepImpl<T: ProjectConfigIOWriter> {
     project_io_writer: T,
 }
@@ -73,6 +205,38 @@ impl<T: ProjectConfigIOWriter + Sync + Send> CreateProjectStep for CreateProject
                 let project = create_heroku_project(uncreated_project);
                 ProjectCreated::HerokuRelease(project)
             }
+            UncreatedProject::HerokuPipeline(uncreated_project) => {
+                let project = create_heroku_pipeline_project(uncreated_project);
+                ProjectCreated::HerokuPipeline(project)
+            }
+            UncreatedProject::ArgoCdApplication(uncreated_project) => {
+                let project = create_argo_cd_application_project(uncreated_project);
+                ProjectCreated::ArgoCdApplication(project)
+            }
+            UncreatedProject::KubernetesRollout(uncreated_project) => {
+                let project = create_kubernetes_rollout_project(uncreated_project);
+                ProjectCreated::KubernetesRollout(project)
+            }
+            UncreatedProject::VercelDeployment(uncreated_project) => {
+                let project = create_vercel_deployment_project(uncreated_project);
+                ProjectCreated::VercelDeployment(project)
+            }
+            UncreatedProject::NetlifyDeploy(uncreated_project) => {
+                let project = create_netlify_deploy_project(uncreated_project);
+                ProjectCreated::NetlifyDeploy(project)
+            }
+            UncreatedProject::RenderDeploy(uncreated_project) => {
+                let project = create_render_deploy_project(uncreated_project);
+                ProjectCreated::RenderDeploy(project)
+            }
+            UncreatedProject::AwsCodeDeploy(uncreated_project) => {
+                let project = create_aws_code_deploy_project(uncreated_project);
+                ProjectCreated::AwsCodeDeploy(project)
+            }
+            UncreatedProject::JenkinsBuild(uncreated_project) => {
+                let project = create_jenkins_build_project(uncreated_project);
+                ProjectCreated::JenkinsBuild(project)
+            }
         };
 
         let project_dto: ProjectConfigDto = created_project.clone().into();
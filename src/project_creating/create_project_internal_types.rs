@@ -1,9 +1,15 @@
 use async_trait::async_trait;
 
 use super::create_project::{
+    ArgoCdApplicationProjectCreated, AwsCodeDeployProjectCreated,
     CreateGithubDeploymentProjectError, CreateProjectEvent, GitHubDeploymentProjectCreated,
-    GitHubPullRequestProjectCreated, HerokuReleaseProjectCreated, UncreatedGitHubDeploymentProject,
-    UncreatedGitHubPullRequestProject, UncreatedHerokuReleaseProject, UncreatedProject,
+    GitHubPullRequestProjectCreated, HerokuPipelineProjectCreated, HerokuReleaseProjectCreated,
+    JenkinsBuildProjectCreated, KubernetesRolloutProjectCreated, NetlifyDeployProjectCreated,
+    RenderDeployProjectCreated, UncreatedArgoCdApplicationProject, UncreatedAwsCodeDeployProject,
+    UncreatedGitHubDeploymentProject, UncreatedGitHubPullRequestProject,
+    UncreatedHerokuPipelineProject, UncreatedHerokuReleaseProject, UncreatedJenkinsBuildProject,
+    UncreatedKubernetesRolloutProject, UncreatedNetlifyDeployProject, UncreatedProject,
+    UncreatedRenderDeployProject, UncreatedVercelDeploymentProject, VercelDeploymentProjectCreated,
 };
 
 // ---------------------------
@@ -18,6 +24,30 @@ pub(super) type CreateGithubPullRequestProject =
 pub(super) type CreateHerokuProject =
     fn(uncreated_project: UncreatedHerokuReleaseProject) -> HerokuReleaseProjectCreated;
 
+pub(super) type CreateHerokuPipelineProject =
+    fn(uncreated_project: UncreatedHerokuPipelineProject) -> HerokuPipelineProjectCreated;
+
+pub(super) type CreateArgoCdApplicationProject =
+    fn(uncreated_project: UncreatedArgoCdApplicationProject) -> ArgoCdApplicationProjectCreated;
+
+pub(super) type CreateKubernetesRolloutProject =
+    fn(uncreated_project: UncreatedKubernetesRolloutProject) -> KubernetesRolloutProjectCreated;
+
+pub(super) type CreateVercelDeploymentProject =
+    fn(uncreated_project: UncreatedVercelDeploymentProject) -> VercelDeploymentProjectCreated;
+
+pub(super) type CreateNetlifyDeployProject =
+    fn(uncreated_project: UncreatedNetlifyDeployProject) -> NetlifyDeployProjectCreated;
+
+pub(super) type CreateRenderDeployProject =
+    fn(uncreated_project: UncreatedRenderDeployProject) -> RenderDeployProjectCreated;
+
+pub(super) type CreateAwsCodeDeployProject =
+    fn(uncreated_project: UncreatedAwsCodeDeployProject) -> AwsCodeDeployProjectCreated;
+
+pub(super) type CreateJenkinsBuildProject =
+    fn(uncreated_project: UncreatedJenkinsBuildProject) -> JenkinsBuildProjectCreated;
+
 #[async_trait]
 pub(super) trait CreateProjectStep {
     async fn create_project(
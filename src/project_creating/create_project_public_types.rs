@@ -7,11 +7,23 @@ use thiserror::Error;
 
 use crate::{
     common_types::{
-        deploy_branch_name::ValidatedDeployBranchName, developer_count::ValidatedDeveloperCount,
+        argo_cd_application_name::ValidatedArgoCdApplicationName,
+        argo_cd_auth_token::ValidatedArgoCdAuthToken, argo_cd_base_url::ValidatedArgoCdBaseUrl,
+        aws_code_deploy_application_name::ValidatedAwsCodeDeployApplicationName,
+        aws_code_deploy_deployment_group_name::ValidatedAwsCodeDeployDeploymentGroupName,
+        aws_region::ValidatedAwsRegion, deploy_branch_name::ValidatedDeployBranchName,
+        developer_count::ValidatedDeveloperCount, github_credentials::GitHubCredentials,
         github_deployment_environment::ValidatedGitHubDeploymentEnvironment,
-        github_owner_repo::ValidatedGitHubOwnerRepo,
-        github_personal_token::ValidatedGitHubPersonalToken,
-        heroku_app_name::ValidatedHerokuAppName, heroku_auth_token::ValidatedHerokuAuthToken,
+        github_owner_repo::ValidatedGitHubOwnerRepo, heroku_app_name::ValidatedHerokuAppName,
+        heroku_auth_token::ValidatedHerokuAuthToken,
+        heroku_pipeline_name::ValidatedHerokuPipelineName,
+        jenkins_api_token::ValidatedJenkinsApiToken, jenkins_base_url::ValidatedJenkinsBaseUrl,
+        jenkins_job_name::ValidatedJenkinsJobName, jenkins_user_name::ValidatedJenkinsUserName,
+        kubernetes_deployment_name::ValidatedKubernetesDeploymentName,
+        kubernetes_namespace::ValidatedKubernetesNamespace,
+        netlify_auth_token::ValidatedNetlifyAuthToken, netlify_site_id::ValidatedNetlifySiteId,
+        render_api_key::ValidatedRenderApiKey, render_service_id::ValidatedRenderServiceId,
+        vercel_auth_token::ValidatedVercelAuthToken, vercel_project_id::ValidatedVercelProjectId,
         working_days_per_week::ValidatedWorkingDaysPerWeek,
     },
     dependencies::project_config_io::writer::interface::ProjectConfigIOWriterError,
@@ -23,7 +35,8 @@ use crate::{
 // Project configs
 pub struct UncreatedGitHubDeploymentProject {
     pub project_name: String,
-    pub github_personal_token: ValidatedGitHubPersonalToken,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
     pub github_owner_repo: ValidatedGitHubOwnerRepo,
     pub github_deployment_environment: ValidatedGitHubDeploymentEnvironment,
     pub developer_count: ValidatedDeveloperCount,
@@ -31,7 +44,8 @@ pub struct UncreatedGitHubDeploymentProject {
 }
 pub struct UncreatedGitHubPullRequestProject {
     pub project_name: String,
-    pub github_personal_token: ValidatedGitHubPersonalToken,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
     pub github_owner_repo: ValidatedGitHubOwnerRepo,
     pub github_deploy_branch_name: ValidatedDeployBranchName,
     pub developer_count: ValidatedDeveloperCount,
@@ -39,17 +53,119 @@ pub struct UncreatedGitHubPullRequestProject {
 }
 pub struct UncreatedHerokuReleaseProject {
     pub project_name: String,
-    pub github_personal_token: ValidatedGitHubPersonalToken,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
     pub github_owner_repo: ValidatedGitHubOwnerRepo,
     pub heroku_app_name: ValidatedHerokuAppName,
     pub heroku_auth_token: ValidatedHerokuAuthToken,
     pub developer_count: ValidatedDeveloperCount,
     pub working_days_per_week: ValidatedWorkingDaysPerWeek,
 }
+pub struct UncreatedHerokuPipelineProject {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub heroku_pipeline_name: ValidatedHerokuPipelineName,
+    pub heroku_auth_token: ValidatedHerokuAuthToken,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+pub struct UncreatedArgoCdApplicationProject {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub argo_cd_base_url: ValidatedArgoCdBaseUrl,
+    pub argo_cd_auth_token: ValidatedArgoCdAuthToken,
+    pub argo_cd_application_name: ValidatedArgoCdApplicationName,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+pub struct UncreatedKubernetesRolloutProject {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub kubeconfig_path: Option<String>,
+    pub kubernetes_context: Option<String>,
+    pub kubernetes_namespace: ValidatedKubernetesNamespace,
+    pub kubernetes_deployment_name: ValidatedKubernetesDeploymentName,
+    pub kubernetes_commit_annotation: Option<String>,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+pub struct UncreatedVercelDeploymentProject {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub vercel_api_base_url: Option<String>,
+    pub vercel_auth_token: ValidatedVercelAuthToken,
+    pub vercel_project_id: ValidatedVercelProjectId,
+    pub vercel_team_id: Option<String>,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+pub struct UncreatedNetlifyDeployProject {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub netlify_api_base_url: Option<String>,
+    pub netlify_auth_token: ValidatedNetlifyAuthToken,
+    pub netlify_site_id: ValidatedNetlifySiteId,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+pub struct UncreatedRenderDeployProject {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub render_api_base_url: Option<String>,
+    pub render_api_key: ValidatedRenderApiKey,
+    pub render_service_id: ValidatedRenderServiceId,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+pub struct UncreatedAwsCodeDeployProject {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub aws_endpoint_url: Option<String>,
+    pub aws_region: ValidatedAwsRegion,
+    pub aws_code_deploy_application_name: ValidatedAwsCodeDeployApplicationName,
+    pub aws_code_deploy_deployment_group_name: ValidatedAwsCodeDeployDeploymentGroupName,
+    pub aws_ecs_commit_tag: Option<String>,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+pub struct UncreatedJenkinsBuildProject {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub jenkins_base_url: ValidatedJenkinsBaseUrl,
+    pub jenkins_job_name: ValidatedJenkinsJobName,
+    pub jenkins_user_name: ValidatedJenkinsUserName,
+    pub jenkins_api_token: ValidatedJenkinsApiToken,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
 pub enum UncreatedProject {
     GitHubDeployment(UncreatedGitHubDeploymentProject),
     GitHubPullRequest(UncreatedGitHubPullRequestProject),
     HerokuRelease(UncreatedHerokuReleaseProject),
+    HerokuPipeline(UncreatedHerokuPipelineProject),
+    ArgoCdApplication(UncreatedArgoCdApplicationProject),
+    KubernetesRollout(UncreatedKubernetesRolloutProject),
+    VercelDeployment(UncreatedVercelDeploymentProject),
+    NetlifyDeploy(UncreatedNetlifyDeployProject),
+    RenderDeploy(UncreatedRenderDeployProject),
+    AwsCodeDeploy(UncreatedAwsCodeDeployProject),
+    JenkinsBuild(UncreatedJenkinsBuildProject),
 }
 
 // ------------------------------------
@@ -57,7 +173,8 @@ pub enum UncreatedProject {
 #[derive(Clone)]
 pub struct GitHubDeploymentProjectCreated {
     pub project_name: String,
-    pub github_personal_token: ValidatedGitHubPersonalToken,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
     pub github_owner_repo: ValidatedGitHubOwnerRepo,
     pub github_deployment_environment: ValidatedGitHubDeploymentEnvironment,
     pub developer_count: ValidatedDeveloperCount,
@@ -67,7 +184,8 @@ pub struct GitHubDeploymentProjectCreated {
 #[derive(Clone)]
 pub struct GitHubPullRequestProjectCreated {
     pub project_name: String,
-    pub github_personal_token: ValidatedGitHubPersonalToken,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
     pub github_owner_repo: ValidatedGitHubOwnerRepo,
     pub github_deploy_branch_name: ValidatedDeployBranchName,
     pub developer_count: ValidatedDeveloperCount,
@@ -77,7 +195,8 @@ pub struct GitHubPullRequestProjectCreated {
 #[derive(Clone)]
 pub struct HerokuReleaseProjectCreated {
     pub project_name: String,
-    pub github_personal_token: ValidatedGitHubPersonalToken,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
     pub github_owner_repo: ValidatedGitHubOwnerRepo,
     pub heroku_app_name: ValidatedHerokuAppName,
     pub heroku_auth_token: ValidatedHerokuAuthToken,
@@ -85,11 +204,203 @@ pub struct HerokuReleaseProjectCreated {
     pub working_days_per_week: ValidatedWorkingDaysPerWeek,
 }
 
+#[derive(Clone)]
+pub struct HerokuPipelineProjectCreated {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub heroku_pipeline_name: ValidatedHerokuPipelineName,
+    pub heroku_auth_token: ValidatedHerokuAuthToken,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+
+#[derive(Clone)]
+pub struct ArgoCdApplicationProjectCreated {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub argo_cd_base_url: ValidatedArgoCdBaseUrl,
+    pub argo_cd_auth_token: ValidatedArgoCdAuthToken,
+    pub argo_cd_application_name: ValidatedArgoCdApplicationName,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+
+#[derive(Clone)]
+pub struct KubernetesRolloutProjectCreated {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub kubeconfig_path: Option<String>,
+    pub kubernetes_context: Option<String>,
+    pub kubernetes_namespace: ValidatedKubernetesNamespace,
+    pub kubernetes_deployment_name: ValidatedKubernetesDeploymentName,
+    pub kubernetes_commit_annotation: Option<String>,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+
+#[derive(Clone)]
+pub struct VercelDeploymentProjectCreated {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub vercel_api_base_url: Option<String>,
+    pub vercel_auth_token: ValidatedVercelAuthToken,
+    pub vercel_project_id: ValidatedVercelProjectId,
+    pub vercel_team_id: Option<String>,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+
+#[derive(Clone)]
+pub struct NetlifyDeployProjectCreated {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub netlify_api_base_url: Option<String>,
+    pub netlify_auth_token: ValidatedNetlifyAuthToken,
+    pub netlify_site_id: ValidatedNetlifySiteId,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+
+#[derive(Clone)]
+pub struct RenderDeployProjectCreated {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub render_api_base_url: Option<String>,
+    pub render_api_key: ValidatedRenderApiKey,
+    pub render_service_id: ValidatedRenderServiceId,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+
+#[derive(Clone)]
+pub struct AwsCodeDeployProjectCreated {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub aws_endpoint_url: Option<String>,
+    pub aws_region: ValidatedAwsRegion,
+    pub aws_code_deploy_application_name: ValidatedAwsCodeDeployApplicationName,
+    pub aws_code_deploy_deployment_group_name: ValidatedAwsCodeDeployDeploymentGroupName,
+    pub aws_ecs_commit_tag: Option<String>,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+
+#[derive(Clone)]
+pub struct JenkinsBuildProjectCreated {
+    pub project_name: String,
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+    pub jenkins_base_url: ValidatedJenkinsBaseUrl,
+    pub jenkins_job_name: ValidatedJenkinsJobName,
+    pub jenkins_user_name: ValidatedJenkinsUserName,
+    pub jenkins_api_token: ValidatedJenkinsApiToken,
+    pub developer_count: ValidatedDeveloperCount,
+    pub working_days_per_week: ValidatedWorkingDaysPerWeek,
+}
+
 #[derive(Clone)]
 pub enum ProjectCreated {
     GitHubDeployment(GitHubDeploymentProjectCreated),
     GitHubPullRequest(GitHubPullRequestProjectCreated),
     HerokuRelease(HerokuReleaseProjectCreated),
+    HerokuPipeline(HerokuPipelineProjectCreated),
+    ArgoCdApplication(ArgoCdApplicationProjectCreated),
+    KubernetesRollout(KubernetesRolloutProjectCreated),
+    VercelDeployment(VercelDeploymentProjectCreated),
+    NetlifyDeploy(NetlifyDeployProjectCreated),
+    RenderDeploy(RenderDeployProjectCreated),
+    AwsCodeDeploy(AwsCodeDeployProjectCreated),
+    JenkinsBuild(JenkinsBuildProjectCreated),
+}
+
+/// The GitHub repository a project reads its commits and pull requests from
+#[derive(Clone)]
+pub struct GitHubRepositoryCreated {
+    pub github_credentials: GitHubCredentials,
+    pub github_api_base_url: Option<String>,
+    pub github_owner_repo: ValidatedGitHubOwnerRepo,
+}
+
+impl ProjectCreated {
+    pub fn github_repository(&self) -> GitHubRepositoryCreated {
+        let (github_credentials, github_api_base_url, github_owner_repo) = match self {
+            ProjectCreated::GitHubDeployment(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+            ProjectCreated::GitHubPullRequest(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+            ProjectCreated::HerokuRelease(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+            ProjectCreated::HerokuPipeline(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+            ProjectCreated::ArgoCdApplication(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+            ProjectCreated::KubernetesRollout(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+            ProjectCreated::VercelDeployment(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+            ProjectCreated::NetlifyDeploy(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+            ProjectCreated::RenderDeploy(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+            ProjectCreated::AwsCodeDeploy(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+            ProjectCreated::JenkinsBuild(config) => (
+                &config.github_credentials,
+                &config.github_api_base_url,
+                &config.github_owner_repo,
+            ),
+        };
+        GitHubRepositoryCreated {
+            github_credentials: github_credentials.clone(),
+            github_api_base_url: github_api_base_url.clone(),
+            github_owner_repo: github_owner_repo.clone(),
+        }
+    }
 }
 
 // Events
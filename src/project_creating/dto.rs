@@ -2,45 +2,125 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::common_types::{
+    argo_cd_application_name::{
+        ValidateArgoCdApplicationNameError, ValidatedArgoCdApplicationName,
+    },
+    argo_cd_auth_token::{ValidateArgoCdAuthTokenError, ValidatedArgoCdAuthToken},
+    argo_cd_base_url::{ValidateArgoCdBaseUrlError, ValidatedArgoCdBaseUrl},
+    aws_code_deploy_application_name::{
+        ValidateAwsCodeDeployApplicationNameError, ValidatedAwsCodeDeployApplicationName,
+    },
+    aws_code_deploy_deployment_group_name::{
+        ValidateAwsCodeDeployDeploymentGroupNameError, ValidatedAwsCodeDeployDeploymentGroupName,
+    },
+    aws_region::{ValidateAwsRegionError, ValidatedAwsRegion},
     deploy_branch_name::{ValidateDeployBranchNameError, ValidatedDeployBranchName},
     deployment_source::DeploymentSource,
     developer_count::{ValidateDeveloperCountError, ValidatedDeveloperCount},
+    github_app::{ValidateGitHubAppError, ValidatedGitHubApp},
+    github_credentials::GitHubCredentials,
     github_deployment_environment::{
-        ValidateGitHubDeploymentEnvironmentError, ValidatedGitHubDeploymentEnvironment,
+        GitHubDeploymentEnvironmentMerge, ValidateGitHubDeploymentEnvironmentError,
+        ValidatedGitHubDeploymentEnvironment,
     },
     github_owner_repo::{ValidateGitHubOwnerRepoError, ValidatedGitHubOwnerRepo},
     github_personal_token::{ValidateGitHubPersonalTokenError, ValidatedGitHubPersonalToken},
     heroku_app_name::{ValidateHerokuAppNameError, ValidatedHerokuAppName},
     heroku_auth_token::{ValidateHerokuAuthTokenError, ValidatedHerokuAuthToken},
+    heroku_pipeline_name::{ValidateHerokuPipelineNameError, ValidatedHerokuPipelineName},
+    jenkins_api_token::{ValidateJenkinsApiTokenError, ValidatedJenkinsApiToken},
+    jenkins_base_url::{ValidateJenkinsBaseUrlError, ValidatedJenkinsBaseUrl},
+    jenkins_job_name::{ValidateJenkinsJobNameError, ValidatedJenkinsJobName},
+    jenkins_user_name::{ValidateJenkinsUserNameError, ValidatedJenkinsUserName},
+    kubernetes_deployment_name::{
+        ValidateKubernetesDeploymentNameError, ValidatedKubernetesDeploymentName,
+    },
+    kubernetes_namespace::{ValidateKubernetesNamespaceError, ValidatedKubernetesNamespace},
+    netlify_auth_token::{ValidateNetlifyAuthTokenError, ValidatedNetlifyAuthToken},
+    netlify_site_id::{ValidateNetlifySiteIdError, ValidatedNetlifySiteId},
+    render_api_key::{ValidateRenderApiKeyError, ValidatedRenderApiKey},
+    render_service_id::{ValidateRenderServiceIdError, ValidatedRenderServiceId},
+    vercel_auth_token::{ValidateVercelAuthTokenError, ValidatedVercelAuthToken},
+    vercel_project_id::{ValidateVercelProjectIdError, ValidatedVercelProjectId},
     working_days_per_week::{ValidateWorkingDaysPerWeekError, ValidatedWorkingDaysPerWeek},
 };
 
 use super::{
     create_project::GitHubPullRequestProjectCreated,
     create_project_public_types::{
-        GitHubDeploymentProjectCreated, HerokuReleaseProjectCreated, ProjectCreated,
+        ArgoCdApplicationProjectCreated, AwsCodeDeployProjectCreated,
+        GitHubDeploymentProjectCreated, HerokuPipelineProjectCreated, HerokuReleaseProjectCreated,
+        JenkinsBuildProjectCreated, KubernetesRolloutProjectCreated, NetlifyDeployProjectCreated,
+        ProjectCreated, RenderDeployProjectCreated, VercelDeploymentProjectCreated,
     },
 };
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectConfigDto {
     pub project_name: String,
     pub github_personal_token: String,
+    pub github_app: Option<GitHubAppDto>,
+    pub github_api_base_url: Option<String>,
     pub github_owner: String,
     pub github_repo: String,
     pub github_deployment_branch_name: Option<String>,
-    pub github_deployment_environment: Option<String>,
+    pub github_deployment_environment: Option<Vec<String>>,
+    pub github_deployment_environment_merge: Option<String>,
     pub heroku_app_name: Option<String>,
     pub heroku_auth_token: Option<String>,
+    pub heroku_pipeline_name: Option<String>,
+    pub argo_cd_base_url: Option<String>,
+    pub argo_cd_auth_token: Option<String>,
+    pub argo_cd_application_name: Option<String>,
+    pub kubeconfig_path: Option<String>,
+    pub kubernetes_context: Option<String>,
+    pub kubernetes_namespace: Option<String>,
+    pub kubernetes_deployment_name: Option<String>,
+    pub kubernetes_commit_annotation: Option<String>,
+    pub vercel_api_base_url: Option<String>,
+    pub vercel_auth_token: Option<String>,
+    pub vercel_project_id: Option<String>,
+    pub vercel_team_id: Option<String>,
+    pub netlify_api_base_url: Option<String>,
+    pub netlify_auth_token: Option<String>,
+    pub netlify_site_id: Option<String>,
+    pub render_api_base_url: Option<String>,
+    pub render_api_key: Option<String>,
+    pub render_service_id: Option<String>,
+    pub aws_endpoint_url: Option<String>,
+    pub aws_region: Option<String>,
+    pub aws_code_deploy_application_name: Option<String>,
+    pub aws_code_deploy_deployment_group_name: Option<String>,
+    pub aws_ecs_commit_tag: Option<String>,
+    pub jenkins_base_url: Option<String>,
+    pub jenkins_job_name: Option<String>,
+    pub jenkins_user_name: Option<String>,
+    pub jenkins_api_token: Option<String>,
     pub developer_count: u32,
     pub working_days_per_week: f32,
     pub deployment_source: String,
+    pub lead_time_start: Option<String>,
+    pub lead_time_fallback: Option<String>,
+    pub lead_time_fallback_cap_days: Option<u32>,
+    pub lead_time_per_pull_request: Option<bool>,
+    pub excluded_authors: Option<Vec<String>>,
+    pub exclude_default_bots: Option<bool>,
+    pub paths: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GitHubAppDto {
+    pub app_id: u64,
+    pub private_key_path: String,
+    pub installation_id: u64,
 }
 
 #[derive(Error, Debug)]
 pub enum CreateProjectDtoError {
     #[error("GitHub personal token is invalid")]
     GitHubPersonalToken(#[from] ValidateGitHubPersonalTokenError),
+    #[error("GitHub App is invalid")]
+    GitHubApp(#[from] ValidateGitHubAppError),
     #[error("GitHub owner/repo is invalid")]
     GitHubOwnerRepo(#[from] ValidateGitHubOwnerRepoError),
     #[error("GitHub deployment environment is invalid")]
@@ -55,25 +135,97 @@ pub enum CreateProjectDtoError {
     HerokuAuthToken(#[from] ValidateHerokuAuthTokenError),
     #[error("GitHub owner/repo is invalid")]
     HerokuAppName(#[from] ValidateHerokuAppNameError),
+    #[error("Heroku pipeline name is invalid")]
+    HerokuPipelineName(#[from] ValidateHerokuPipelineNameError),
+    #[error("Argo CD base url is invalid")]
+    ArgoCdBaseUrl(#[from] ValidateArgoCdBaseUrlError),
+    #[error("Argo CD auth token is invalid")]
+    ArgoCdAuthToken(#[from] ValidateArgoCdAuthTokenError),
+    #[error("Argo CD application name is invalid")]
+    ArgoCdApplicationName(#[from] ValidateArgoCdApplicationNameError),
+    #[error("Kubernetes namespace is invalid")]
+    KubernetesNamespace(#[from] ValidateKubernetesNamespaceError),
+    #[error("Kubernetes deployment name is invalid")]
+    KubernetesDeploymentName(#[from] ValidateKubernetesDeploymentNameError),
+    #[error("Vercel auth token is invalid")]
+    VercelAuthToken(#[from] ValidateVercelAuthTokenError),
+    #[error("Vercel project id is invalid")]
+    VercelProjectId(#[from] ValidateVercelProjectIdError),
+    #[error("Netlify auth token is invalid")]
+    NetlifyAuthToken(#[from] ValidateNetlifyAuthTokenError),
+    #[error("Netlify site id is invalid")]
+    NetlifySiteId(#[from] ValidateNetlifySiteIdError),
+    #[error("Render API key is invalid")]
+    RenderApiKey(#[from] ValidateRenderApiKeyError),
+    #[error("Render service id is invalid")]
+    RenderServiceId(#[from] ValidateRenderServiceIdError),
+    #[error("AWS region is invalid")]
+    AwsRegion(#[from] ValidateAwsRegionError),
+    #[error("CodeDeploy application name is invalid")]
+    AwsCodeDeployApplicationName(#[from] ValidateAwsCodeDeployApplicationNameError),
+    #[error("CodeDeploy deployment group name is invalid")]
+    AwsCodeDeployDeploymentGroupName(#[from] ValidateAwsCodeDeployDeploymentGroupNameError),
+    #[error("Jenkins base url is invalid")]
+    JenkinsBaseUrl(#[from] ValidateJenkinsBaseUrlError),
+    #[error("Jenkins job name is invalid")]
+    JenkinsJobName(#[from] ValidateJenkinsJobNameError),
+    #[error("Jenkins user name is invalid")]
+    JenkinsUserName(#[from] ValidateJenkinsUserNameError),
+    #[error("Jenkins API token is invalid")]
+    JenkinsApiToken(#[from] ValidateJenkinsApiTokenError),
     #[error("Data source type is invalid")]
     InvalidDataSource(String),
 }
 
+fn to_github_credentials(
+    dto: &ProjectConfigDto,
+) -> Result<GitHubCredentials, CreateProjectDtoError> {
+    match &dto.github_app {
+        Some(github_app) => Ok(GitHubCredentials::App(ValidatedGitHubApp::new(
+            Some(github_app.app_id),
+            Some(github_app.private_key_path.clone()),
+            Some(github_app.installation_id),
+        )?)),
+        None => Ok(GitHubCredentials::PersonalToken(
+            ValidatedGitHubPersonalToken::new(Some(dto.github_personal_token.clone()))?,
+        )),
+    }
+}
+
+fn from_github_credentials(github_credentials: &GitHubCredentials) -> ProjectConfigDto {
+    match github_credentials {
+        GitHubCredentials::PersonalToken(github_personal_token) => ProjectConfigDto {
+            github_personal_token: github_personal_token.expose().to_string(),
+            ..Default::default()
+        },
+        GitHubCredentials::App(github_app) => ProjectConfigDto {
+            github_app: Some(GitHubAppDto {
+                app_id: github_app.get_app_id(),
+                private_key_path: github_app.get_private_key_path(),
+                installation_id: github_app.get_installation_id(),
+            }),
+            ..Default::default()
+        },
+    }
+}
+
 fn to_github_deployment_project_created(
     dto: &ProjectConfigDto,
 ) -> Result<GitHubDeploymentProjectCreated, CreateProjectDtoError> {
-    let github_personal_token =
-        ValidatedGitHubPersonalToken::new(Some(dto.github_personal_token.clone()))?;
+    let github_credentials = to_github_credentials(dto)?;
     let github_owner_repo =
         ValidatedGitHubOwnerRepo::new(format!("{}/{}", dto.github_owner, dto.github_repo))?;
-    let github_deployment_environment =
-        ValidatedGitHubDeploymentEnvironment::new(dto.github_deployment_environment.clone())?;
+    let github_deployment_environment = ValidatedGitHubDeploymentEnvironment::new(
+        dto.github_deployment_environment.clone(),
+        dto.github_deployment_environment_merge.clone(),
+    )?;
     let developer_count = ValidatedDeveloperCount::new(dto.developer_count.to_string())?;
     let working_days_per_week =
         ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
     Ok(GitHubDeploymentProjectCreated {
         project_name: dto.project_name.to_string(),
-        github_personal_token,
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
         github_owner_repo,
         github_deployment_environment,
         developer_count,
@@ -87,24 +239,30 @@ fn from_github_deployment_project_created(
     let (owner, repo) = domain_obj.github_owner_repo.get_values();
     ProjectConfigDto {
         project_name: domain_obj.project_name,
-        github_personal_token: domain_obj.github_personal_token.to_string(),
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
         heroku_auth_token: None,
         heroku_app_name: None,
         deployment_source: DeploymentSource::GitHubDeployment.value(),
         github_owner: owner,
         github_repo: repo,
-        github_deployment_environment: Some(domain_obj.github_deployment_environment.to_string()),
+        github_deployment_environment: Some(domain_obj.github_deployment_environment.names()),
+        // The default merge is left out of the config
+        github_deployment_environment_merge: match domain_obj.github_deployment_environment.merge()
+        {
+            GitHubDeploymentEnvironmentMerge::CountEach => None,
+            merge => Some(merge.value().to_string()),
+        },
         github_deployment_branch_name: None,
         developer_count: domain_obj.developer_count.to_u32(),
         working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
     }
 }
 
 fn to_github_pull_request_project_created(
     dto: &ProjectConfigDto,
 ) -> Result<GitHubPullRequestProjectCreated, CreateProjectDtoError> {
-    let github_personal_token =
-        ValidatedGitHubPersonalToken::new(Some(dto.github_personal_token.clone()))?;
+    let github_credentials = to_github_credentials(dto)?;
     let github_owner_repo =
         ValidatedGitHubOwnerRepo::new(format!("{}/{}", dto.github_owner, dto.github_repo))?;
     let github_deploy_branch_name =
@@ -114,7 +272,8 @@ fn to_github_pull_request_project_created(
         ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
     Ok(GitHubPullRequestProjectCreated {
         project_name: dto.project_name.to_string(),
-        github_personal_token,
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
         github_owner_repo,
         github_deploy_branch_name,
         developer_count,
@@ -128,7 +287,7 @@ fn from_github_pull_request_project_created(
     let (owner, repo) = domain_obj.github_owner_repo.get_values();
     ProjectConfigDto {
         project_name: domain_obj.project_name,
-        github_personal_token: domain_obj.github_personal_token.to_string(),
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
         heroku_auth_token: None,
         heroku_app_name: None,
         deployment_source: DeploymentSource::GitHubPullRequest.value(),
@@ -138,14 +297,14 @@ fn from_github_pull_request_project_created(
         github_deployment_branch_name: Some(domain_obj.github_deploy_branch_name.to_string()),
         developer_count: domain_obj.developer_count.to_u32(),
         working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
     }
 }
 
 fn to_heroku_release_project_created(
     dto: &ProjectConfigDto,
 ) -> Result<HerokuReleaseProjectCreated, CreateProjectDtoError> {
-    let github_personal_token =
-        ValidatedGitHubPersonalToken::new(Some(dto.github_personal_token.to_string()))?;
+    let github_credentials = to_github_credentials(dto)?;
     let heroku_app_name = ValidatedHerokuAppName::new(dto.heroku_app_name.clone())?;
     let heroku_auth_token = ValidatedHerokuAuthToken::new(dto.heroku_auth_token.clone())?;
     let github_owner_repo =
@@ -155,7 +314,8 @@ fn to_heroku_release_project_created(
         ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
     Ok(HerokuReleaseProjectCreated {
         project_name: dto.project_name.clone(),
-        github_personal_token,
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
         github_owner_repo,
         heroku_app_name,
         heroku_auth_token,
@@ -170,16 +330,380 @@ fn from_heroku_release_project_created(
     let (owner, repo) = domain_obj.github_owner_repo.get_values();
     ProjectConfigDto {
         project_name: domain_obj.project_name,
-        github_personal_token: domain_obj.github_personal_token.to_string(),
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
         github_owner: owner,
         github_repo: repo,
         github_deployment_environment: None,
         github_deployment_branch_name: None,
         heroku_app_name: Some(domain_obj.heroku_app_name.to_string()),
-        heroku_auth_token: Some(domain_obj.heroku_auth_token.to_string()),
+        heroku_auth_token: Some(domain_obj.heroku_auth_token.expose().to_string()),
         deployment_source: DeploymentSource::HerokuRelease.value(),
         developer_count: domain_obj.developer_count.to_u32(),
         working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
+    }
+}
+
+fn to_heroku_pipeline_project_created(
+    dto: &ProjectConfigDto,
+) -> Result<HerokuPipelineProjectCreated, CreateProjectDtoError> {
+    let github_credentials = to_github_credentials(dto)?;
+    let heroku_pipeline_name = ValidatedHerokuPipelineName::new(dto.heroku_pipeline_name.clone())?;
+    let heroku_auth_token = ValidatedHerokuAuthToken::new(dto.heroku_auth_token.clone())?;
+    let github_owner_repo =
+        ValidatedGitHubOwnerRepo::new(format!("{}/{}", dto.github_owner, dto.github_repo))?;
+    let developer_count = ValidatedDeveloperCount::new(dto.developer_count.to_string())?;
+    let working_days_per_week =
+        ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
+    Ok(HerokuPipelineProjectCreated {
+        project_name: dto.project_name.clone(),
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
+        github_owner_repo,
+        heroku_pipeline_name,
+        heroku_auth_token,
+        developer_count,
+        working_days_per_week,
+    })
+}
+
+fn from_heroku_pipeline_project_created(
+    domain_obj: HerokuPipelineProjectCreated,
+) -> ProjectConfigDto {
+    let (owner, repo) = domain_obj.github_owner_repo.get_values();
+    ProjectConfigDto {
+        project_name: domain_obj.project_name,
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
+        github_owner: owner,
+        github_repo: repo,
+        heroku_pipeline_name: Some(domain_obj.heroku_pipeline_name.to_string()),
+        heroku_auth_token: Some(domain_obj.heroku_auth_token.expose().to_string()),
+        deployment_source: DeploymentSource::HerokuPipeline.value(),
+        developer_count: domain_obj.developer_count.to_u32(),
+        working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
+    }
+}
+
+fn to_argo_cd_application_project_created(
+    dto: &ProjectConfigDto,
+) -> Result<ArgoCdApplicationProjectCreated, CreateProjectDtoError> {
+    let github_credentials = to_github_credentials(dto)?;
+    let argo_cd_base_url = ValidatedArgoCdBaseUrl::new(dto.argo_cd_base_url.clone())?;
+    let argo_cd_auth_token = ValidatedArgoCdAuthToken::new(dto.argo_cd_auth_token.clone())?;
+    let argo_cd_application_name =
+        ValidatedArgoCdApplicationName::new(dto.argo_cd_application_name.clone())?;
+    let github_owner_repo =
+        ValidatedGitHubOwnerRepo::new(format!("{}/{}", dto.github_owner, dto.github_repo))?;
+    let developer_count = ValidatedDeveloperCount::new(dto.developer_count.to_string())?;
+    let working_days_per_week =
+        ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
+    Ok(ArgoCdApplicationProjectCreated {
+        project_name: dto.project_name.clone(),
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
+        github_owner_repo,
+        argo_cd_base_url,
+        argo_cd_auth_token,
+        argo_cd_application_name,
+        developer_count,
+        working_days_per_week,
+    })
+}
+
+fn from_argo_cd_application_project_created(
+    domain_obj: ArgoCdApplicationProjectCreated,
+) -> ProjectConfigDto {
+    let (owner, repo) = domain_obj.github_owner_repo.get_values();
+    ProjectConfigDto {
+        project_name: domain_obj.project_name,
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
+        github_owner: owner,
+        github_repo: repo,
+        argo_cd_base_url: Some(domain_obj.argo_cd_base_url.to_string()),
+        argo_cd_auth_token: Some(domain_obj.argo_cd_auth_token.expose().to_string()),
+        argo_cd_application_name: Some(domain_obj.argo_cd_application_name.to_string()),
+        deployment_source: DeploymentSource::ArgoCdApplication.value(),
+        developer_count: domain_obj.developer_count.to_u32(),
+        working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
+    }
+}
+
+fn to_kubernetes_rollout_project_created(
+    dto: &ProjectConfigDto,
+) -> Result<KubernetesRolloutProjectCreated, CreateProjectDtoError> {
+    let github_credentials = to_github_credentials(dto)?;
+    let kubernetes_namespace = ValidatedKubernetesNamespace::new(dto.kubernetes_namespace.clone())?;
+    let kubernetes_deployment_name =
+        ValidatedKubernetesDeploymentName::new(dto.kubernetes_deployment_name.clone())?;
+    let github_owner_repo =
+        ValidatedGitHubOwnerRepo::new(format!("{}/{}", dto.github_owner, dto.github_repo))?;
+    let developer_count = ValidatedDeveloperCount::new(dto.developer_count.to_string())?;
+    let working_days_per_week =
+        ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
+    Ok(KubernetesRolloutProjectCreated {
+        project_name: dto.project_name.clone(),
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
+        github_owner_repo,
+        kubeconfig_path: dto.kubeconfig_path.clone(),
+        kubernetes_context: dto.kubernetes_context.clone(),
+        kubernetes_namespace,
+        kubernetes_deployment_name,
+        kubernetes_commit_annotation: dto.kubernetes_commit_annotation.clone(),
+        developer_count,
+        working_days_per_week,
+    })
+}
+
+fn from_kubernetes_rollout_project_created(
+    domain_obj: KubernetesRolloutProjectCreated,
+) -> ProjectConfigDto {
+    let (owner, repo) = domain_obj.github_owner_repo.get_values();
+    ProjectConfigDto {
+        project_name: domain_obj.project_name,
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
+        github_owner: owner,
+        github_repo: repo,
+        kubeconfig_path: domain_obj.kubeconfig_path,
+        kubernetes_context: domain_obj.kubernetes_context,
+        kubernetes_namespace: Some(domain_obj.kubernetes_namespace.to_string()),
+        kubernetes_deployment_name: Some(domain_obj.kubernetes_deployment_name.to_string()),
+        kubernetes_commit_annotation: domain_obj.kubernetes_commit_annotation,
+        deployment_source: DeploymentSource::KubernetesRollout.value(),
+        developer_count: domain_obj.developer_count.to_u32(),
+        working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
+    }
+}
+
+fn to_vercel_deployment_project_created(
+    dto: &ProjectConfigDto,
+) -> Result<VercelDeploymentProjectCreated, CreateProjectDtoError> {
+    let github_credentials = to_github_credentials(dto)?;
+    let vercel_auth_token = ValidatedVercelAuthToken::new(dto.vercel_auth_token.clone())?;
+    let vercel_project_id = ValidatedVercelProjectId::new(dto.vercel_project_id.clone())?;
+    let github_owner_repo =
+        ValidatedGitHubOwnerRepo::new(format!("{}/{}", dto.github_owner, dto.github_repo))?;
+    let developer_count = ValidatedDeveloperCount::new(dto.developer_count.to_string())?;
+    let working_days_per_week =
+        ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
+    Ok(VercelDeploymentProjectCreated {
+        project_name: dto.project_name.clone(),
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
+        github_owner_repo,
+        vercel_api_base_url: dto.vercel_api_base_url.clone(),
+        vercel_auth_token,
+        vercel_project_id,
+        vercel_team_id: dto.vercel_team_id.clone(),
+        developer_count,
+        working_days_per_week,
+    })
+}
+
+fn from_vercel_deployment_project_created(
+    domain_obj: VercelDeploymentProjectCreated,
+) -> ProjectConfigDto {
+    let (owner, repo) = domain_obj.github_owner_repo.get_values();
+    ProjectConfigDto {
+        project_name: domain_obj.project_name,
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
+        github_owner: owner,
+        github_repo: repo,
+        vercel_api_base_url: domain_obj.vercel_api_base_url,
+        vercel_auth_token: Some(domain_obj.vercel_auth_token.expose().to_string()),
+        vercel_project_id: Some(domain_obj.vercel_project_id.to_string()),
+        vercel_team_id: domain_obj.vercel_team_id,
+        deployment_source: DeploymentSource::VercelDeployment.value(),
+        developer_count: domain_obj.developer_count.to_u32(),
+        working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
+    }
+}
+
+fn to_netlify_deploy_project_created(
+    dto: &ProjectConfigDto,
+) -> Result<NetlifyDeployProjectCreated, CreateProjectDtoError> {
+    let github_credentials = to_github_credentials(dto)?;
+    let netlify_auth_token = ValidatedNetlifyAuthToken::new(dto.netlify_auth_token.clone())?;
+    let netlify_site_id = ValidatedNetlifySiteId::new(dto.netlify_site_id.clone())?;
+    let github_owner_repo =
+        ValidatedGitHubOwnerRepo::new(format!("{}/{}", dto.github_owner, dto.github_repo))?;
+    let developer_count = ValidatedDeveloperCount::new(dto.developer_count.to_string())?;
+    let working_days_per_week =
+        ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
+    Ok(NetlifyDeployProjectCreated {
+        project_name: dto.project_name.clone(),
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
+        github_owner_repo,
+        netlify_api_base_url: dto.netlify_api_base_url.clone(),
+        netlify_auth_token,
+        netlify_site_id,
+        developer_count,
+        working_days_per_week,
+    })
+}
+
+fn from_netlify_deploy_project_created(
+    domain_obj: NetlifyDeployProjectCreated,
+) -> ProjectConfigDto {
+    let (owner, repo) = domain_obj.github_owner_repo.get_values();
+    ProjectConfigDto {
+        project_name: domain_obj.project_name,
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
+        github_owner: owner,
+        github_repo: repo,
+        netlify_api_base_url: domain_obj.netlify_api_base_url,
+        netlify_auth_token: Some(domain_obj.netlify_auth_token.expose().to_string()),
+        netlify_site_id: Some(domain_obj.netlify_site_id.to_string()),
+        deployment_source: DeploymentSource::NetlifyDeploy.value(),
+        developer_count: domain_obj.developer_count.to_u32(),
+        working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
+    }
+}
+
+fn to_render_deploy_project_created(
+    dto: &ProjectConfigDto,
+) -> Result<RenderDeployProjectCreated, CreateProjectDtoError> {
+    let github_credentials = to_github_credentials(dto)?;
+    let render_api_key = ValidatedRenderApiKey::new(dto.render_api_key.clone())?;
+    let render_service_id = ValidatedRenderServiceId::new(dto.render_service_id.clone())?;
+    let github_owner_repo =
+        ValidatedGitHubOwnerRepo::new(format!("{}/{}", dto.github_owner, dto.github_repo))?;
+    let developer_count = ValidatedDeveloperCount::new(dto.developer_count.to_string())?;
+    let working_days_per_week =
+        ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
+    Ok(RenderDeployProjectCreated {
+        project_name: dto.project_name.clone(),
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
+        github_owner_repo,
+        render_api_base_url: dto.render_api_base_url.clone(),
+        render_api_key,
+        render_service_id,
+        developer_count,
+        working_days_per_week,
+    })
+}
+
+fn from_render_deploy_project_created(domain_obj: RenderDeployProjectCreated) -> ProjectConfigDto {
+    let (owner, repo) = domain_obj.github_owner_repo.get_values();
+    ProjectConfigDto {
+        project_name: domain_obj.project_name,
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
+        github_owner: owner,
+        github_repo: repo,
+        render_api_base_url: domain_obj.render_api_base_url,
+        render_api_key: Some(domain_obj.render_api_key.expose().to_string()),
+        render_service_id: Some(domain_obj.render_service_id.to_string()),
+        deployment_source: DeploymentSource::RenderDeploy.value(),
+        developer_count: domain_obj.developer_count.to_u32(),
+        working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
+    }
+}
+
+fn to_aws_code_deploy_project_created(
+    dto: &ProjectConfigDto,
+) -> Result<AwsCodeDeployProjectCreated, CreateProjectDtoError> {
+    let github_credentials = to_github_credentials(dto)?;
+    let aws_region = ValidatedAwsRegion::new(dto.aws_region.clone())?;
+    let aws_code_deploy_application_name =
+        ValidatedAwsCodeDeployApplicationName::new(dto.aws_code_deploy_application_name.clone())?;
+    let aws_code_deploy_deployment_group_name = ValidatedAwsCodeDeployDeploymentGroupName::new(
+        dto.aws_code_deploy_deployment_group_name.clone(),
+    )?;
+    let github_owner_repo =
+        ValidatedGitHubOwnerRepo::new(format!("{}/{}", dto.github_owner, dto.github_repo))?;
+    let developer_count = ValidatedDeveloperCount::new(dto.developer_count.to_string())?;
+    let working_days_per_week =
+        ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
+    Ok(AwsCodeDeployProjectCreated {
+        project_name: dto.project_name.clone(),
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
+        github_owner_repo,
+        aws_endpoint_url: dto.aws_endpoint_url.clone(),
+        aws_region,
+        aws_code_deploy_application_name,
+        aws_code_deploy_deployment_group_name,
+        aws_ecs_commit_tag: dto.aws_ecs_commit_tag.clone(),
+        developer_count,
+        working_days_per_week,
+    })
+}
+
+fn from_aws_code_deploy_project_created(
+    domain_obj: AwsCodeDeployProjectCreated,
+) -> ProjectConfigDto {
+    let (owner, repo) = domain_obj.github_owner_repo.get_values();
+    ProjectConfigDto {
+        project_name: domain_obj.project_name,
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
+        github_owner: owner,
+        github_repo: repo,
+        aws_endpoint_url: domain_obj.aws_endpoint_url,
+        aws_region: Some(domain_obj.aws_region.to_string()),
+        aws_code_deploy_application_name: Some(
+            domain_obj.aws_code_deploy_application_name.to_string(),
+        ),
+        aws_code_deploy_deployment_group_name: Some(
+            domain_obj.aws_code_deploy_deployment_group_name.to_string(),
+        ),
+        aws_ecs_commit_tag: domain_obj.aws_ecs_commit_tag,
+        deployment_source: DeploymentSource::AwsCodeDeploy.value(),
+        developer_count: domain_obj.developer_count.to_u32(),
+        working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
+    }
+}
+
+fn to_jenkins_build_project_created(
+    dto: &ProjectConfigDto,
+) -> Result<JenkinsBuildProjectCreated, CreateProjectDtoError> {
+    let github_credentials = to_github_credentials(dto)?;
+    let jenkins_base_url = ValidatedJenkinsBaseUrl::new(dto.jenkins_base_url.clone())?;
+    let jenkins_job_name = ValidatedJenkinsJobName::new(dto.jenkins_job_name.clone())?;
+    let jenkins_user_name = ValidatedJenkinsUserName::new(dto.jenkins_user_name.clone())?;
+    let jenkins_api_token = ValidatedJenkinsApiToken::new(dto.jenkins_api_token.clone())?;
+    let github_owner_repo =
+        ValidatedGitHubOwnerRepo::new(format!("{}/{}", dto.github_owner, dto.github_repo))?;
+    let developer_count = ValidatedDeveloperCount::new(dto.developer_count.to_string())?;
+    let working_days_per_week =
+        ValidatedWorkingDaysPerWeek::new(dto.working_days_per_week.to_string())?;
+    Ok(JenkinsBuildProjectCreated {
+        project_name: dto.project_name.clone(),
+        github_credentials,
+        github_api_base_url: dto.github_api_base_url.clone(),
+        github_owner_repo,
+        jenkins_base_url,
+        jenkins_job_name,
+        jenkins_user_name,
+        jenkins_api_token,
+        developer_count,
+        working_days_per_week,
+    })
+}
+
+fn from_jenkins_build_project_created(domain_obj: JenkinsBuildProjectCreated) -> ProjectConfigDto {
+    let (owner, repo) = domain_obj.github_owner_repo.get_values();
+    ProjectConfigDto {
+        project_name: domain_obj.project_name,
+        github_api_base_url: domain_obj.github_api_base_url.clone(),
+        github_owner: owner,
+        github_repo: repo,
+        jenkins_base_url: Some(domain_obj.jenkins_base_url.to_string()),
+        jenkins_job_name: Some(domain_obj.jenkins_job_name.to_string()),
+        jenkins_user_name: Some(domain_obj.jenkins_user_name.to_string()),
+        jenkins_api_token: Some(domain_obj.jenkins_api_token.expose().to_string()),
+        deployment_source: DeploymentSource::JenkinsBuild.value(),
+        developer_count: domain_obj.developer_count.to_u32(),
+        working_days_per_week: domain_obj.working_days_per_week.to_f32(),
+        ..from_github_credentials(&domain_obj.github_credentials)
     }
 }
 
@@ -195,6 +719,30 @@ impl From<ProjectCreated> for ProjectConfigDto {
             ProjectCreated::HerokuRelease(domain_obj) => {
                 from_heroku_release_project_created(domain_obj)
             }
+            ProjectCreated::HerokuPipeline(domain_obj) => {
+                from_heroku_pipeline_project_created(domain_obj)
+            }
+            ProjectCreated::ArgoCdApplication(domain_obj) => {
+                from_argo_cd_application_project_created(domain_obj)
+            }
+            ProjectCreated::KubernetesRollout(domain_obj) => {
+                from_kubernetes_rollout_project_created(domain_obj)
+            }
+            ProjectCreated::VercelDeployment(domain_obj) => {
+                from_vercel_deployment_project_created(domain_obj)
+            }
+            ProjectCreated::NetlifyDeploy(domain_obj) => {
+                from_netlify_deploy_project_created(domain_obj)
+            }
+            ProjectCreated::RenderDeploy(domain_obj) => {
+                from_render_deploy_project_created(domain_obj)
+            }
+            ProjectCreated::AwsCodeDeploy(domain_obj) => {
+                from_aws_code_deploy_project_created(domain_obj)
+            }
+            ProjectCreated::JenkinsBuild(domain_obj) => {
+                from_jenkins_build_project_created(domain_obj)
+            }
         }
     }
 }
@@ -211,6 +759,30 @@ impl TryFrom<ProjectConfigDto> for ProjectCreated {
         } else if dto.deployment_source.as_str() == DeploymentSource::HerokuRelease.value() {
             let domain_obj = to_heroku_release_project_created(&dto)?;
             Ok(ProjectCreated::HerokuRelease(domain_obj))
+        } else if dto.deployment_source.as_str() == DeploymentSource::HerokuPipeline.value() {
+            let domain_obj = to_heroku_pipeline_project_created(&dto)?;
+            Ok(ProjectCreated::HerokuPipeline(domain_obj))
+        } else if dto.deployment_source.as_str() == DeploymentSource::ArgoCdApplication.value() {
+            let domain_obj = to_argo_cd_application_project_created(&dto)?;
+            Ok(ProjectCreated::ArgoCdApplication(domain_obj))
+        } else if dto.deployment_source.as_str() == DeploymentSource::KubernetesRollout.value() {
+            let domain_obj = to_kubernetes_rollout_project_created(&dto)?;
+            Ok(ProjectCreated::KubernetesRollout(domain_obj))
+        } else if dto.deployment_source.as_str() == DeploymentSource::VercelDeployment.value() {
+            let domain_obj = to_vercel_deployment_project_created(&dto)?;
+            Ok(ProjectCreated::VercelDeployment(domain_obj))
+        } else if dto.deployment_source.as_str() == DeploymentSource::NetlifyDeploy.value() {
+            let domain_obj = to_netlify_deploy_project_created(&dto)?;
+            Ok(ProjectCreated::NetlifyDeploy(domain_obj))
+        } else if dto.deployment_source.as_str() == DeploymentSource::RenderDeploy.value() {
+            let domain_obj = to_render_deploy_project_created(&dto)?;
+            Ok(ProjectCreated::RenderDeploy(domain_obj))
+        } else if dto.deployment_source.as_str() == DeploymentSource::AwsCodeDeploy.value() {
+            let domain_obj = to_aws_code_deploy_project_created(&dto)?;
+            Ok(ProjectCreated::AwsCodeDeploy(domain_obj))
+        } else if dto.deployment_source.as_str() == DeploymentSource::JenkinsBuild.value() {
+            let domain_obj = to_jenkins_build_project_created(&dto)?;
+            Ok(ProjectCreated::JenkinsBuild(domain_obj))
         } else {
             Err(CreateProjectDtoError::InvalidDataSource(
                 dto.deployment_source,
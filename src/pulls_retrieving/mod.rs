@@ -0,0 +1,9 @@
+mod retrieve_pulls_implementation;
+mod retrieve_pulls_internal_types;
+mod retrieve_pulls_public_tests;
+mod retrieve_pulls_public_types;
+
+pub mod retrieve_pulls {
+    pub use super::retrieve_pulls_implementation::*;
+    pub use super::retrieve_pulls_public_types::*;
+}
@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use super::{
+    retrieve_pulls::{
+        MergedPull, OpenPull, PullsResult, PullsSummary, PullsThroughput, RetrievePulls,
+        RetrievePullsEvent, RetrievePullsEventError, RetrievePullsExecutionContext,
+    },
+    retrieve_pulls_internal_types::{CreateEvents, SummarizePulls, ToMergedPull, ToOpenPull},
+};
+use crate::{
+    common_types::{date_time_range::DateTimeRange, weekly_items::WeeklyItems},
+    dependencies::pull_requests_fetcher::interface::{
+        PullActivity, PullActivityParams, PullRequestsFetcher,
+    },
+    shared::median::median,
+};
+
+// ---------------------------
+// CalculateEachPull
+// ---------------------------
+const to_merged_pull: ToMergedPull = |pull: PullActivity| -> Option<MergedPull> {
+    let merged_at = pull.merged_at?;
+    let review_rounds = match pull.first_reviewed_at {
+        Some(_) => pull.changes_requested + 1,
+        None => 0,
+    };
+    Some(MergedPull {
+        number: pull.number,
+        title: pull.title,
+        author_login: pull.author_login,
+        created_at: pull.created_at,
+        merged_at,
+        time_to_first_review_seconds: pull
+            .first_reviewed_at
+            .map(|reviewed_at| (reviewed_at - pull.created_at).num_seconds()),
+        time_to_merge_seconds: (merged_at - pull.created_at).num_seconds(),
+        review_rounds,
+        additions: pull.additions,
+        deletions: pull.deletions,
+        changed_files: pull.changed_files,
+    })
+};
+
+const to_open_pull: ToOpenPull = |pull: PullActivity, until: DateTime<Utc>| -> Option<OpenPull> {
+    // Opened after the timeframe
+    if pull.created_at > until {
+        return None;
+    }
+    Some(OpenPull {
+        number: pull.number,
+        title: pull.title,
+        author_login: pull.author_login,
+        created_at: pull.created_at,
+        age_seconds: (until - pull.created_at).num_seconds(),
+    })
+};
+
+// ---------------------------
+// Aggregation
+// ---------------------------
+const summarize_pulls: SummarizePulls = |merged: &[MergedPull],
+                                         open: &[OpenPull],
+                                         timeframe: &DateTimeRange|
+ -> PullsSummary {
+    let weekly_merged_counts = WeeklyItems::new(
+        merged.to_vec(),
+        |it| it.merged_at.date_naive(),
+        timeframe.clone(),
+    )
+    .iter()
+    .map(|(_week, items)| items.len() as i64)
+    .collect::<Vec<_>>();
+    let merged_median =
+        |value: fn(&MergedPull) -> Option<i64>| median(merged.iter().flat_map(value).collect());
+
+    PullsSummary {
+        throughput: PullsThroughput {
+            total_merged: merged.len() as u32,
+            weekly_merged_median: median(weekly_merged_counts),
+        },
+        time_to_first_review_median_seconds: merged_median(|it| it.time_to_first_review_seconds),
+        time_to_merge_median_seconds: merged_median(|it| Some(it.time_to_merge_seconds)),
+        review_rounds_median: merged_median(|it| Some(it.review_rounds.into())),
+        additions_median: merged_median(|it| Some(it.additions as i64)),
+        deletions_median: merged_median(|it| Some(it.deletions as i64)),
+        changed_files_median: merged_median(|it| Some(it.changed_files as i64)),
+        open_pulls: open.len() as u32,
+        open_age_median_seconds: median(open.iter().map(|it| it.age_seconds).collect()),
+    }
+};
+
+// ---------------------------
+// create events
+// ---------------------------
+const create_events: CreateEvents = |result: PullsResult| -> Vec<RetrievePullsEvent> {
+    vec![RetrievePullsEvent::RetrievePulls(result)]
+};
+
+// ---------------------------
+// overall workflow
+// ---------------------------
+pub struct RetrievePullsWorkflow<FPullRequestsFetcher: PullRequestsFetcher> {
+    pub pull_requests_fetcher: FPullRequestsFetcher,
+}
+#[async_trait]
+impl<FPullRequestsFetcher: PullRequestsFetcher + Sync + Send> RetrievePulls
+    for RetrievePullsWorkflow<FPullRequestsFetcher>
+{
+    async fn retrieve_pulls(
+        self,
+        context: RetrievePullsExecutionContext,
+    ) -> Result<Vec<RetrievePullsEvent>, RetrievePullsEventError> {
+        let fetched = self
+            .pull_requests_fetcher
+            .fetch_activity(PullActivityParams {
+                timeframe: context.timeframe.clone(),
+            })
+            .await?;
+        let mut merged = fetched
+            .merged
+            .into_iter()
+            .flat_map(to_merged_pull)
+            .collect::<Vec<_>>();
+        merged.sort_by_key(|it| it.merged_at);
+        let until = context.timeframe.get_until();
+        let mut open = fetched
+            .open
+            .into_iter()
+            .flat_map(|pull| to_open_pull(pull, until))
+            .collect::<Vec<_>>();
+        open.sort_by_key(|it| it.created_at);
+        let summary = summarize_pulls(&merged, &open, &context.timeframe);
+
+        Ok(create_events(PullsResult {
+            timeframe: context.timeframe,
+            summary,
+            merged,
+            open,
+        }))
+    }
+}
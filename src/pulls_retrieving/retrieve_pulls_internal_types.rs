@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+
+use super::retrieve_pulls::{MergedPull, OpenPull, PullsResult, PullsSummary, RetrievePullsEvent};
+use crate::{
+    common_types::date_time_range::DateTimeRange,
+    dependencies::pull_requests_fetcher::interface::PullActivity,
+};
+
+// ---------------------------
+// CalculateEachPull
+// ---------------------------
+pub(super) type ToMergedPull = fn(PullActivity) -> Option<MergedPull>;
+
+pub(super) type ToOpenPull = fn(PullActivity, until: DateTime<Utc>) -> Option<OpenPull>;
+
+// ---------------------------
+// Aggregation
+// ---------------------------
+pub(super) type SummarizePulls = fn(&[MergedPull], &[OpenPull], &DateTimeRange) -> PullsSummary;
+
+// ---------------------------
+// Create events
+// ---------------------------
+pub(super) type CreateEvents = fn(result: PullsResult) -> Vec<RetrievePullsEvent>;
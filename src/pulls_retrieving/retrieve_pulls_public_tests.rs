@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    mod retrieve_pulls_workflow_tests {
+        use crate::{
+            common_types::date_time_range::DateTimeRange,
+            dependencies::pull_requests_fetcher::{
+                interface::PullActivity, mock::PullRequestsFetcherWithMock,
+            },
+            pulls_retrieving::retrieve_pulls::{
+                RetrievePulls, RetrievePullsEvent, RetrievePullsExecutionContext,
+                RetrievePullsWorkflow,
+            },
+            shared::datetime_utc::parse,
+        };
+
+        const HOUR: i64 = 60 * 60;
+
+        fn build_pull(
+            number: u64,
+            created_at: &str,
+            first_reviewed_at: Option<&str>,
+            merged_at: Option<&str>,
+            changes_requested: u32,
+        ) -> PullActivity {
+            PullActivity {
+                number,
+                title: "title".to_string(),
+                author_login: "dev".to_string(),
+                created_at: parse(created_at).unwrap(),
+                merged_at: merged_at.map(|it| parse(it).unwrap()),
+                first_reviewed_at: first_reviewed_at.map(|it| parse(it).unwrap()),
+                changes_requested,
+                additions: number * 10,
+                deletions: number,
+                changed_files: number,
+            }
+        }
+
+        #[tokio::test]
+        async fn summarizes_merged_and_open_pulls() {
+            let workflow = RetrievePullsWorkflow {
+                pull_requests_fetcher: PullRequestsFetcherWithMock {
+                    merged: vec![
+                        build_pull(
+                            1,
+                            "2023-01-02 10:00:00",
+                            Some("2023-01-02 12:00:00"),
+                            Some("2023-01-03 10:00:00"),
+                            0,
+                        ),
+                        build_pull(
+                            2,
+                            "2023-01-04 10:00:00",
+                            Some("2023-01-04 14:00:00"),
+                            Some("2023-01-06 10:00:00"),
+                            2,
+                        ),
+                        build_pull(
+                            3,
+                            "2023-01-10 10:00:00",
+                            None,
+                            Some("2023-01-10 16:00:00"),
+                            0,
+                        ),
+                    ],
+                    open: vec![
+                        build_pull(4, "2023-01-29 00:00:00", None, None, 0),
+                        build_pull(5, "2023-02-10 00:00:00", None, None, 0),
+                    ],
+                    ..Default::default()
+                },
+            };
+            let context = RetrievePullsExecutionContext {
+                timeframe: DateTimeRange::new(
+                    parse("2023-01-01 00:00:00").unwrap(),
+                    parse("2023-01-31 00:00:00").unwrap(),
+                )
+                .unwrap(),
+            };
+
+            let events = workflow.retrieve_pulls(context).await.unwrap();
+            let RetrievePullsEvent::RetrievePulls(result) = events.into_iter().next().unwrap();
+
+            let rounds = result
+                .merged
+                .iter()
+                .map(|it| it.review_rounds)
+                .collect::<Vec<_>>();
+            assert_eq!(rounds, vec![1, 3, 0]);
+            assert_eq!(result.summary.throughput.total_merged, 3);
+            // Only the reviewed pulls: 2h and 4h
+            assert_eq!(
+                result.summary.time_to_first_review_median_seconds,
+                (3 * HOUR) as f64
+            );
+            // 6h, 24h, 48h
+            assert_eq!(
+                result.summary.time_to_merge_median_seconds,
+                (24 * HOUR) as f64
+            );
+            assert_eq!(result.summary.review_rounds_median, 1.0);
+            assert_eq!(result.summary.additions_median, 20.0);
+            // The pull opened after the timeframe is left out
+            assert_eq!(result.summary.open_pulls, 1);
+            assert_eq!(result.summary.open_age_median_seconds, (48 * HOUR) as f64);
+        }
+    }
+}
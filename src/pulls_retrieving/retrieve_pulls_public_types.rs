@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    common_types::date_time_range::DateTimeRange,
+    dependencies::pull_requests_fetcher::interface::PullRequestsFetcherError,
+};
+
+// ==================================
+// This file contains the definitions of PUBLIC types (exposed at the boundary of the bounded context)
+// related to the workflow
+// ==================================
+
+// ------------------------------------
+// inputs to the workflow
+
+#[derive(Clone)]
+pub struct RetrievePullsExecutionContext {
+    pub timeframe: DateTimeRange,
+}
+
+// ------------------------------------
+// outputs from the workflow (success case)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MergedPull {
+    pub number: u64,
+    pub title: String,
+    pub author_login: String,
+    pub created_at: DateTime<Utc>,
+    pub merged_at: DateTime<Utc>,
+    pub time_to_first_review_seconds: Option<i64>,
+    pub time_to_merge_seconds: i64,
+    /// Reviews that requested changes, plus the last one; zero without any review
+    pub review_rounds: u32,
+    pub additions: u64,
+    pub deletions: u64,
+    pub changed_files: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct OpenPull {
+    pub number: u64,
+    pub title: String,
+    pub author_login: String,
+    pub created_at: DateTime<Utc>,
+    /// Age at the end of the timeframe
+    pub age_seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PullsThroughput {
+    pub total_merged: u32,
+    pub weekly_merged_median: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PullsSummary {
+    pub throughput: PullsThroughput,
+    pub time_to_first_review_median_seconds: f64,
+    pub time_to_merge_median_seconds: f64,
+    pub review_rounds_median: f64,
+    pub additions_median: f64,
+    pub deletions_median: f64,
+    pub changed_files_median: f64,
+    pub open_pulls: u32,
+    pub open_age_median_seconds: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PullsResult {
+    pub timeframe: DateTimeRange,
+    pub summary: PullsSummary,
+    pub merged: Vec<MergedPull>,
+    pub open: Vec<OpenPull>,
+}
+
+// Events
+/// The possible events resulting from the workflow
+/// Not all events will occur, depending on the logic of the workflow
+pub enum RetrievePullsEvent {
+    RetrievePulls(PullsResult),
+}
+
+// Error types
+#[derive(Error, Debug)]
+pub enum RetrievePullsEventError {
+    #[error("Cannot fetch")]
+    FetchPulls(#[from] PullRequestsFetcherError),
+}
+
+// ------------------------------------
+// the workflow itself
+#[async_trait]
+pub trait RetrievePulls {
+    async fn retrieve_pulls(
+        self,
+        context: RetrievePullsExecutionContext,
+    ) -> Result<Vec<RetrievePullsEvent>, RetrievePullsEventError>;
+}
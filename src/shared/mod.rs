@@ -1,4 +1,7 @@
 pub mod datetime_utc;
+pub mod github_client;
 pub mod median;
 pub mod non_empty_vec;
+pub mod rate_limit;
+pub mod redaction;
 pub mod setup_logger;
@@ -0,0 +1,272 @@
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        OnceLock,
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::{header::HeaderMap, Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+use task_local_extensions::Extensions;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+// Enough to keep a run fast while staying clear of GitHub's secondary rate limits
+const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+static CONCURRENT_REQUESTS: OnceLock<Semaphore> = OnceLock::new();
+static RETRIED_CALLS: AtomicUsize = AtomicUsize::new(0);
+static SKIPPED_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    /// Waits longer than this are not worth it; the response is returned as it is
+    pub max_delay: Duration,
+}
+
+pub const DEFAULT_RETRY_POLICY: RetryPolicy = RetryPolicy {
+    max_retries: 5,
+    base_delay: Duration::from_secs(1),
+    max_delay: Duration::from_secs(600),
+};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HttpCallReport {
+    pub retried: usize,
+    pub skipped: usize,
+}
+
+pub fn call_report() -> HttpCallReport {
+    HttpCallReport {
+        retried: RETRIED_CALLS.load(Ordering::Relaxed),
+        skipped: SKIPPED_CALLS.load(Ordering::Relaxed),
+    }
+}
+
+/// Counts a call whose result was given up on, so that the run can report it
+fn record_skipped_call() {
+    SKIPPED_CALLS.fetch_add(1, Ordering::Relaxed);
+}
+
+async fn acquire_request_slot() -> SemaphorePermit<'static> {
+    CONCURRENT_REQUESTS
+        .get_or_init(|| Semaphore::new(MAX_CONCURRENT_REQUESTS))
+        .acquire()
+        .await
+        .expect("Request semaphore is never closed")
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+fn is_retryable(status: StatusCode, headers: &HeaderMap) -> bool {
+    let retry_after = header_u64(headers, "retry-after");
+    let rate_limit_exhausted = header_u64(headers, "x-ratelimit-remaining") == Some(0);
+    // GitHub answers both primary and secondary (abuse) rate limits with 403 or 429
+    status.is_server_error()
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || (status == StatusCode::FORBIDDEN && (retry_after.is_some() || rate_limit_exhausted))
+}
+
+/// How long to wait before retrying, or `None` when the response should be returned as it is
+fn retry_delay(
+    policy: &RetryPolicy,
+    status: StatusCode,
+    headers: &HeaderMap,
+    attempt: u32,
+) -> Option<Duration> {
+    if attempt >= policy.max_retries || !is_retryable(status, headers) {
+        return None;
+    }
+    let retry_after = header_u64(headers, "retry-after").map(Duration::from_secs);
+    let rate_limit_exhausted = header_u64(headers, "x-ratelimit-remaining") == Some(0);
+    let delay = match (retry_after, rate_limit_exhausted) {
+        (Some(retry_after), _) => retry_after,
+        (None, true) => header_u64(headers, "x-ratelimit-reset")
+            .map(|reset| Duration::from_secs((reset as i64 - Utc::now().timestamp()).max(1) as u64))
+            .unwrap_or(policy.base_delay),
+        (None, false) => policy.base_delay * 2u32.pow(attempt),
+    };
+
+    if delay > policy.max_delay {
+        None
+    } else {
+        Some(delay)
+    }
+}
+
+async fn wait_for_retry(delay: Duration, status: StatusCode, url: &str) {
+    log::debug!("Retrying {} in {:?} after {}", url, delay, status);
+    RETRIED_CALLS.fetch_add(1, Ordering::Relaxed);
+    tokio::time::sleep(delay).await;
+}
+
+/// Sends with bounded concurrency, retrying rate-limited and failed responses with backoff
+pub async fn send_with_retry<F, Fut, E>(policy: &RetryPolicy, send: F) -> Result<Response, E>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<Response, E>>,
+{
+    let mut attempt = 0;
+    loop {
+        let response = {
+            let _slot = acquire_request_slot().await;
+            send().await?
+        };
+        match retry_delay(policy, response.status(), response.headers(), attempt) {
+            Some(delay) => {
+                wait_for_retry(delay, response.status(), response.url().as_str()).await;
+                attempt += 1;
+            }
+            None => {
+                if is_retryable(response.status(), response.headers()) {
+                    record_skipped_call();
+                }
+                return Ok(response);
+            }
+        }
+    }
+}
+
+/// `send_with_retry` for clients built with `reqwest_middleware`
+pub struct RateLimitMiddleware {
+    pub policy: RetryPolicy,
+}
+
+#[async_trait]
+impl Middleware for RateLimitMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut req = req;
+        let mut attempt = 0;
+        loop {
+            // A streamed body cannot be sent twice, so such a request is never retried
+            let retry_req = req.try_clone();
+            let response = {
+                let _slot = acquire_request_slot().await;
+                next.clone().run(req, extensions).await?
+            };
+            let delay = retry_delay(&self.policy, response.status(), response.headers(), attempt);
+            match (delay, retry_req) {
+                (Some(delay), Some(retry_req)) => {
+                    wait_for_retry(delay, response.status(), response.url().as_str()).await;
+                    attempt += 1;
+                    req = retry_req;
+                }
+                _ => {
+                    if is_retryable(response.status(), response.headers()) {
+                        record_skipped_call();
+                    }
+                    return Ok(response);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::{header::HeaderMap, StatusCode};
+    use reqwest_middleware::ClientBuilder;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::{retry_delay, send_with_retry, RateLimitMiddleware, RetryPolicy};
+
+    const FAST_RETRY_POLICY: RetryPolicy = RetryPolicy {
+        max_retries: 3,
+        base_delay: Duration::from_millis(10),
+        max_delay: Duration::from_secs(5),
+    };
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn decide_retry_delay_from_status_and_headers() {
+        let policy = FAST_RETRY_POLICY;
+        let secondary_limit = headers(&[("retry-after", "2")]);
+        let primary_limit = headers(&[("x-ratelimit-remaining", "0"), ("x-ratelimit-reset", "0")]);
+        let too_long = headers(&[("retry-after", "3600")]);
+
+        assert_eq!(
+            retry_delay(&policy, StatusCode::FORBIDDEN, &secondary_limit, 0),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            retry_delay(&policy, StatusCode::FORBIDDEN, &primary_limit, 0),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            retry_delay(&policy, StatusCode::BAD_GATEWAY, &HeaderMap::new(), 2),
+            Some(Duration::from_millis(40))
+        );
+        assert_eq!(
+            retry_delay(&policy, StatusCode::BAD_GATEWAY, &HeaderMap::new(), 3),
+            None
+        );
+        assert_eq!(
+            retry_delay(&policy, StatusCode::FORBIDDEN, &HeaderMap::new(), 0),
+            None
+        );
+        assert_eq!(
+            retry_delay(&policy, StatusCode::TOO_MANY_REQUESTS, &too_long, 0),
+            None
+        );
+        assert_eq!(
+            retry_delay(&policy, StatusCode::NOT_FOUND, &HeaderMap::new(), 0),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn retry_until_the_server_recovers() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        let url = format!("{}/flaky", server.uri());
+
+        let client = reqwest::Client::new();
+        let response = send_with_retry(&FAST_RETRY_POLICY, || client.get(&url).send())
+            .await
+            .unwrap();
+        let middleware_client = ClientBuilder::new(reqwest::Client::new())
+            .with(RateLimitMiddleware {
+                policy: FAST_RETRY_POLICY,
+            })
+            .build();
+        let from_middleware = middleware_client.get(&url).send().await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(from_middleware.status(), StatusCode::OK);
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+    }
+}
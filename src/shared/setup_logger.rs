@@ -1,10 +1,26 @@
+use std::io::Write;
+
 use env_logger;
 
+use super::redaction::redact;
+
 pub fn init(verbose: bool) {
     let log_level = if verbose {
         log::LevelFilter::Debug
     } else {
         log::LevelFilter::Info
     };
-    env_logger::builder().filter_level(log_level).init();
+    env_logger::builder()
+        .filter_level(log_level)
+        .format(|buf, record| {
+            writeln!(
+                buf,
+                "[{} {:<5} {}] {}",
+                buf.timestamp(),
+                record.level(),
+                record.target(),
+                redact(&record.args().to_string())
+            )
+        })
+        .init();
 }
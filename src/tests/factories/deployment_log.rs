@@ -13,6 +13,7 @@ pub fn build_deployment_log(deployed_at_str: &str) -> DeploymentLog {
     DeploymentLog {
         info: DeploymentInfo::GithubDeployment {
             id: "id".to_string(),
+            environment: "production".to_string(),
         },
         head_commit: Commit {
             sha: "sha".to_string(),
@@ -1,3 +1,4 @@
 pub mod commit;
 pub mod deployment_log;
+pub mod repo_commit;
 pub mod repository_info;
@@ -0,0 +1,31 @@
+use octocrab::models::repos::RepoCommit;
+
+/// A commit as the GitHub REST API returns it, authored at `authored_at` (RFC 3339)
+pub fn build_repo_commit_json(sha: &str, authored_at: &str) -> serde_json::Value {
+    serde_json::json!({
+        "url": format!("https://api.github.com/repos/owner/repo/commits/{}", sha),
+        "sha": sha,
+        "node_id": format!("C_{}", sha),
+        "html_url": format!("https://github.com/owner/repo/commit/{}", sha),
+        "comments_url": format!("https://api.github.com/repos/owner/repo/commits/{}/comments", sha),
+        "commit": {
+            "url": format!("https://api.github.com/repos/owner/repo/git/commits/{}", sha),
+            "author": { "name": "dev", "email": "dev@example.com", "date": authored_at },
+            "committer": { "name": "dev", "email": "dev@example.com", "date": authored_at },
+            "message": "message",
+            "comment_count": 0,
+            "tree": {
+                "sha": "tree",
+                "url": "https://api.github.com/repos/owner/repo/git/trees/tree"
+            }
+        },
+        "author": null,
+        "committer": null,
+        "parents": []
+    })
+}
+
+pub fn build_repo_commit(sha: &str, authored_at: &str) -> RepoCommit {
+    serde_json::from_value(build_repo_commit_json(sha, authored_at))
+        .expect("Could not build the commit")
+}